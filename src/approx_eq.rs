@@ -0,0 +1,426 @@
+use std::mem::discriminant;
+
+use crate::statistics::display;
+use crate::{table_scalar, ScalarValuable, Table, TableList, TableScalar};
+
+/// How many differences [`Table::approx_mismatch`] lists before summarising the rest.
+const MAX_REPORTED: usize = 20;
+
+/// Options for [`Table::approx_eq`] and [`TableList::approx_eq`]. Values behind nulls are
+/// always ignored.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ApproxEqOptions {
+    /// The largest absolute difference at which two floats are still equal.
+    pub tolerance: f64,
+    /// Whether NaN equals NaN.
+    pub nan_eq: bool,
+    /// Whether rows may appear in any order. Rows are matched greedily, which takes
+    /// quadratic time, so this is meant for tests rather than large tables.
+    pub ignore_row_order: bool,
+}
+
+impl Default for ApproxEqOptions {
+    fn default() -> Self {
+        ApproxEqOptions {
+            tolerance: 1e-9,
+            nan_eq: true,
+            ignore_row_order: false,
+        }
+    }
+}
+
+fn float_eq(a: f64, b: f64, options: ApproxEqOptions) -> bool {
+    if a.is_nan() || b.is_nan() {
+        options.nan_eq && a.is_nan() && b.is_nan()
+    } else {
+        a == b || (a - b).abs() <= options.tolerance
+    }
+}
+
+fn option_eq<T>(a: &Option<T>, b: &Option<T>, eq: impl Fn(&T, &T) -> bool) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => eq(a, b),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+impl TableScalar {
+    /// Compares two scalars, allowing for float rounding. Lists held by the scalars are
+    /// compared with [`TableList::approx_eq`].
+    pub fn approx_eq(&self, other: &TableScalar, options: ApproxEqOptions) -> bool {
+        use table_scalar::Value;
+        match (&self.value, &other.value) {
+            (Some(Value::Float16(a)), Some(Value::Float16(b)))
+            | (Some(Value::Float32(a)), Some(Value::Float32(b))) => {
+                float_eq(*a as f64, *b as f64, options)
+            }
+            (Some(Value::Float64(a)), Some(Value::Float64(b))) => float_eq(*a, *b, options),
+            (Some(Value::Struct(a)), Some(Value::Struct(b))) => {
+                a.elements.len() == b.elements.len()
+                    && a.elements.iter().all(|(name, a)| {
+                        option_eq(&Some(a), &b.elements.get(name), |a, b| {
+                            a.approx_eq(b, options)
+                        })
+                    })
+            }
+            (Some(Value::Union(a)), Some(Value::Union(b)))
+            | (Some(Value::Dictionary(a)), Some(Value::Dictionary(b))) => a.approx_eq(b, options),
+            (Some(Value::List(a)), Some(Value::List(b)))
+            | (Some(Value::FixedSizeList(a)), Some(Value::FixedSizeList(b)))
+            | (Some(Value::LargeList(a)), Some(Value::LargeList(b))) => a.approx_eq(b, options),
+            (Some(Value::Map(a)), Some(Value::Map(b))) => {
+                option_eq(&a.keys, &b.keys, |a, b| a.approx_eq(b, options))
+                    && option_eq(&a.values, &b.values, |a, b| a.approx_eq(b, options))
+            }
+            (a, b) => a == b,
+        }
+    }
+}
+
+/// Pairs up equal rows, returning the unmatched rows of each side. In order, rows are
+/// paired by position; otherwise each left row takes the first free right row it equals.
+fn unmatched_rows(
+    left: usize,
+    right: usize,
+    ignore_row_order: bool,
+    eq: impl Fn(usize, usize) -> bool,
+) -> (Vec<usize>, Vec<usize>) {
+    if !ignore_row_order {
+        let unmatched = (0..left.max(right))
+            .filter(|i| *i >= left.min(right) || !eq(*i, *i))
+            .collect::<Vec<_>>();
+        let left_rows = unmatched.iter().copied().filter(|i| *i < left).collect();
+        let right_rows = unmatched.into_iter().filter(|i| *i < right).collect();
+        return (left_rows, right_rows);
+    }
+    let mut free = vec![true; right];
+    let mut left_rows = vec![];
+    for i in 0..left {
+        match (0..right).find(|j| free.get(*j).copied().unwrap_or(false) && eq(i, *j)) {
+            Some(j) => {
+                if let Some(free) = free.get_mut(j) {
+                    *free = false;
+                }
+            }
+            None => left_rows.push(i),
+        }
+    }
+    let right_rows = (0..right)
+        .filter(|j| free.get(*j).copied().unwrap_or(false))
+        .collect();
+    (left_rows, right_rows)
+}
+
+fn scalars(list: &TableList) -> Option<Vec<TableScalar>> {
+    (0..list.len()).map(|i| list.scalar(i).ok()).collect()
+}
+
+impl TableList {
+    /// Compares two lists of the same type, allowing for float rounding and ignoring the
+    /// values stored behind nulls. Lists of different types, or that cannot be read, are never
+    /// equal.
+    pub fn approx_eq(&self, other: &TableList, options: ApproxEqOptions) -> bool {
+        if self.values.as_ref().map(discriminant) != other.values.as_ref().map(discriminant)
+            || self.type_name() != other.type_name()
+            || self.len() != other.len()
+        {
+            return false;
+        }
+        let (Some(left), Some(right)) = (scalars(self), scalars(other)) else {
+            return false;
+        };
+        let (left_rows, right_rows) =
+            unmatched_rows(left.len(), right.len(), options.ignore_row_order, |i, j| {
+                option_eq(&left.get(i), &right.get(j), |a, b| a.approx_eq(b, options))
+            });
+        left_rows.is_empty() && right_rows.is_empty()
+    }
+}
+
+fn format_scalar(scalar: &TableScalar) -> String {
+    display(&Some(scalar.clone())).unwrap_or_else(|| "null".to_string())
+}
+
+fn format_row(names: &[&str], row: &[TableScalar]) -> String {
+    let cells = names
+        .iter()
+        .zip(row)
+        .map(|(name, value)| format!("{}: {}", name, format_scalar(value)))
+        .collect::<Vec<_>>();
+    format!("{{{}}}", cells.join(", "))
+}
+
+fn rows(table: &Table) -> Result<Vec<Vec<TableScalar>>, String> {
+    (0..table.len())
+        .map(|i| {
+            table
+                .values
+                .iter()
+                .map(|column| column.scalar(i))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|error| format!("row {}: {}", i, error))
+        })
+        .collect()
+}
+
+impl Table {
+    /// Compares two tables with the same fields, see [`TableList::approx_eq`].
+    pub fn approx_eq(&self, other: &Table, options: ApproxEqOptions) -> bool {
+        self.approx_mismatch(other, options).is_none()
+    }
+
+    /// Describes how `other` differs from this table under [`Table::approx_eq`], or returns
+    /// `None` if they are equal.
+    pub fn approx_mismatch(&self, other: &Table, options: ApproxEqOptions) -> Option<String> {
+        if self.fields != other.fields {
+            return Some(format!(
+                "fields differ:\n  left:  {:?}\n  right: {:?}",
+                self.fields, other.fields
+            ));
+        }
+        let (left, right) = match (rows(self), rows(other)) {
+            (Ok(left), Ok(right)) => (left, right),
+            (Err(error), _) => return Some(format!("left table is invalid at {}", error)),
+            (_, Err(error)) => return Some(format!("right table is invalid at {}", error)),
+        };
+        let row_eq = |a: &[TableScalar], b: &[TableScalar]| {
+            a.iter().zip(b).all(|(a, b)| a.approx_eq(b, options))
+        };
+        let (left_rows, right_rows) =
+            unmatched_rows(left.len(), right.len(), options.ignore_row_order, |i, j| {
+                option_eq(&left.get(i), &right.get(j), |a, b| row_eq(a, b))
+            });
+        if left_rows.is_empty() && right_rows.is_empty() {
+            return None;
+        }
+
+        let names = self
+            .fields
+            .iter()
+            .map(|field| field.name.as_str())
+            .collect::<Vec<_>>();
+        let mut lines = vec![];
+        if left.len() != right.len() {
+            lines.push(format!(
+                "left has {} rows, right has {} rows",
+                left.len(),
+                right.len()
+            ));
+        }
+        if options.ignore_row_order {
+            for i in &left_rows {
+                if let Some(row) = left.get(*i) {
+                    let row = format_row(&names, row);
+                    lines.push(format!("left row {} has no match: {}", i, row));
+                }
+            }
+            for j in &right_rows {
+                if let Some(row) = right.get(*j) {
+                    let row = format_row(&names, row);
+                    lines.push(format!("right row {} has no match: {}", j, row));
+                }
+            }
+        } else {
+            for i in &left_rows {
+                match (left.get(*i), right.get(*i)) {
+                    (Some(a), Some(b)) => {
+                        for ((name, a), b) in names.iter().zip(a).zip(b) {
+                            if !a.approx_eq(b, options) {
+                                lines.push(format!(
+                                    "row {}, column `{}`: {} != {}",
+                                    i,
+                                    name,
+                                    format_scalar(a),
+                                    format_scalar(b)
+                                ));
+                            }
+                        }
+                    }
+                    (Some(a), None) => {
+                        lines.push(format!("left row {}: {}", i, format_row(&names, a)));
+                    }
+                    _ => {}
+                }
+            }
+            for j in right_rows.iter().filter(|j| **j >= left.len()) {
+                if let Some(row) = right.get(*j) {
+                    lines.push(format!("right row {}: {}", j, format_row(&names, row)));
+                }
+            }
+        }
+        if lines.len() > MAX_REPORTED {
+            let more = lines.len() - MAX_REPORTED;
+            lines.truncate(MAX_REPORTED);
+            lines.push(format!("... and {} more", more));
+        }
+        Some(lines.join("\n"))
+    }
+}
+
+/// Asserts that two [`Table`]s are equal under [`Table::approx_eq`], printing the
+/// differing cells or rows on failure. Takes [`ApproxEqOptions`] as an optional third
+/// argument.
+///
+/// ```
+/// # use arrow_scalars::{assert_table_eq, ApproxEqOptions, Table};
+/// # let schema = arrow::datatypes::Schema::new(vec![]);
+/// let table = Table::new(&schema).unwrap();
+/// assert_table_eq!(table, table.clone());
+/// assert_table_eq!(table, table.clone(), ApproxEqOptions::default());
+/// ```
+#[macro_export]
+macro_rules! assert_table_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_table_eq!($left, $right, $crate::ApproxEqOptions::default())
+    };
+    ($left:expr, $right:expr, $options:expr $(,)?) => {
+        if let Some(mismatch) = $crate::Table::approx_mismatch(&$left, &$right, $options) {
+            panic!("assertion `left approx_eq right` failed\n{}", mismatch);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::array::{
+        ArrayRef, Date32Array, Float64Array, Int32Array, Int64Array, ListArray,
+        TimestampSecondArray,
+    };
+    use arrow::datatypes::Int32Type;
+    use arrow::record_batch::RecordBatch;
+
+    use super::*;
+    use crate::{table_list, ListValuable};
+
+    fn table(a: Vec<Option<i32>>, b: Vec<f64>) -> Table {
+        Table::from_arrow(
+            &RecordBatch::try_from_iter_with_nullable(vec![
+                ("a", Arc::new(Int32Array::from(a)) as ArrayRef, true),
+                ("b", Arc::new(Float64Array::from(b)) as ArrayRef, false),
+            ])
+            .unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_list_approx_eq() {
+        let options = ApproxEqOptions::default();
+        let a = Float64Array::from(vec![1.0, f64::NAN])
+            .clone_as_list()
+            .unwrap();
+        let b = Float64Array::from(vec![1.0 + 1e-12, f64::NAN])
+            .clone_as_list()
+            .unwrap();
+        assert!(a.approx_eq(&b, options));
+        assert!(!a.approx_eq(
+            &b,
+            ApproxEqOptions {
+                nan_eq: false,
+                ..options
+            }
+        ));
+        assert!(!a.approx_eq(
+            &b,
+            ApproxEqOptions {
+                tolerance: 0.0,
+                ..options
+            }
+        ));
+
+        // Values behind nulls differ, which the derived `PartialEq` notices.
+        let mut a = Int32Array::from(vec![Some(1), None])
+            .clone_as_list()
+            .unwrap();
+        let b = a.clone();
+        if let Some(table_list::Values::Int32(list)) = a.values.as_mut() {
+            list.values[1] = 7;
+        }
+        assert_ne!(a, b);
+        assert!(a.approx_eq(&b, options));
+
+        let a = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), None]),
+            None,
+        ])
+        .clone_as_list()
+        .unwrap();
+        let b = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            None,
+            Some(vec![Some(1), None]),
+        ])
+        .clone_as_list()
+        .unwrap();
+        assert!(!a.approx_eq(&b, options));
+        assert!(a.approx_eq(
+            &b,
+            ApproxEqOptions {
+                ignore_row_order: true,
+                ..options
+            }
+        ));
+        assert!(!a.approx_eq(
+            &Int32Array::from(vec![1, 2]).clone_as_list().unwrap(),
+            options
+        ));
+
+        let int32 = Int32Array::from(Vec::<i32>::new()).clone_as_list().unwrap();
+        let int64 = Int64Array::from(Vec::<i64>::new()).clone_as_list().unwrap();
+        let date32 = Date32Array::from(Vec::<i32>::new())
+            .clone_as_list()
+            .unwrap();
+        assert!(int32.approx_eq(&int32.clone(), options));
+        assert!(!int32.approx_eq(&int64, options));
+        assert!(!int32.approx_eq(&date32, options));
+        let utc = TimestampSecondArray::from(vec![1])
+            .with_timezone("UTC".to_string())
+            .clone_as_list()
+            .unwrap();
+        let naive = TimestampSecondArray::from(vec![1]).clone_as_list().unwrap();
+        assert!(!utc.approx_eq(&naive, options));
+    }
+
+    #[test]
+    fn test_table_approx_eq() {
+        let left = table(vec![Some(1), None, Some(3)], vec![0.1 + 0.2, 1.0, 2.0]);
+        let right = table(vec![Some(1), None, Some(3)], vec![0.3, 1.0, 2.0]);
+        assert_ne!(left, right);
+        assert_table_eq!(left, right);
+
+        let shuffled = table(vec![Some(3), Some(1), None], vec![2.0, 0.3, 1.0]);
+        assert!(!left.approx_eq(&shuffled, ApproxEqOptions::default()));
+        assert_table_eq!(
+            left,
+            shuffled,
+            ApproxEqOptions {
+                ignore_row_order: true,
+                ..Default::default()
+            }
+        );
+
+        let changed = table(
+            vec![Some(1), Some(2), Some(3), Some(4)],
+            vec![0.3, 1.0, 2.5, 0.0],
+        );
+        assert_eq!(
+            left.approx_mismatch(&changed, ApproxEqOptions::default())
+                .unwrap(),
+            "left has 3 rows, right has 4 rows\n\
+             row 1, column `a`: null != 2\n\
+             row 2, column `b`: 2 != 2.5\n\
+             right row 3: {a: 4, b: 0}"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "row 0, column `a`: 1 != 2")]
+    fn test_assert_table_eq() {
+        assert_table_eq!(
+            table(vec![Some(1)], vec![0.0]),
+            table(vec![Some(2)], vec![0.0])
+        );
+    }
+}
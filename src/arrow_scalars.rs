@@ -385,6 +385,19 @@ pub mod table_scalar {
         Map(Map),
     }
 }
+/// Packed validity of a list. A non-empty `set` on the list takes precedence over it and must
+/// hold one entry per row, where `false` is a null row. A list with neither an entry in `set`
+/// nor a `validity` has every row valid.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Validity {
+    /// Little-endian bitmap with one bit per row, as in Arrow. A set bit is a valid row.
+    #[prost(bytes = "vec", tag = "1")]
+    pub bitmap: ::prost::alloc::vec::Vec<u8>,
+    /// Every row is valid and `bitmap` is empty.
+    #[prost(bool, tag = "2")]
+    pub all_valid: bool,
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TableList {
@@ -403,6 +416,8 @@ pub mod table_list {
         pub values: ::prost::alloc::vec::Vec<bool>,
         #[prost(bool, repeated, tag = "2")]
         pub set: ::prost::alloc::vec::Vec<bool>,
+        #[prost(message, optional, tag = "3")]
+        pub validity: ::core::option::Option<super::Validity>,
     }
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
@@ -411,6 +426,8 @@ pub mod table_list {
         pub values: ::prost::alloc::vec::Vec<i32>,
         #[prost(bool, repeated, tag = "2")]
         pub set: ::prost::alloc::vec::Vec<bool>,
+        #[prost(message, optional, tag = "3")]
+        pub validity: ::core::option::Option<super::Validity>,
     }
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
@@ -419,6 +436,8 @@ pub mod table_list {
         pub values: ::prost::alloc::vec::Vec<i32>,
         #[prost(bool, repeated, tag = "2")]
         pub set: ::prost::alloc::vec::Vec<bool>,
+        #[prost(message, optional, tag = "3")]
+        pub validity: ::core::option::Option<super::Validity>,
     }
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
@@ -427,6 +446,8 @@ pub mod table_list {
         pub values: ::prost::alloc::vec::Vec<i32>,
         #[prost(bool, repeated, tag = "2")]
         pub set: ::prost::alloc::vec::Vec<bool>,
+        #[prost(message, optional, tag = "3")]
+        pub validity: ::core::option::Option<super::Validity>,
     }
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
@@ -435,6 +456,8 @@ pub mod table_list {
         pub values: ::prost::alloc::vec::Vec<i64>,
         #[prost(bool, repeated, tag = "2")]
         pub set: ::prost::alloc::vec::Vec<bool>,
+        #[prost(message, optional, tag = "3")]
+        pub validity: ::core::option::Option<super::Validity>,
     }
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
@@ -443,6 +466,8 @@ pub mod table_list {
         pub values: ::prost::alloc::vec::Vec<u32>,
         #[prost(bool, repeated, tag = "2")]
         pub set: ::prost::alloc::vec::Vec<bool>,
+        #[prost(message, optional, tag = "3")]
+        pub validity: ::core::option::Option<super::Validity>,
     }
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
@@ -451,6 +476,8 @@ pub mod table_list {
         pub values: ::prost::alloc::vec::Vec<u32>,
         #[prost(bool, repeated, tag = "2")]
         pub set: ::prost::alloc::vec::Vec<bool>,
+        #[prost(message, optional, tag = "3")]
+        pub validity: ::core::option::Option<super::Validity>,
     }
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
@@ -459,6 +486,8 @@ pub mod table_list {
         pub values: ::prost::alloc::vec::Vec<u32>,
         #[prost(bool, repeated, tag = "2")]
         pub set: ::prost::alloc::vec::Vec<bool>,
+        #[prost(message, optional, tag = "3")]
+        pub validity: ::core::option::Option<super::Validity>,
     }
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
@@ -467,6 +496,8 @@ pub mod table_list {
         pub values: ::prost::alloc::vec::Vec<u64>,
         #[prost(bool, repeated, tag = "2")]
         pub set: ::prost::alloc::vec::Vec<bool>,
+        #[prost(message, optional, tag = "3")]
+        pub validity: ::core::option::Option<super::Validity>,
     }
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
@@ -475,6 +506,8 @@ pub mod table_list {
         pub values: ::prost::alloc::vec::Vec<f32>,
         #[prost(bool, repeated, tag = "2")]
         pub set: ::prost::alloc::vec::Vec<bool>,
+        #[prost(message, optional, tag = "3")]
+        pub validity: ::core::option::Option<super::Validity>,
     }
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
@@ -483,6 +516,8 @@ pub mod table_list {
         pub values: ::prost::alloc::vec::Vec<f32>,
         #[prost(bool, repeated, tag = "2")]
         pub set: ::prost::alloc::vec::Vec<bool>,
+        #[prost(message, optional, tag = "3")]
+        pub validity: ::core::option::Option<super::Validity>,
     }
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
@@ -491,6 +526,8 @@ pub mod table_list {
         pub values: ::prost::alloc::vec::Vec<f64>,
         #[prost(bool, repeated, tag = "2")]
         pub set: ::prost::alloc::vec::Vec<bool>,
+        #[prost(message, optional, tag = "3")]
+        pub validity: ::core::option::Option<super::Validity>,
     }
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
@@ -501,6 +538,8 @@ pub mod table_list {
         pub tz: ::core::option::Option<::prost::alloc::string::String>,
         #[prost(bool, repeated, tag = "4")]
         pub set: ::prost::alloc::vec::Vec<bool>,
+        #[prost(message, optional, tag = "5")]
+        pub validity: ::core::option::Option<super::Validity>,
     }
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
@@ -529,6 +568,8 @@ pub mod table_list {
         pub set: ::prost::alloc::vec::Vec<bool>,
         #[prost(int32, optional, tag = "3")]
         pub size: ::core::option::Option<i32>,
+        #[prost(message, optional, tag = "4")]
+        pub validity: ::core::option::Option<super::Validity>,
     }
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
@@ -537,6 +578,8 @@ pub mod table_list {
         pub values: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
         #[prost(bool, repeated, tag = "2")]
         pub set: ::prost::alloc::vec::Vec<bool>,
+        #[prost(message, optional, tag = "3")]
+        pub validity: ::core::option::Option<super::Validity>,
     }
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
@@ -555,6 +598,8 @@ pub mod table_list {
         pub values: ::prost::alloc::vec::Vec<super::TableList>,
         #[prost(bool, repeated, tag = "3")]
         pub set: ::prost::alloc::vec::Vec<bool>,
+        #[prost(message, optional, tag = "4")]
+        pub validity: ::core::option::Option<super::Validity>,
     }
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
@@ -563,6 +608,8 @@ pub mod table_list {
         pub values: ::prost::alloc::vec::Vec<super::TableScalar>,
         #[prost(bool, repeated, tag = "2")]
         pub set: ::prost::alloc::vec::Vec<bool>,
+        #[prost(message, optional, tag = "3")]
+        pub validity: ::core::option::Option<super::Validity>,
     }
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
@@ -575,6 +622,8 @@ pub mod table_list {
         pub list_type: ::core::option::Option<super::FieldProto>,
         #[prost(int32, optional, tag = "4")]
         pub size: ::core::option::Option<i32>,
+        #[prost(message, optional, tag = "5")]
+        pub validity: ::core::option::Option<super::Validity>,
     }
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
@@ -673,3 +722,54 @@ pub struct Table {
     #[prost(message, repeated, tag = "2")]
     pub values: ::prost::alloc::vec::Vec<TableList>,
 }
+/// The differences from an old table to a new one, as found by `Table::diff`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TableDiff {
+    /// Fields only in the new table.
+    #[prost(message, repeated, tag = "1")]
+    pub added_fields: ::prost::alloc::vec::Vec<FieldProto>,
+    /// Fields only in the old table.
+    #[prost(message, repeated, tag = "2")]
+    pub removed_fields: ::prost::alloc::vec::Vec<FieldProto>,
+    /// Fields in both tables whose type or nullability changed.
+    #[prost(message, repeated, tag = "3")]
+    pub changed_fields: ::prost::alloc::vec::Vec<table_diff::FieldChange>,
+    /// Rows of the new table whose key is not in the old table.
+    #[prost(message, repeated, tag = "4")]
+    pub added_rows: ::prost::alloc::vec::Vec<TableRow>,
+    /// Rows of the old table whose key is not in the new table.
+    #[prost(message, repeated, tag = "5")]
+    pub removed_rows: ::prost::alloc::vec::Vec<TableRow>,
+    /// Cells that differ between rows with the same key.
+    #[prost(message, repeated, tag = "6")]
+    pub changed_cells: ::prost::alloc::vec::Vec<table_diff::CellChange>,
+}
+/// Nested message and enum types in `TableDiff`.
+pub mod table_diff {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct FieldChange {
+        #[prost(message, optional, tag = "1")]
+        pub old_field: ::core::option::Option<super::FieldProto>,
+        #[prost(message, optional, tag = "2")]
+        pub new_field: ::core::option::Option<super::FieldProto>,
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct CellChange {
+        /// The key columns of the row.
+        #[prost(message, optional, tag = "1")]
+        pub key: ::core::option::Option<super::TableRow>,
+        #[prost(string, tag = "2")]
+        pub column: ::prost::alloc::string::String,
+        #[prost(message, optional, tag = "3")]
+        pub old_value: ::core::option::Option<super::TableScalar>,
+        #[prost(message, optional, tag = "4")]
+        pub new_value: ::core::option::Option<super::TableScalar>,
+        #[prost(uint64, tag = "5")]
+        pub old_row: u64,
+        #[prost(uint64, tag = "6")]
+        pub new_row: u64,
+    }
+}
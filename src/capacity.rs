@@ -0,0 +1,267 @@
+use arrow::datatypes::{DataType, Schema};
+
+use crate::table_list::{
+    BinaryList, BooleanList, DictionaryList, Float16List, Float32List, Float64List, Int16List,
+    Int32List, Int64List, Int8List, ListList, StructList, TimeList, UInt16List, UInt32List,
+    UInt64List, UInt8List, UnionList, Utf8List,
+};
+use crate::{table_list, ArrowScalarError, Table, TableList, Validity};
+
+/// The buffers of a list that grow by one entry per row.
+trait Buffers {
+    fn reserve(&mut self, additional: usize);
+    fn shrink_to_fit(&mut self);
+}
+
+fn shrink_validity(validity: &mut Option<Validity>) {
+    if let Some(validity) = validity.as_mut() {
+        validity.bitmap.shrink_to_fit();
+    }
+}
+
+macro_rules! flat_buffers {
+    ($list:ty, $values:ident) => {
+        impl Buffers for $list {
+            fn reserve(&mut self, additional: usize) {
+                self.$values.reserve(additional);
+                self.set.reserve(additional);
+            }
+
+            fn shrink_to_fit(&mut self) {
+                self.$values.shrink_to_fit();
+                self.set.shrink_to_fit();
+                shrink_validity(&mut self.validity);
+            }
+        }
+    };
+}
+
+flat_buffers!(BooleanList, values);
+flat_buffers!(Int8List, values);
+flat_buffers!(Int16List, values);
+flat_buffers!(Int32List, values);
+flat_buffers!(Int64List, values);
+flat_buffers!(UInt8List, values);
+flat_buffers!(UInt16List, values);
+flat_buffers!(UInt32List, values);
+flat_buffers!(UInt64List, values);
+flat_buffers!(Float16List, values);
+flat_buffers!(Float32List, values);
+flat_buffers!(Float64List, values);
+flat_buffers!(TimeList, times);
+flat_buffers!(BinaryList, values);
+flat_buffers!(Utf8List, values);
+flat_buffers!(UnionList, values);
+
+/// Each row of a list of lists is its own child list, so only the outer buffers can be
+/// reserved ahead of time.
+impl Buffers for ListList {
+    fn reserve(&mut self, additional: usize) {
+        self.values.reserve(additional);
+        self.set.reserve(additional);
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.values.shrink_to_fit();
+        self.values.iter_mut().for_each(TableList::shrink_to_fit);
+        self.set.shrink_to_fit();
+        shrink_validity(&mut self.validity);
+    }
+}
+
+impl Buffers for StructList {
+    fn reserve(&mut self, additional: usize) {
+        self.values
+            .iter_mut()
+            .for_each(|child| child.reserve(additional));
+        self.set.reserve(additional);
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.values.iter_mut().for_each(TableList::shrink_to_fit);
+        self.set.shrink_to_fit();
+        shrink_validity(&mut self.validity);
+    }
+}
+
+impl Buffers for DictionaryList {
+    fn reserve(&mut self, additional: usize) {
+        if let Some(values) = self.values.as_mut() {
+            values.reserve(additional);
+        }
+    }
+
+    fn shrink_to_fit(&mut self) {
+        if let Some(values) = self.values.as_mut() {
+            values.shrink_to_fit();
+        }
+    }
+}
+
+impl TableList {
+    fn buffers_mut(&mut self) -> Option<&mut dyn Buffers> {
+        let list: &mut dyn Buffers = match self.values.as_mut()? {
+            table_list::Values::Boolean(list) => list,
+            table_list::Values::Int8(list) => list,
+            table_list::Values::Int16(list) => list,
+            table_list::Values::Int32(list)
+            | table_list::Values::Date32(list)
+            | table_list::Values::Time32Second(list)
+            | table_list::Values::Time32Millisecond(list)
+            | table_list::Values::IntervalYearMonth(list) => list,
+            table_list::Values::Int64(list)
+            | table_list::Values::Date64(list)
+            | table_list::Values::Time64Microsecond(list)
+            | table_list::Values::Time64Nanosecond(list)
+            | table_list::Values::DurationSecond(list)
+            | table_list::Values::DurationMillisecond(list)
+            | table_list::Values::DurationMicrosecond(list)
+            | table_list::Values::DurationNanosecond(list)
+            | table_list::Values::IntervalDayTime(list) => list,
+            table_list::Values::Uint8(list) => list,
+            table_list::Values::Uint16(list) => list,
+            table_list::Values::Uint32(list) => list,
+            table_list::Values::Uint64(list) => list,
+            table_list::Values::Float16(list) => list,
+            table_list::Values::Float32(list) => list,
+            table_list::Values::Float64(list) => list,
+            table_list::Values::TimestampSecond(list)
+            | table_list::Values::TimestampMillisecond(list)
+            | table_list::Values::TimestampMicrosecond(list)
+            | table_list::Values::TimestampNanosecond(list) => list,
+            table_list::Values::Binary(list)
+            | table_list::Values::LargeBinary(list)
+            | table_list::Values::FixedSizeBinary(list) => list,
+            table_list::Values::Utf8(list) | table_list::Values::LargeUtf8(list) => list,
+            table_list::Values::List(list)
+            | table_list::Values::LargeList(list)
+            | table_list::Values::FixedSizeList(list) => list,
+            table_list::Values::Union(list) => list,
+            table_list::Values::Dictionary(dict) => dict.as_mut(),
+            table_list::Values::Struct(list) => list,
+        };
+        Some(list)
+    }
+
+    /// Creates an empty list with room for `capacity` rows, see [`TableList::reserve`].
+    pub fn with_capacity(data_type: &DataType, capacity: usize) -> Result<Self, ArrowScalarError> {
+        let mut list = TableList::new(data_type)?;
+        list.reserve(capacity);
+        Ok(list)
+    }
+
+    /// Reserves room for at least `additional` more rows in the values and validity of
+    /// this list, and in the children of a struct. The items of string, binary and list
+    /// rows are not reserved.
+    pub fn reserve(&mut self, additional: usize) {
+        if let Some(list) = self.buffers_mut() {
+            list.reserve(additional);
+        }
+    }
+
+    /// Shrinks the buffers of this list and its children to fit their contents. Call it
+    /// after [`TableList::clear`] to release the memory kept for reuse.
+    pub fn shrink_to_fit(&mut self) {
+        if let Some(list) = self.buffers_mut() {
+            list.shrink_to_fit();
+        }
+    }
+}
+
+impl Table {
+    /// Creates an empty table with room for `capacity` rows in every column.
+    pub fn with_capacity(schema: &Schema, capacity: usize) -> Result<Self, ArrowScalarError> {
+        let mut table = Table::new(schema)?;
+        table.reserve(capacity);
+        Ok(table)
+    }
+
+    /// Reserves room for at least `additional` more rows in every column, see
+    /// [`TableList::reserve`].
+    pub fn reserve(&mut self, additional: usize) {
+        for column in self.values.iter_mut() {
+            column.reserve(additional);
+        }
+    }
+
+    /// Shrinks every column to fit its contents, see [`TableList::shrink_to_fit`].
+    pub fn shrink_to_fit(&mut self) {
+        for column in self.values.iter_mut() {
+            column.shrink_to_fit();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::datatypes::Field;
+
+    use super::*;
+    use crate::TableScalar;
+
+    #[test]
+    fn test_with_capacity() {
+        let list = TableList::with_capacity(&DataType::Int32, 100).unwrap();
+        let Some(table_list::Values::Int32(list)) = list.values else {
+            panic!("expected an Int32 list");
+        };
+        assert!(list.values.capacity() >= 100);
+        assert!(list.set.capacity() >= 100);
+
+        let data_type = DataType::Struct(vec![
+            Field::new("a", DataType::Utf8, true),
+            Field::new(
+                "b",
+                DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Float64)),
+                true,
+            ),
+        ]);
+        let schema = Schema::new(vec![Field::new("s", data_type, true)]);
+        let table = Table::with_capacity(&schema, 50).unwrap();
+        let Some(table_list::Values::Struct(list)) = &table.values[0].values else {
+            panic!("expected a struct list");
+        };
+        assert!(list.set.capacity() >= 50);
+        let Some(table_list::Values::Utf8(a)) = &list.values[0].values else {
+            panic!("expected a Utf8 list");
+        };
+        assert!(a.values.capacity() >= 50);
+        let Some(table_list::Values::Dictionary(b)) = &list.values[1].values else {
+            panic!("expected a dictionary list");
+        };
+        let Some(table_list::Values::Float64(b)) = &b.values.as_ref().unwrap().values else {
+            panic!("expected a Float64 list");
+        };
+        assert!(b.values.capacity() >= 50);
+
+        let list_type = DataType::List(Box::new(Field::new("item", DataType::Int32, true)));
+        let list = TableList::with_capacity(&list_type, 10).unwrap();
+        assert_eq!(list.len(), 0);
+    }
+
+    #[test]
+    fn test_reserve_and_shrink() {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, true),
+        ]);
+        let mut table = Table::new(&schema).unwrap();
+        let empty = table.memory_size();
+        table.reserve(1000);
+        let reserved = table.memory_size();
+        assert!(reserved >= empty + 1000 * 8);
+
+        let mut list = TableList::with_capacity(&DataType::Utf8, 10).unwrap();
+        list.push(TableScalar::utf8("abc".to_string())).unwrap();
+        list.clear();
+        list.shrink_to_fit();
+        assert_eq!(
+            list.memory_size(),
+            TableList::new(&DataType::Utf8).unwrap().memory_size()
+        );
+
+        table.shrink_to_fit();
+        assert_eq!(table.memory_size(), empty);
+        assert_eq!(table.len(), 0);
+    }
+}
@@ -0,0 +1,187 @@
+use arrow::error::ArrowError;
+use prost::Message;
+
+use crate::{ArrowScalarError, Table};
+
+fn invalid_argument(message: String) -> ArrowScalarError {
+    ArrowScalarError::ArrowError(ArrowError::InvalidArgumentError(message))
+}
+
+impl Table {
+    /// Splits the table into tables of at most `max_rows` rows, sharing its fields. A table
+    /// without rows gives one empty chunk, so the fields still reach the receiver.
+    pub fn chunks(&self, max_rows: usize) -> Result<Vec<Table>, ArrowScalarError> {
+        if max_rows == 0 {
+            return Err(invalid_argument("chunks of 0 rows".to_string()));
+        }
+        let len = self.len();
+        if len == 0 {
+            return Ok(vec![self.clone()]);
+        }
+        (0..len)
+            .step_by(max_rows)
+            .map(|offset| self.slice(offset, max_rows.min(len - offset)))
+            .collect()
+    }
+
+    /// Splits the table into tables whose protobuf encoding, as measured by
+    /// [`Message::encoded_len`], is at most `max_encoded_bytes`. Each chunk holds as many rows
+    /// as fit, so a row that does not fit on its own is an error.
+    pub fn chunks_by_bytes(
+        &self,
+        max_encoded_bytes: usize,
+    ) -> Result<Vec<Table>, ArrowScalarError> {
+        let len = self.len();
+        let mut chunks = vec![];
+        let mut offset = 0;
+        loop {
+            let (rows, chunk) = self.largest_chunk(offset, max_encoded_bytes)?;
+            offset += rows;
+            chunks.push(chunk);
+            if offset >= len {
+                return Ok(chunks);
+            }
+        }
+    }
+
+    /// The longest slice starting at `offset` that encodes to at most `max` bytes, found by
+    /// doubling and then bisecting its length. It holds at least one row unless the table
+    /// has none left.
+    fn largest_chunk(&self, offset: usize, max: usize) -> Result<(usize, Table), ArrowScalarError> {
+        let remaining = self.len() - offset;
+        let fits = |rows: usize| -> Result<Option<Table>, ArrowScalarError> {
+            let chunk = self.slice(offset, rows)?;
+            Ok(if chunk.encoded_len() <= max {
+                Some(chunk)
+            } else {
+                None
+            })
+        };
+
+        let smallest = remaining.min(1);
+        let Some(mut best) = fits(smallest)? else {
+            return Err(invalid_argument(format!(
+                "row {} does not fit in {} encoded bytes",
+                offset, max
+            )));
+        };
+        let mut low = smallest;
+        let mut high = remaining + 1;
+        // Grow until a length does not fit, then bisect between the last two lengths.
+        let mut rows = smallest;
+        while rows < remaining {
+            rows = (rows * 2).min(remaining);
+            match fits(rows)? {
+                Some(chunk) => {
+                    low = rows;
+                    best = chunk;
+                }
+                None => {
+                    high = rows;
+                    break;
+                }
+            }
+        }
+        while high - low > 1 {
+            let middle = low + (high - low) / 2;
+            match fits(middle)? {
+                Some(chunk) => {
+                    low = middle;
+                    best = chunk;
+                }
+                None => high = middle,
+            }
+        }
+        Ok((low, best))
+    }
+
+    /// Concatenates `tables`, which must all have the same fields, into one table. This undoes
+    /// [`Table::chunks`] and [`Table::chunks_by_bytes`].
+    pub fn concat(tables: &[Table]) -> Result<Table, ArrowScalarError> {
+        let mut iter = tables.iter();
+        let mut result = iter.next().cloned().unwrap_or_default();
+        for table in iter {
+            result.extend(table)?;
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::array::{ArrayRef, Int32Array, StringArray};
+    use arrow::record_batch::RecordBatch;
+
+    use super::*;
+
+    fn table(rows: i32) -> Table {
+        let batch = RecordBatch::try_from_iter(vec![
+            (
+                "a",
+                Arc::new(Int32Array::from((0..rows).collect::<Vec<_>>())) as ArrayRef,
+            ),
+            (
+                "b",
+                Arc::new(StringArray::from(
+                    (0..rows)
+                        .map(|i| "x".repeat(i as usize % 7))
+                        .collect::<Vec<_>>(),
+                )) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+        Table::from_arrow(&batch).unwrap()
+    }
+
+    #[test]
+    fn test_chunks() {
+        let table = table(10);
+        let chunks = table.chunks(4).unwrap();
+        assert_eq!(
+            chunks.iter().map(Table::len).collect::<Vec<_>>(),
+            vec![4, 4, 2]
+        );
+        assert!(chunks.iter().all(|chunk| chunk.fields == table.fields));
+        assert_eq!(Table::concat(&chunks).unwrap(), table);
+        assert_eq!(table.chunks(10).unwrap(), vec![table.clone()]);
+        assert!(table.chunks(0).is_err());
+
+        let empty = table.slice(0, 0).unwrap();
+        assert_eq!(empty.chunks(3).unwrap(), vec![empty.clone()]);
+        assert_eq!(Table::concat(&[]).unwrap(), Table::default());
+        let mut other = table.clone();
+        other.fields[0].name = "c".to_string();
+        assert!(matches!(
+            Table::concat(&[table, other]),
+            Err(ArrowScalarError::TypeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_chunks_by_bytes() {
+        let table = table(1000);
+        let max = 2000;
+        let chunks = table.chunks_by_bytes(max).unwrap();
+        assert!(chunks.len() > 1);
+        let mut offset = 0;
+        for chunk in chunks.iter() {
+            assert!(chunk.encoded_len() <= max);
+            assert!(!chunk.is_empty());
+            offset += chunk.len();
+            // Every chunk but the last is full: one more row would not fit.
+            if offset < table.len() {
+                let bigger = table.slice(offset - chunk.len(), chunk.len() + 1).unwrap();
+                assert!(bigger.encoded_len() > max);
+            }
+        }
+        assert_eq!(Table::concat(&chunks).unwrap(), table);
+
+        let whole = table.chunks_by_bytes(table.encoded_len()).unwrap();
+        assert_eq!(whole, vec![table.clone()]);
+        let empty = table.slice(0, 0).unwrap();
+        assert_eq!(empty.chunks_by_bytes(max).unwrap(), vec![empty]);
+        assert!(table.chunks_by_bytes(10).is_err());
+    }
+}
@@ -0,0 +1,850 @@
+use std::ops::Range;
+
+use crate::validity::ListValidity;
+use crate::{table_list, table_scalar, ArrowScalarError, ScalarValuable, TableList, TableScalar};
+
+/// Row-level operations shared by every list message, so `TableList` can apply them without
+/// caring which variant it holds.
+pub(crate) trait ListColumn: Sized {
+    /// Builds a new column from the rows at `indices`. Indices must be in bounds.
+    fn gather(&self, indices: &[usize]) -> Self;
+    /// Builds a new column from `len` rows starting at `offset`. The range must be in bounds.
+    fn slice_rows(&self, offset: usize, len: usize) -> Self;
+    /// Whether the rows of `other` can be appended to `self`.
+    fn compatible(&self, other: &Self) -> bool;
+    /// Appends the rows of `other`. The columns must be `compatible`.
+    fn append(&mut self, other: &Self);
+    /// Replaces the rows in `range` with the rows of `other`. The range must be in bounds and
+    /// the columns must be `compatible`.
+    fn splice_rows(&mut self, range: Range<usize>, other: &Self);
+}
+
+impl<T: ListColumn> ListColumn for Box<T> {
+    fn gather(&self, indices: &[usize]) -> Self {
+        Box::new(self.as_ref().gather(indices))
+    }
+    fn slice_rows(&self, offset: usize, len: usize) -> Self {
+        Box::new(self.as_ref().slice_rows(offset, len))
+    }
+    fn compatible(&self, other: &Self) -> bool {
+        self.as_ref().compatible(other)
+    }
+    fn append(&mut self, other: &Self) {
+        self.as_mut().append(other)
+    }
+    fn splice_rows(&mut self, range: Range<usize>, other: &Self) {
+        self.as_mut().splice_rows(range, other)
+    }
+}
+
+/// Implements `ListColumn` for a message holding one `values` vector and its validity,
+/// copying over the remaining type metadata. Results keep the validity form of `self`.
+macro_rules! impl_list_column {
+    ($list_type:ident, $values:ident $(, $meta:ident)*) => {
+        impl ListColumn for table_list::$list_type {
+            fn gather(&self, indices: &[usize]) -> Self {
+                let mut list = table_list::$list_type {
+                    $values: indices.iter().map(|i| &self.$values[*i]).cloned().collect(),
+                    set: indices.iter().map(|i| self.is_valid(*i)).collect(),
+                    validity: None,
+                    $($meta: self.$meta.clone(),)*
+                };
+                if self.is_packed() {
+                    list.pack();
+                }
+                list
+            }
+            fn slice_rows(&self, offset: usize, len: usize) -> Self {
+                let mut list = table_list::$list_type {
+                    $values: self.$values[offset..offset + len].to_vec(),
+                    set: (offset..offset + len).map(|i| self.is_valid(i)).collect(),
+                    validity: None,
+                    $($meta: self.$meta.clone(),)*
+                };
+                if self.is_packed() {
+                    list.pack();
+                }
+                list
+            }
+            fn compatible(&self, _other: &Self) -> bool {
+                true $(&& self.$meta == _other.$meta)*
+            }
+            fn append(&mut self, other: &Self) {
+                self.extend_validity(other.validity_ref().iter(other.row_count()));
+                self.$values.extend_from_slice(&other.$values);
+            }
+            fn splice_rows(&mut self, range: Range<usize>, other: &Self) {
+                let packed = self.is_packed();
+                self.unpack();
+                self.set
+                    .splice(range.clone(), other.validity_ref().iter(other.row_count()));
+                self.$values.splice(range, other.$values.iter().cloned());
+                if packed {
+                    self.pack();
+                }
+            }
+        }
+    };
+}
+
+impl_list_column!(BooleanList, values);
+impl_list_column!(Int8List, values);
+impl_list_column!(Int16List, values);
+impl_list_column!(Int32List, values);
+impl_list_column!(Int64List, values);
+impl_list_column!(UInt8List, values);
+impl_list_column!(UInt16List, values);
+impl_list_column!(UInt32List, values);
+impl_list_column!(UInt64List, values);
+impl_list_column!(Float16List, values);
+impl_list_column!(Float32List, values);
+impl_list_column!(Float64List, values);
+impl_list_column!(TimeList, times, tz);
+impl_list_column!(BinaryList, values, size);
+impl_list_column!(Utf8List, values);
+impl_list_column!(UnionList, values);
+impl_list_column!(ListList, values, list_type, size);
+
+impl ListColumn for table_list::StructList {
+    fn gather(&self, indices: &[usize]) -> Self {
+        let mut list = table_list::StructList {
+            fields: self.fields.clone(),
+            values: self.values.iter().map(|v| v.gather(indices)).collect(),
+            set: indices.iter().map(|i| self.is_valid(*i)).collect(),
+            validity: None,
+        };
+        if self.is_packed() {
+            list.pack();
+        }
+        list
+    }
+    fn slice_rows(&self, offset: usize, len: usize) -> Self {
+        let mut list = table_list::StructList {
+            fields: self.fields.clone(),
+            values: self
+                .values
+                .iter()
+                .map(|v| v.slice_rows(offset, len))
+                .collect(),
+            set: (offset..offset + len).map(|i| self.is_valid(i)).collect(),
+            validity: None,
+        };
+        if self.is_packed() {
+            list.pack();
+        }
+        list
+    }
+    fn compatible(&self, other: &Self) -> bool {
+        self.fields == other.fields
+            && self.values.len() == other.values.len()
+            && self
+                .values
+                .iter()
+                .zip(other.values.iter())
+                .all(|(a, b)| a.compatible(b))
+    }
+    fn append(&mut self, other: &Self) {
+        self.extend_validity(other.validity_ref().iter(other.row_count()));
+        for (values, other_values) in self.values.iter_mut().zip(other.values.iter()) {
+            values.append(other_values);
+        }
+    }
+    fn splice_rows(&mut self, range: Range<usize>, other: &Self) {
+        let packed = self.is_packed();
+        self.unpack();
+        self.set
+            .splice(range.clone(), other.validity_ref().iter(other.row_count()));
+        for (values, other_values) in self.values.iter_mut().zip(other.values.iter()) {
+            values.splice_rows(range.clone(), other_values);
+        }
+        if packed {
+            self.pack();
+        }
+    }
+}
+
+impl ListColumn for table_list::DictionaryList {
+    fn gather(&self, indices: &[usize]) -> Self {
+        table_list::DictionaryList {
+            values: self.values.as_ref().map(|v| v.gather(indices)),
+            index_type: self.index_type.clone(),
+        }
+    }
+    fn slice_rows(&self, offset: usize, len: usize) -> Self {
+        table_list::DictionaryList {
+            values: self.values.as_ref().map(|v| v.slice_rows(offset, len)),
+            index_type: self.index_type.clone(),
+        }
+    }
+    fn compatible(&self, other: &Self) -> bool {
+        self.index_type == other.index_type
+            && match (&self.values, &other.values) {
+                (Some(values), Some(other_values)) => values.compatible(other_values),
+                _ => true,
+            }
+    }
+    fn append(&mut self, other: &Self) {
+        match (&mut self.values, &other.values) {
+            (Some(values), Some(other_values)) => values.append(other_values),
+            (values @ None, other_values) => *values = other_values.clone(),
+            (_, None) => {}
+        }
+    }
+    fn splice_rows(&mut self, range: Range<usize>, other: &Self) {
+        match (&mut self.values, &other.values) {
+            (Some(values), Some(other_values)) => values.splice_rows(range, other_values),
+            (values @ None, other_values) => *values = other_values.clone(),
+            (_, None) => {}
+        }
+    }
+}
+
+/// Applies `$expr` to the message inside `$values`, rewrapping the result in the same variant.
+macro_rules! map_list_values {
+    ($values:expr, $list:ident => $expr:expr) => {
+        match $values {
+            table_list::Values::Boolean($list) => table_list::Values::Boolean($expr),
+            table_list::Values::Int8($list) => table_list::Values::Int8($expr),
+            table_list::Values::Int16($list) => table_list::Values::Int16($expr),
+            table_list::Values::Int32($list) => table_list::Values::Int32($expr),
+            table_list::Values::Int64($list) => table_list::Values::Int64($expr),
+            table_list::Values::Uint8($list) => table_list::Values::Uint8($expr),
+            table_list::Values::Uint16($list) => table_list::Values::Uint16($expr),
+            table_list::Values::Uint32($list) => table_list::Values::Uint32($expr),
+            table_list::Values::Uint64($list) => table_list::Values::Uint64($expr),
+            table_list::Values::Float16($list) => table_list::Values::Float16($expr),
+            table_list::Values::Float32($list) => table_list::Values::Float32($expr),
+            table_list::Values::Float64($list) => table_list::Values::Float64($expr),
+            table_list::Values::Date32($list) => table_list::Values::Date32($expr),
+            table_list::Values::Date64($list) => table_list::Values::Date64($expr),
+            table_list::Values::Time32Second($list) => table_list::Values::Time32Second($expr),
+            table_list::Values::Time32Millisecond($list) => {
+                table_list::Values::Time32Millisecond($expr)
+            }
+            table_list::Values::Time64Microsecond($list) => {
+                table_list::Values::Time64Microsecond($expr)
+            }
+            table_list::Values::Time64Nanosecond($list) => {
+                table_list::Values::Time64Nanosecond($expr)
+            }
+            table_list::Values::TimestampSecond($list) => {
+                table_list::Values::TimestampSecond($expr)
+            }
+            table_list::Values::TimestampMillisecond($list) => {
+                table_list::Values::TimestampMillisecond($expr)
+            }
+            table_list::Values::TimestampMicrosecond($list) => {
+                table_list::Values::TimestampMicrosecond($expr)
+            }
+            table_list::Values::TimestampNanosecond($list) => {
+                table_list::Values::TimestampNanosecond($expr)
+            }
+            table_list::Values::DurationSecond($list) => table_list::Values::DurationSecond($expr),
+            table_list::Values::DurationMillisecond($list) => {
+                table_list::Values::DurationMillisecond($expr)
+            }
+            table_list::Values::DurationMicrosecond($list) => {
+                table_list::Values::DurationMicrosecond($expr)
+            }
+            table_list::Values::DurationNanosecond($list) => {
+                table_list::Values::DurationNanosecond($expr)
+            }
+            table_list::Values::IntervalYearMonth($list) => {
+                table_list::Values::IntervalYearMonth($expr)
+            }
+            table_list::Values::IntervalDayTime($list) => {
+                table_list::Values::IntervalDayTime($expr)
+            }
+            table_list::Values::Binary($list) => table_list::Values::Binary($expr),
+            table_list::Values::LargeBinary($list) => table_list::Values::LargeBinary($expr),
+            table_list::Values::FixedSizeBinary($list) => {
+                table_list::Values::FixedSizeBinary($expr)
+            }
+            table_list::Values::Utf8($list) => table_list::Values::Utf8($expr),
+            table_list::Values::LargeUtf8($list) => table_list::Values::LargeUtf8($expr),
+            table_list::Values::List($list) => table_list::Values::List($expr),
+            table_list::Values::LargeList($list) => table_list::Values::LargeList($expr),
+            table_list::Values::FixedSizeList($list) => table_list::Values::FixedSizeList($expr),
+            table_list::Values::Union($list) => table_list::Values::Union($expr),
+            table_list::Values::Dictionary($list) => table_list::Values::Dictionary($expr),
+            table_list::Values::Struct($list) => table_list::Values::Struct($expr),
+        }
+    };
+}
+
+/// Applies `$expr` to the messages inside two `Values` of the same variant, or evaluates
+/// `$mismatch` when the variants differ.
+macro_rules! zip_list_values {
+    ($values:expr, $other_values:expr, ($list:ident, $other:ident) => $expr:expr, _ => $mismatch:expr) => {
+        match ($values, $other_values) {
+            (table_list::Values::Boolean($list), table_list::Values::Boolean($other)) => $expr,
+            (table_list::Values::Int8($list), table_list::Values::Int8($other)) => $expr,
+            (table_list::Values::Int16($list), table_list::Values::Int16($other)) => $expr,
+            (table_list::Values::Int32($list), table_list::Values::Int32($other)) => $expr,
+            (table_list::Values::Int64($list), table_list::Values::Int64($other)) => $expr,
+            (table_list::Values::Uint8($list), table_list::Values::Uint8($other)) => $expr,
+            (table_list::Values::Uint16($list), table_list::Values::Uint16($other)) => $expr,
+            (table_list::Values::Uint32($list), table_list::Values::Uint32($other)) => $expr,
+            (table_list::Values::Uint64($list), table_list::Values::Uint64($other)) => $expr,
+            (table_list::Values::Float16($list), table_list::Values::Float16($other)) => $expr,
+            (table_list::Values::Float32($list), table_list::Values::Float32($other)) => $expr,
+            (table_list::Values::Float64($list), table_list::Values::Float64($other)) => $expr,
+            (table_list::Values::Date32($list), table_list::Values::Date32($other)) => $expr,
+            (table_list::Values::Date64($list), table_list::Values::Date64($other)) => $expr,
+            (table_list::Values::Time32Second($list), table_list::Values::Time32Second($other)) => {
+                $expr
+            }
+            (
+                table_list::Values::Time32Millisecond($list),
+                table_list::Values::Time32Millisecond($other),
+            ) => $expr,
+            (
+                table_list::Values::Time64Microsecond($list),
+                table_list::Values::Time64Microsecond($other),
+            ) => $expr,
+            (
+                table_list::Values::Time64Nanosecond($list),
+                table_list::Values::Time64Nanosecond($other),
+            ) => $expr,
+            (
+                table_list::Values::TimestampSecond($list),
+                table_list::Values::TimestampSecond($other),
+            ) => $expr,
+            (
+                table_list::Values::TimestampMillisecond($list),
+                table_list::Values::TimestampMillisecond($other),
+            ) => $expr,
+            (
+                table_list::Values::TimestampMicrosecond($list),
+                table_list::Values::TimestampMicrosecond($other),
+            ) => $expr,
+            (
+                table_list::Values::TimestampNanosecond($list),
+                table_list::Values::TimestampNanosecond($other),
+            ) => $expr,
+            (
+                table_list::Values::DurationSecond($list),
+                table_list::Values::DurationSecond($other),
+            ) => $expr,
+            (
+                table_list::Values::DurationMillisecond($list),
+                table_list::Values::DurationMillisecond($other),
+            ) => $expr,
+            (
+                table_list::Values::DurationMicrosecond($list),
+                table_list::Values::DurationMicrosecond($other),
+            ) => $expr,
+            (
+                table_list::Values::DurationNanosecond($list),
+                table_list::Values::DurationNanosecond($other),
+            ) => $expr,
+            (
+                table_list::Values::IntervalYearMonth($list),
+                table_list::Values::IntervalYearMonth($other),
+            ) => $expr,
+            (
+                table_list::Values::IntervalDayTime($list),
+                table_list::Values::IntervalDayTime($other),
+            ) => $expr,
+            (table_list::Values::Binary($list), table_list::Values::Binary($other)) => $expr,
+            (table_list::Values::LargeBinary($list), table_list::Values::LargeBinary($other)) => {
+                $expr
+            }
+            (
+                table_list::Values::FixedSizeBinary($list),
+                table_list::Values::FixedSizeBinary($other),
+            ) => $expr,
+            (table_list::Values::Utf8($list), table_list::Values::Utf8($other)) => $expr,
+            (table_list::Values::LargeUtf8($list), table_list::Values::LargeUtf8($other)) => $expr,
+            (table_list::Values::List($list), table_list::Values::List($other)) => $expr,
+            (table_list::Values::LargeList($list), table_list::Values::LargeList($other)) => $expr,
+            (
+                table_list::Values::FixedSizeList($list),
+                table_list::Values::FixedSizeList($other),
+            ) => $expr,
+            (table_list::Values::Union($list), table_list::Values::Union($other)) => $expr,
+            (table_list::Values::Dictionary($list), table_list::Values::Dictionary($other)) => {
+                $expr
+            }
+            (table_list::Values::Struct($list), table_list::Values::Struct($other)) => $expr,
+            _ => $mismatch,
+        }
+    };
+}
+
+impl ListColumn for TableList {
+    fn gather(&self, indices: &[usize]) -> Self {
+        TableList {
+            values: self
+                .values
+                .as_ref()
+                .map(|values| map_list_values!(values, list => list.gather(indices))),
+        }
+    }
+    fn slice_rows(&self, offset: usize, len: usize) -> Self {
+        TableList {
+            values: self
+                .values
+                .as_ref()
+                .map(|values| map_list_values!(values, list => list.slice_rows(offset, len))),
+        }
+    }
+    fn compatible(&self, other: &Self) -> bool {
+        match (&self.values, &other.values) {
+            (Some(values), Some(other_values)) => zip_list_values!(
+                values,
+                other_values,
+                (list, other_list) => list.compatible(other_list),
+                _ => false
+            ),
+            _ => true,
+        }
+    }
+    fn append(&mut self, other: &Self) {
+        match (&mut self.values, &other.values) {
+            (Some(values), Some(other_values)) => zip_list_values!(
+                values,
+                other_values,
+                (list, other_list) => list.append(other_list),
+                _ => {}
+            ),
+            (values @ None, other_values) => *values = other_values.clone(),
+            (_, None) => {}
+        }
+    }
+    fn splice_rows(&mut self, range: Range<usize>, other: &Self) {
+        match (&mut self.values, &other.values) {
+            (Some(values), Some(other_values)) => zip_list_values!(
+                values,
+                other_values,
+                (list, other_list) => list.splice_rows(range, other_list),
+                _ => {}
+            ),
+            (values @ None, other_values) => *values = other_values.clone(),
+            (_, None) => {}
+        }
+    }
+}
+
+impl TableList {
+    /// Returns `len` rows starting at `offset`. The list is validated first, so a malformed
+    /// list is an error rather than a panic.
+    pub fn slice(&self, offset: usize, len: usize) -> Result<TableList, ArrowScalarError> {
+        self.validate()?;
+        match offset.checked_add(len) {
+            Some(end) if end <= self.len() => Ok(self.slice_rows(offset, len)),
+            _ => Err(ArrowScalarError::AccessError {
+                index: offset.saturating_add(len),
+                len: self.len(),
+            }),
+        }
+    }
+
+    /// Returns the rows at `indices`, in that order. Indices may repeat.
+    pub fn take(&self, indices: &[usize]) -> Result<TableList, ArrowScalarError> {
+        self.validate()?;
+        let len = self.len();
+        if let Some(index) = indices.iter().find(|i| **i >= len) {
+            return Err(ArrowScalarError::AccessError { index: *index, len });
+        }
+        Ok(self.gather(indices))
+    }
+
+    /// Returns the rows where `mask` is true. The mask must be as long as the list.
+    pub fn filter(&self, mask: &[bool]) -> Result<TableList, ArrowScalarError> {
+        self.validate()?;
+        if mask.len() != self.len() {
+            return Err(ArrowScalarError::LengthMismatch {
+                expected: self.len(),
+                actual: mask.len(),
+            });
+        }
+        let indices = mask
+            .iter()
+            .enumerate()
+            .filter_map(|(i, keep)| if *keep { Some(i) } else { None })
+            .collect::<Vec<_>>();
+        Ok(self.gather(&indices))
+    }
+
+    /// Appends the rows of `other`, which must hold the same type. `other` is validated
+    /// first, so `self` is left unchanged on error.
+    pub fn extend_from(&mut self, other: &TableList) -> Result<(), ArrowScalarError> {
+        other.validate()?;
+        self.check_extend(other)?;
+        self.append(other);
+        Ok(())
+    }
+
+    /// Concatenates `lists`, which must all hold the same type, into one list.
+    pub fn concat(lists: &[TableList]) -> Result<TableList, ArrowScalarError> {
+        let mut iter = lists.iter();
+        let mut result = match iter.next() {
+            Some(first) => {
+                first.validate()?;
+                first.clone()
+            }
+            None => TableList::default(),
+        };
+        for list in iter {
+            result.extend_from(list)?;
+        }
+        Ok(result)
+    }
+
+    /// Replaces the value at `index` with `scalar`, which must have the type of this list.
+    pub fn set(&mut self, index: usize, scalar: TableScalar) -> Result<(), ArrowScalarError> {
+        self.validate()?;
+        self.check_index(index, self.len())?;
+        let row = self.single_row(scalar)?;
+        self.splice_rows(index..index + 1, &row);
+        Ok(())
+    }
+
+    /// Makes the value at `index` null.
+    pub fn set_null(&mut self, index: usize) -> Result<(), ArrowScalarError> {
+        self.set(index, TableScalar::default())
+    }
+
+    /// Inserts `scalar` at `index`, shifting the rows after it. `index` may be the length of
+    /// the list.
+    pub fn insert(&mut self, index: usize, scalar: TableScalar) -> Result<(), ArrowScalarError> {
+        self.validate()?;
+        self.check_index(index, self.len() + 1)?;
+        let row = self.single_row(scalar)?;
+        self.splice_rows(index..index, &row);
+        Ok(())
+    }
+
+    /// Removes and returns the value at `index`, shifting the rows after it.
+    pub fn remove(&mut self, index: usize) -> Result<TableScalar, ArrowScalarError> {
+        self.validate()?;
+        self.check_index(index, self.len())?;
+        let scalar = self.scalar(index)?;
+        let empty = self.slice_rows(0, 0);
+        self.splice_rows(index..index + 1, &empty);
+        Ok(scalar)
+    }
+
+    /// Removes and returns the value at `index`, replacing it with the last row. This does
+    /// not preserve the order of the rows, but does not shift them either.
+    pub fn swap_remove(&mut self, index: usize) -> Result<TableScalar, ArrowScalarError> {
+        self.validate()?;
+        let len = self.len();
+        self.check_index(index, len)?;
+        let scalar = self.scalar(index)?;
+        let last = self.slice_rows(len - 1, 1);
+        let empty = self.slice_rows(0, 0);
+        self.splice_rows(len - 1..len, &empty);
+        if index < len - 1 {
+            self.splice_rows(index..index + 1, &last);
+        }
+        Ok(scalar)
+    }
+
+    fn check_index(&self, index: usize, len: usize) -> Result<(), ArrowScalarError> {
+        if index < len {
+            Ok(())
+        } else {
+            Err(ArrowScalarError::AccessError {
+                index,
+                len: self.len(),
+            })
+        }
+    }
+
+    /// A list of the same type as this one holding only `scalar`. Dictionaries also take the
+    /// plain values returned by `scalar`.
+    fn single_row(&self, scalar: TableScalar) -> Result<TableList, ArrowScalarError> {
+        let mut row = self.slice_rows(0, 0);
+        let scalar = match (&row.values, scalar.value) {
+            (Some(table_list::Values::Dictionary(_)), Some(value))
+                if !matches!(value, table_scalar::Value::Dictionary(_)) =>
+            {
+                TableScalar {
+                    value: Some(table_scalar::Value::Dictionary(Box::new(TableScalar {
+                        value: Some(value),
+                    }))),
+                }
+            }
+            (_, value) => TableScalar { value },
+        };
+        row.push(scalar)?;
+        self.check_extend(&row)?;
+        Ok(row)
+    }
+
+    pub(crate) fn check_extend(&self, other: &TableList) -> Result<(), ArrowScalarError> {
+        if self.compatible(other) {
+            Ok(())
+        } else {
+            Err(ArrowScalarError::TypeMismatch {
+                expected: self.type_name(),
+                actual: other.type_name(),
+            })
+        }
+    }
+
+    pub(crate) fn type_name(&self) -> String {
+        match &self.values {
+            None => "Null".to_string(),
+            Some(table_list::Values::Union(_)) => "Union".to_string(),
+            Some(table_list::Values::Dictionary(_)) => "Dictionary".to_string(),
+            Some(_) => match self.data_type() {
+                Ok(data_type) => format!("{:?}", data_type),
+                Err(_) => "Unknown".to_string(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::array::*;
+    use arrow::datatypes::*;
+
+    use super::*;
+    use crate::{ListValuable, TableScalar, ValidityRef};
+
+    #[test]
+    fn test_slice() {
+        let array = Int32Array::from(vec![Some(1), None, Some(3), Some(4)]);
+        let list = array.clone_as_list().unwrap();
+        let sliced = list.slice(1, 2).unwrap();
+        assert_eq!(sliced, array.slice(1, 2).clone_as_list().unwrap());
+        assert_eq!(list.slice(4, 0).unwrap().len(), 0);
+        assert!(list.slice(3, 2).is_err());
+    }
+
+    #[test]
+    fn test_take() {
+        let array = StringArray::from(vec![Some("a"), None, Some("c")]);
+        let list = array.clone_as_list().unwrap();
+        let taken = list.take(&[2, 0, 2, 1]).unwrap();
+        let expected = StringArray::from(vec![Some("c"), Some("a"), Some("c"), None]);
+        assert_eq!(taken, expected.clone_as_list().unwrap());
+        assert!(list.take(&[3]).is_err());
+    }
+
+    #[test]
+    fn test_filter_nested() {
+        let data = vec![
+            Some(vec![Some(0), Some(1)]),
+            None,
+            Some(vec![Some(3), None]),
+        ];
+        let list_array = Arc::new(ListArray::from_iter_primitive::<Int32Type, _, _>(data));
+        let bool_array = Arc::new(BooleanArray::from(vec![Some(true), None, Some(false)]));
+        let struct_array = StructArray::from(vec![
+            (
+                Field::new("bo", DataType::Boolean, true),
+                bool_array as ArrayRef,
+            ),
+            (
+                Field::new(
+                    "list",
+                    DataType::List(Box::new(Field::new("item", DataType::Int32, true))),
+                    true,
+                ),
+                list_array as ArrayRef,
+            ),
+        ]);
+        let list = struct_array.clone_as_list().unwrap();
+        let filtered = list.filter(&[false, true, true]).unwrap();
+        assert_eq!(filtered.len(), 2);
+        assert_eq!(filtered.scalar_at(0), list.scalar_at(1));
+        assert_eq!(filtered.scalar_at(1), list.scalar_at(2));
+        assert_eq!(
+            filtered.to_array().unwrap().data_type(),
+            struct_array.data_type()
+        );
+        assert!(list.filter(&[true]).is_err());
+
+        let mut short = list.clone();
+        if let Some(table_list::Values::Struct(list)) = short.values.as_mut() {
+            if let Some(table_list::Values::List(list)) = list.values[1].values.as_mut() {
+                list.values.truncate(1);
+            }
+        }
+        assert!(matches!(
+            short.filter(&[true, true, true]).unwrap_err().root(),
+            ArrowScalarError::InvalidList(_)
+        ));
+        assert!(short.take(&[2]).is_err());
+        assert!(short.slice(1, 2).is_err());
+
+        let array: DictionaryArray<Int8Type> = vec!["x", "y", "z"].into_iter().collect();
+        let list = array.clone_as_list().unwrap();
+        let filtered = list.filter(&[true, false, true]).unwrap();
+        assert_eq!(
+            filtered.iter().collect::<Vec<_>>(),
+            vec![
+                TableScalar::utf8("x".to_string()),
+                TableScalar::utf8("z".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extend_and_concat() {
+        let first = Int64Array::from(vec![Some(1), None]);
+        let second = Int64Array::from(vec![None, Some(4)]);
+        let mut list = first.clone_as_list().unwrap();
+        list.extend_from(&second.clone_as_list().unwrap()).unwrap();
+        let expected = Int64Array::from(vec![Some(1), None, None, Some(4)]);
+        assert_eq!(list, expected.clone_as_list().unwrap());
+
+        // Packed lists stay packed, also once a null follows only valid rows.
+        let values = (0..12)
+            .map(|i| if i % 5 == 4 { None } else { Some(i) })
+            .collect::<Vec<_>>();
+        let whole = Int64Array::from(values.clone()).clone_as_list().unwrap();
+        let mut list = Int64Array::from(values[..3].to_vec())
+            .clone_as_list()
+            .unwrap();
+        list.extend_from(&whole.slice(3, 9).unwrap()).unwrap();
+        assert_eq!(list, whole);
+        let mut unpacked = whole.slice(0, 3).unwrap();
+        unpacked.unpack_validity();
+        unpacked.extend_from(&whole.slice(3, 9).unwrap()).unwrap();
+        assert!(matches!(
+            unpacked.validity(),
+            Some(ValidityRef::Set(set)) if set.len() == 12
+        ));
+        unpacked.pack_validity();
+        assert_eq!(unpacked, whole);
+
+        let other = Int32Array::from(vec![1]).clone_as_list().unwrap();
+        assert!(matches!(
+            list.extend_from(&other),
+            Err(ArrowScalarError::TypeMismatch { .. })
+        ));
+
+        let seconds = TimestampSecondArray::from(vec![1]).with_timezone("UTC".to_string());
+        let local = TimestampSecondArray::from(vec![2]);
+        let mut seconds = seconds.clone_as_list().unwrap();
+        assert!(seconds
+            .extend_from(&local.clone_as_list().unwrap())
+            .is_err());
+
+        let data = vec![Some(vec![Some(0), Some(1)]), None];
+        let list_array = ListArray::from_iter_primitive::<Int32Type, _, _>(data);
+        let list = list_array.clone_as_list().unwrap();
+        let concat =
+            TableList::concat(&[list.clone(), list.slice(1, 1).unwrap(), list.clone()]).unwrap();
+        assert_eq!(concat.len(), 5);
+        assert_eq!(concat.scalar_at(3), concat.scalar_at(0));
+        assert_eq!(concat.scalar_at(2), concat.scalar_at(1));
+        assert_eq!(TableList::concat(&[]).unwrap().len(), 0);
+
+        let mut short = list.clone();
+        short.unpack_validity();
+        if let Some(table_list::Values::List(list)) = short.values.as_mut() {
+            list.set.truncate(1);
+        }
+        let mut extended = list.clone();
+        assert!(extended.extend_from(&short).is_err());
+        assert_eq!(extended, list);
+        assert!(TableList::concat(&[short.clone(), list.clone()]).is_err());
+        assert!(TableList::concat(&[list.clone(), short]).is_err());
+
+        let array: DictionaryArray<Int8Type> = vec!["x", "y"].into_iter().collect();
+        let dict = array.clone_as_list().unwrap();
+        let concat = TableList::concat(&[dict.clone(), dict]).unwrap();
+        assert_eq!(concat.len(), 4);
+        assert_eq!(concat.scalar_at(2), TableScalar::utf8("x".to_string()));
+    }
+
+    #[test]
+    fn test_set_insert_remove() {
+        let mut list = Int32Array::from(vec![Some(1), None, Some(3)])
+            .clone_as_list()
+            .unwrap();
+        list.pack_validity();
+        list.set(1, TableScalar::int32(2)).unwrap();
+        list.set_null(0).unwrap();
+        list.insert(3, TableScalar::int32(4)).unwrap();
+        assert_eq!(
+            list.to_array().unwrap().as_ref(),
+            &Int32Array::from(vec![None, Some(2), Some(3), Some(4)]) as &dyn Array
+        );
+        assert_eq!(list.swap_remove(0).unwrap(), TableScalar::default());
+        assert_eq!(list.remove(0).unwrap(), TableScalar::int32(4));
+        assert_eq!(
+            list.iter().collect::<Vec<_>>(),
+            vec![TableScalar::int32(2), TableScalar::int32(3)]
+        );
+        assert!(matches!(
+            list.set(0, TableScalar::utf8("a".to_string())),
+            Err(ArrowScalarError::InvalidScalar(_))
+        ));
+        assert!(matches!(
+            list.remove(2),
+            Err(ArrowScalarError::AccessError { index: 2, len: 2 })
+        ));
+        assert!(list.insert(3, TableScalar::int32(0)).is_err());
+        assert_eq!(list.len(), 2);
+
+        let data = vec![Some(vec![Some(0), Some(1)]), None, Some(vec![])];
+        let list_array = ListArray::from_iter_primitive::<Int32Type, _, _>(data);
+        let bool_array = BooleanArray::from(vec![Some(true), None, Some(false)]);
+        let struct_array = StructArray::from(vec![
+            (
+                Field::new("bo", DataType::Boolean, true),
+                Arc::new(bool_array) as ArrayRef,
+            ),
+            (
+                Field::new("list", list_array.data_type().clone(), true),
+                Arc::new(list_array) as ArrayRef,
+            ),
+        ]);
+        let mut list = struct_array.clone_as_list().unwrap();
+        let first = list.scalar_at(0);
+        let second = list.scalar_at(1);
+        list.set(2, first.clone()).unwrap();
+        list.insert(0, second.clone()).unwrap();
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.remove(1).unwrap(), first);
+        list.set_null(0).unwrap();
+        assert_eq!(list.swap_remove(0).unwrap(), TableScalar::default());
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.scalar_at(0), first);
+        assert_eq!(list.scalar_at(1), second);
+        assert!(list.to_array().is_ok());
+
+        let mut short = list.clone();
+        if let Some(table_list::Values::Struct(list)) = short.values.as_mut() {
+            if let Some(table_list::Values::List(list)) = list.values[1].values.as_mut() {
+                list.values.truncate(1);
+            }
+        }
+        assert!(short.set(1, first.clone()).is_err());
+        assert!(short.set_null(1).is_err());
+        assert!(short.insert(2, second).is_err());
+        assert!(short.remove(1).is_err());
+        assert!(short.swap_remove(0).is_err());
+
+        let array: DictionaryArray<Int8Type> = vec!["x", "y", "z"].into_iter().collect();
+        let mut dict = array.clone_as_list().unwrap();
+        dict.set(0, TableScalar::utf8("w".to_string())).unwrap();
+        assert_eq!(
+            dict.swap_remove(1).unwrap(),
+            TableScalar::utf8("y".to_string())
+        );
+        assert_eq!(
+            dict.iter().collect::<Vec<_>>(),
+            vec![
+                TableScalar::utf8("w".to_string()),
+                TableScalar::utf8("z".to_string())
+            ]
+        );
+    }
+
+    impl TableList {
+        fn scalar_at(&self, i: usize) -> TableScalar {
+            self.iter().nth(i).unwrap()
+        }
+    }
+}
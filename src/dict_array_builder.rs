@@ -4,15 +4,18 @@ use arrow::{array::*, datatypes::*};
 use half::f16;
 
 use crate::{TableList, table_list, ArrowScalarError};
+use crate::list::dictionary_key;
+use crate::validity::ListValidity;
 
 
 pub(crate) fn dict_builder<T: ArrowDictionaryKeyType>(list: &TableList) -> Result<ArrayRef, ArrowScalarError> {
 
     match &list.values {
-        Some(table_list::Values::Int8(table_list::Int8List {values, set})) => {
+        Some(table_list::Values::Int8(child)) => {
+            let (values, validity) = (&child.values, child.validity_ref());
             let mut builder = PrimitiveDictionaryBuilder::<T, Int8Type>::new();
-            for (v, s) in values.iter().zip(set.iter()) {
-                if *s {
+            for (v, s) in values.iter().zip(validity.iter(values.len())) {
+                if s {
                     builder.append(*v as i8).map_err(ArrowScalarError::ArrowError)?;
                 } else {
                     builder.append_null();
@@ -20,10 +23,11 @@ pub(crate) fn dict_builder<T: ArrowDictionaryKeyType>(list: &TableList) -> Resul
             }
             Ok(Arc::new(builder.finish()))
         }
-        Some(table_list::Values::Int16(table_list::Int16List {values, set})) => {
+        Some(table_list::Values::Int16(child)) => {
+            let (values, validity) = (&child.values, child.validity_ref());
             let mut builder = PrimitiveDictionaryBuilder::<T, Int16Type>::new();
-            for (v, s) in values.iter().zip(set.iter()) {
-                if *s {
+            for (v, s) in values.iter().zip(validity.iter(values.len())) {
+                if s {
                     builder.append(*v as i16).map_err(ArrowScalarError::ArrowError)?;
                 } else {
                     builder.append_null();
@@ -31,10 +35,11 @@ pub(crate) fn dict_builder<T: ArrowDictionaryKeyType>(list: &TableList) -> Resul
             }
             Ok(Arc::new(builder.finish()))
         }
-        Some(table_list::Values::Int32(table_list::Int32List {values, set})) => {
+        Some(table_list::Values::Int32(child)) => {
+            let (values, validity) = (&child.values, child.validity_ref());
             let mut builder = PrimitiveDictionaryBuilder::<T, Int32Type>::new();
-            for (v, s) in values.iter().zip(set.iter()) {
-                if *s {
+            for (v, s) in values.iter().zip(validity.iter(values.len())) {
+                if s {
                     builder.append(*v).map_err(ArrowScalarError::ArrowError)?;
                 } else {
                     builder.append_null();
@@ -42,10 +47,11 @@ pub(crate) fn dict_builder<T: ArrowDictionaryKeyType>(list: &TableList) -> Resul
             }
             Ok(Arc::new(builder.finish()))
         }
-        Some(table_list::Values::Int64(table_list::Int64List {values, set})) => {
+        Some(table_list::Values::Int64(child)) => {
+            let (values, validity) = (&child.values, child.validity_ref());
             let mut builder = PrimitiveDictionaryBuilder::<T, Int64Type>::new();
-            for (v, s) in values.iter().zip(set.iter()) {
-                if *s {
+            for (v, s) in values.iter().zip(validity.iter(values.len())) {
+                if s {
                     builder.append(*v).map_err(ArrowScalarError::ArrowError)?;
                 } else {
                     builder.append_null();
@@ -53,10 +59,11 @@ pub(crate) fn dict_builder<T: ArrowDictionaryKeyType>(list: &TableList) -> Resul
             }
             Ok(Arc::new(builder.finish()))
         }
-        Some(table_list::Values::Uint8(table_list::UInt8List {values, set})) => {
+        Some(table_list::Values::Uint8(child)) => {
+            let (values, validity) = (&child.values, child.validity_ref());
             let mut builder = PrimitiveDictionaryBuilder::<T, UInt8Type>::new();
-            for (v, s) in values.iter().zip(set.iter()) {
-                if *s {
+            for (v, s) in values.iter().zip(validity.iter(values.len())) {
+                if s {
                     builder.append(*v as u8).map_err(ArrowScalarError::ArrowError)?;
                 } else {
                     builder.append_null();
@@ -64,10 +71,11 @@ pub(crate) fn dict_builder<T: ArrowDictionaryKeyType>(list: &TableList) -> Resul
             }
             Ok(Arc::new(builder.finish()))
         }
-        Some(table_list::Values::Uint16(table_list::UInt16List {values, set})) => {
+        Some(table_list::Values::Uint16(child)) => {
+            let (values, validity) = (&child.values, child.validity_ref());
             let mut builder = PrimitiveDictionaryBuilder::<T, UInt16Type>::new();
-            for (v, s) in values.iter().zip(set.iter()) {
-                if *s {
+            for (v, s) in values.iter().zip(validity.iter(values.len())) {
+                if s {
                     builder.append(*v as u16).map_err(ArrowScalarError::ArrowError)?;
                 } else {
                     builder.append_null();
@@ -75,10 +83,11 @@ pub(crate) fn dict_builder<T: ArrowDictionaryKeyType>(list: &TableList) -> Resul
             }
             Ok(Arc::new(builder.finish()))
         }
-        Some(table_list::Values::Uint32(table_list::UInt32List {values, set})) => {
+        Some(table_list::Values::Uint32(child)) => {
+            let (values, validity) = (&child.values, child.validity_ref());
             let mut builder = PrimitiveDictionaryBuilder::<T, UInt32Type>::new();
-            for (v, s) in values.iter().zip(set.iter()) {
-                if *s {
+            for (v, s) in values.iter().zip(validity.iter(values.len())) {
+                if s {
                     builder.append(*v).map_err(ArrowScalarError::ArrowError)?;
                 } else {
                     builder.append_null();
@@ -86,10 +95,11 @@ pub(crate) fn dict_builder<T: ArrowDictionaryKeyType>(list: &TableList) -> Resul
             }
             Ok(Arc::new(builder.finish()))
         }
-        Some(table_list::Values::Uint64(table_list::UInt64List {values, set})) => {
+        Some(table_list::Values::Uint64(child)) => {
+            let (values, validity) = (&child.values, child.validity_ref());
             let mut builder = PrimitiveDictionaryBuilder::<T, UInt64Type>::new();
-            for (v, s) in values.iter().zip(set.iter()) {
-                if *s {
+            for (v, s) in values.iter().zip(validity.iter(values.len())) {
+                if s {
                     builder.append(*v).map_err(ArrowScalarError::ArrowError)?;
                 } else {
                     builder.append_null();
@@ -97,10 +107,11 @@ pub(crate) fn dict_builder<T: ArrowDictionaryKeyType>(list: &TableList) -> Resul
             }
             Ok(Arc::new(builder.finish()))
         }
-        Some(table_list::Values::Float16(table_list::Float16List {values, set})) => {
+        Some(table_list::Values::Float16(child)) => {
+            let (values, validity) = (&child.values, child.validity_ref());
             let mut builder = PrimitiveDictionaryBuilder::<T, Float16Type>::new();
-            for (v, s) in values.iter().zip(set.iter()) {
-                if *s {
+            for (v, s) in values.iter().zip(validity.iter(values.len())) {
+                if s {
                     builder.append(f16::from_f32(*v)).map_err(ArrowScalarError::ArrowError)?;
                 } else {
                     builder.append_null();
@@ -108,10 +119,11 @@ pub(crate) fn dict_builder<T: ArrowDictionaryKeyType>(list: &TableList) -> Resul
             }
             Ok(Arc::new(builder.finish()))
         }
-        Some(table_list::Values::Float32(table_list::Float32List {values, set})) => {
+        Some(table_list::Values::Float32(child)) => {
+            let (values, validity) = (&child.values, child.validity_ref());
             let mut builder = PrimitiveDictionaryBuilder::<T, Float32Type>::new();
-            for (v, s) in values.iter().zip(set.iter()) {
-                if *s {
+            for (v, s) in values.iter().zip(validity.iter(values.len())) {
+                if s {
                     builder.append(*v).map_err(ArrowScalarError::ArrowError)?;
                 } else {
                     builder.append_null();
@@ -119,10 +131,11 @@ pub(crate) fn dict_builder<T: ArrowDictionaryKeyType>(list: &TableList) -> Resul
             }
             Ok(Arc::new(builder.finish()))
         }
-        Some(table_list::Values::Float64(table_list::Float64List {values, set})) => {
+        Some(table_list::Values::Float64(child)) => {
+            let (values, validity) = (&child.values, child.validity_ref());
             let mut builder = PrimitiveDictionaryBuilder::<T, Float64Type>::new();
-            for (v, s) in values.iter().zip(set.iter()) {
-                if *s {
+            for (v, s) in values.iter().zip(validity.iter(values.len())) {
+                if s {
                     builder.append(*v).map_err(ArrowScalarError::ArrowError)?;
                 } else {
                     builder.append_null();
@@ -130,10 +143,11 @@ pub(crate) fn dict_builder<T: ArrowDictionaryKeyType>(list: &TableList) -> Resul
             }
             Ok(Arc::new(builder.finish()))
         }
-        Some(table_list::Values::Utf8(table_list::Utf8List {values, set})) => {
+        Some(table_list::Values::Utf8(child)) => {
+            let (values, validity) = (&child.values, child.validity_ref());
             let mut builder = StringDictionaryBuilder::<T>::new();
-            for (v, s) in values.iter().zip(set.iter()) {
-                if *s {
+            for (v, s) in values.iter().zip(validity.iter(values.len())) {
+                if s {
                     builder.append(v).map_err(ArrowScalarError::ArrowError)?;
                 } else {
                     builder.append_null();
@@ -149,7 +163,12 @@ pub(crate) fn list_dict_builder<T: ArrowDictionaryKeyType>(data_type: DataType,
     match data_type {
         DataType::Utf8 => {
             let mut list_builder = ListBuilder::new(StringDictionaryBuilder::<T>::new());
-            for values in list.values.iter() {
+            let validity = list.validity_ref();
+            for (values, valid) in list.values.iter().zip(validity.iter(list.values.len())) {
+                if !valid {
+                    list_builder.append(false);
+                    continue;
+                }
                 let values = match values.values.as_ref() {
                     Some(table_list::Values::Dictionary(dict)) => {
                         if let Some(vals) =  dict.values.as_ref() {
@@ -164,15 +183,16 @@ pub(crate) fn list_dict_builder<T: ArrowDictionaryKeyType>(data_type: DataType,
                         continue;
                     }
                     _ => {
-                        return Err(ArrowScalarError::InvalidProtobuf);
+                        return Err(ArrowScalarError::InvalidProtobuf("TableList.values"));
                     }
                 };
 
                 match values.values.as_ref() {
-                    Some(table_list::Values::Utf8(table_list::Utf8List {values, set})) => {
+                    Some(table_list::Values::Utf8(child)) => {
+                        let (values, validity) = (&child.values, child.validity_ref());
                         let values_builder = list_builder.values();
-                        for (v, s) in values.iter().zip(set.iter()) {
-                            if *s {
+                        for (v, s) in values.iter().zip(validity.iter(values.len())) {
+                            if s {
                                 values_builder.append(v).map_err(ArrowScalarError::ArrowError)?;
                             } else {
                                 values_builder.append_null();
@@ -184,7 +204,7 @@ pub(crate) fn list_dict_builder<T: ArrowDictionaryKeyType>(data_type: DataType,
                         list_builder.append(false);
                     }
                     _ => {
-                        return Err(ArrowScalarError::InvalidProtobuf);
+                        return Err(ArrowScalarError::InvalidProtobuf("TableList.values"));
                     }
                 }
             }
@@ -197,22 +217,27 @@ pub(crate) fn list_dict_builder<T: ArrowDictionaryKeyType>(data_type: DataType,
 pub(crate) fn dict_array_to_proto<T:ArrowDictionaryKeyType>(array: &DictionaryArray<T>, values_type: &DataType) -> Result<table_list::DictionaryList, ArrowScalarError> {
     match values_type {
         DataType::Utf8 => {
-            let dict_values = array.values().as_any().downcast_ref::<StringArray>().unwrap();
+            let dict_values = array.values().as_any().downcast_ref::<StringArray>().ok_or_else(|| {
+                ArrowScalarError::type_mismatch(DataType::Utf8, array.values().data_type())
+            })?;
             let mut values = Vec::with_capacity(array.len());
             let mut set = Vec::with_capacity(array.len());
             let keys = array.keys();
             for key in keys.iter() {
                 if let Some(key) = key {
                     set.push(true);
-                    values.push(dict_values.value(key.to_usize().unwrap()).to_string());
+                    let key = dictionary_key(key)?;
+                    values.push(dict_values.value(key).to_string());
                 } else {
                     set.push(false);
+                    values.push(String::new());
                 }
             }
             let string_list = TableList {
                 values: Some(table_list::Values::Utf8(table_list::Utf8List {
                     values,
                     set,
+                    validity: None,
                 })),
             };
             Ok(table_list::DictionaryList {
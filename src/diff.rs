@@ -0,0 +1,381 @@
+use std::collections::{HashMap, VecDeque};
+
+use prost::Message;
+
+use crate::table_diff::{CellChange, FieldChange};
+use crate::{
+    table_scalar, ApproxEqOptions, ArrowScalarError, FieldProto, RowValuable, ScalarValuable,
+    Table, TableDiff, TableList, TableRow, TableScalar,
+};
+
+/// Cells are compared exactly, but NaN equals NaN and values behind nulls are ignored.
+const EXACT: ApproxEqOptions = ApproxEqOptions {
+    tolerance: 0.0,
+    nan_eq: true,
+    ignore_row_order: false,
+};
+
+fn scalar(table: &Table, column: usize, row: usize) -> Result<TableScalar, ArrowScalarError> {
+    let list = table
+        .values
+        .get(column)
+        .ok_or(ArrowScalarError::InvalidProtobuf("Table.values"))?;
+    let name = table
+        .fields
+        .get(column)
+        .map(|field| field.name.as_str())
+        .unwrap_or_default();
+    list.scalar(row)
+        .map_err(|error| error.at_row(row).in_column(name))
+}
+
+fn encode_list(list: &TableList, key: &mut Vec<u8>) -> Result<(), ArrowScalarError> {
+    key.extend((list.len() as u64).to_le_bytes());
+    for i in 0..list.len() {
+        encode_key(&list.scalar(i)?, key)?;
+    }
+    Ok(())
+}
+
+/// Appends a canonical encoding of `scalar` to `key`, so that equal scalars give equal keys.
+/// The protobuf encoding is not canonical: struct fields are kept in a `HashMap` and lists
+/// have several validity forms. Those are encoded one field or row at a time instead, each
+/// behind a marker byte.
+fn encode_key(scalar: &TableScalar, key: &mut Vec<u8>) -> Result<(), ArrowScalarError> {
+    match &scalar.value {
+        None | Some(table_scalar::Value::Null(_)) => key.push(0),
+        Some(table_scalar::Value::Struct(value)) => {
+            key.push(1);
+            let mut names = value.elements.keys().collect::<Vec<_>>();
+            names.sort();
+            key.extend((names.len() as u64).to_le_bytes());
+            for name in names {
+                key.extend((name.len() as u64).to_le_bytes());
+                key.extend(name.as_bytes());
+                encode_key(&value.elements[name], key)?;
+            }
+        }
+        Some(
+            table_scalar::Value::List(list)
+            | table_scalar::Value::LargeList(list)
+            | table_scalar::Value::FixedSizeList(list),
+        ) => {
+            key.push(2);
+            encode_list(list, key)?;
+        }
+        Some(table_scalar::Value::Map(map)) => {
+            key.push(3);
+            for list in [&map.keys, &map.values] {
+                encode_list(list.as_ref().unwrap_or(&TableList::default()), key)?;
+            }
+        }
+        Some(table_scalar::Value::Union(inner) | table_scalar::Value::Dictionary(inner)) => {
+            key.push(4);
+            encode_key(inner, key)?;
+        }
+        Some(_) => {
+            key.push(5);
+            key.extend(scalar.encode_length_delimited_to_vec());
+        }
+    }
+    Ok(())
+}
+
+/// The key of a row, as the concatenated canonical encodings of its key scalars.
+fn key(table: &Table, columns: &[usize], row: usize) -> Result<Vec<u8>, ArrowScalarError> {
+    let mut key = vec![];
+    for column in columns {
+        encode_key(&scalar(table, *column, row)?, &mut key)?;
+    }
+    Ok(key)
+}
+
+impl Table {
+    /// Compares this table with a newer version of it. Rows are matched on `key_columns`,
+    /// which must be in both tables with the same type. Rows that share a key are matched
+    /// in order, so with no key columns at all rows are matched by position. Cells are
+    /// compared in the columns of both tables whose type is unchanged.
+    pub fn diff(&self, other: &Table, key_columns: &[&str]) -> Result<TableDiff, ArrowScalarError> {
+        let old_keys = key_columns
+            .iter()
+            .map(|name| self.column_index(name))
+            .collect::<Result<Vec<_>, _>>()?;
+        let new_keys = key_columns
+            .iter()
+            .map(|name| other.column_index(name))
+            .collect::<Result<Vec<_>, _>>()?;
+        for (old, new) in old_keys.iter().zip(new_keys.iter()) {
+            if let (Some(old), Some(new)) = (self.fields.get(*old), other.fields.get(*new)) {
+                if old.data_type != new.data_type {
+                    return Err(ArrowScalarError::type_mismatch(
+                        old.to_arrow()?.data_type(),
+                        new.to_arrow()?.data_type(),
+                    )
+                    .in_column(&old.name));
+                }
+            }
+        }
+
+        let mut diff = TableDiff::default();
+        let mut compared = vec![];
+        for (old_index, old) in self.fields.iter().enumerate() {
+            match other
+                .fields
+                .iter()
+                .enumerate()
+                .find(|(_, new)| new.name == old.name)
+            {
+                Some((new_index, new)) => {
+                    if old != new {
+                        diff.changed_fields.push(FieldChange {
+                            old_field: Some(old.clone()),
+                            new_field: Some(new.clone()),
+                        });
+                    }
+                    if old.data_type == new.data_type {
+                        compared.push((old_index, new_index, &old.name));
+                    }
+                }
+                None => diff.removed_fields.push(old.clone()),
+            }
+        }
+        diff.added_fields = other
+            .fields
+            .iter()
+            .filter(|new| !self.fields.iter().any(|old| old.name == new.name))
+            .cloned()
+            .collect::<Vec<FieldProto>>();
+
+        let mut new_rows = HashMap::<Vec<u8>, VecDeque<usize>>::new();
+        for row in 0..other.len() {
+            new_rows
+                .entry(key(other, &new_keys, row)?)
+                .or_default()
+                .push_back(row);
+        }
+        let mut matched = vec![false; other.len()];
+        for old_row in 0..self.len() {
+            let new_row = new_rows
+                .get_mut(&key(self, &old_keys, old_row)?)
+                .and_then(|rows| rows.pop_front());
+            let Some(new_row) = new_row else {
+                diff.removed_rows.push(self.row(old_row)?);
+                continue;
+            };
+            if let Some(matched) = matched.get_mut(new_row) {
+                *matched = true;
+            }
+            for (old_column, new_column, name) in compared.iter() {
+                let old_value = scalar(self, *old_column, old_row)?;
+                let new_value = scalar(other, *new_column, new_row)?;
+                if !old_value.approx_eq(&new_value, EXACT) {
+                    let values = key_columns
+                        .iter()
+                        .zip(old_keys.iter())
+                        .map(|(name, column)| {
+                            Ok((name.to_string(), scalar(self, *column, old_row)?))
+                        })
+                        .collect::<Result<HashMap<_, _>, ArrowScalarError>>()?;
+                    diff.changed_cells.push(CellChange {
+                        key: Some(TableRow { values }),
+                        column: name.to_string(),
+                        old_value: Some(old_value),
+                        new_value: Some(new_value),
+                        old_row: old_row as u64,
+                        new_row: new_row as u64,
+                    });
+                }
+            }
+        }
+        for (new_row, matched) in matched.into_iter().enumerate() {
+            if !matched {
+                diff.added_rows.push(other.row(new_row)?);
+            }
+        }
+        Ok(diff)
+    }
+}
+
+impl TableDiff {
+    /// Whether the two tables had the same fields and rows.
+    pub fn is_empty(&self) -> bool {
+        self == &TableDiff::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::array::{
+        ArrayRef, Float64Array, Int32Array, Int64Array, ListArray, StringArray, StructArray,
+    };
+    use arrow::datatypes::{DataType, Field, Int32Type};
+    use arrow::record_batch::RecordBatch;
+
+    use super::*;
+
+    fn table(columns: Vec<(&str, ArrayRef)>) -> Table {
+        Table::from_arrow(&RecordBatch::try_from_iter(columns).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_diff() {
+        let old = table(vec![
+            ("id", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+            (
+                "name",
+                Arc::new(StringArray::from(vec!["a", "b", "c"])) as ArrayRef,
+            ),
+            (
+                "score",
+                Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef,
+            ),
+            (
+                "gone",
+                Arc::new(Int32Array::from(vec![0, 0, 0])) as ArrayRef,
+            ),
+        ]);
+        let new = table(vec![
+            ("id", Arc::new(Int32Array::from(vec![3, 1, 4])) as ArrayRef),
+            (
+                "name",
+                Arc::new(StringArray::from(vec!["c", "x", "d"])) as ArrayRef,
+            ),
+            (
+                "score",
+                Arc::new(Int64Array::from(vec![3, 1, 4])) as ArrayRef,
+            ),
+            (
+                "extra",
+                Arc::new(Float64Array::from(vec![f64::NAN; 3])) as ArrayRef,
+            ),
+        ]);
+        let diff = old.diff(&new, &["id"]).unwrap();
+
+        let names =
+            |fields: &[FieldProto]| fields.iter().map(|f| f.name.clone()).collect::<Vec<_>>();
+        assert_eq!(names(&diff.added_fields), vec!["extra"]);
+        assert_eq!(names(&diff.removed_fields), vec!["gone"]);
+        assert_eq!(diff.changed_fields.len(), 1);
+        assert_eq!(
+            diff.changed_fields[0].new_field.as_ref().unwrap().name,
+            "score"
+        );
+
+        assert_eq!(diff.removed_rows, vec![old.row(1).unwrap()]);
+        // The new row holds a NaN, so it cannot be compared with `==`.
+        assert_eq!(diff.added_rows.len(), 1);
+        assert_eq!(diff.added_rows[0].values["id"], TableScalar::int32(4));
+        assert_eq!(
+            diff.changed_cells,
+            vec![CellChange {
+                key: Some(TableRow {
+                    values: vec![("id".to_string(), TableScalar::int32(1))]
+                        .into_iter()
+                        .collect(),
+                }),
+                column: "name".to_string(),
+                old_value: Some(TableScalar::utf8("a".to_string())),
+                new_value: Some(TableScalar::utf8("x".to_string())),
+                old_row: 0,
+                new_row: 1,
+            }]
+        );
+
+        let decoded = TableDiff::decode(diff.encode_to_vec().as_slice()).unwrap();
+        assert_eq!(decoded.encode_to_vec().len(), diff.encode_to_vec().len());
+        assert_eq!(decoded.changed_cells, diff.changed_cells);
+        assert_eq!(decoded.removed_rows, diff.removed_rows);
+
+        assert!(new.diff(&new, &["id"]).unwrap().is_empty());
+        assert!(matches!(
+            old.diff(&new, &["score"]).unwrap_err().root(),
+            ArrowScalarError::TypeMismatch { .. }
+        ));
+        assert!(matches!(
+            old.diff(&new, &["extra"]),
+            Err(ArrowScalarError::UnknownColumn(name)) if name == "extra"
+        ));
+    }
+
+    #[test]
+    fn test_diff_by_position() {
+        let old = table(vec![(
+            "a",
+            Arc::new(Int32Array::from(vec![Some(1), None, Some(3)])) as ArrayRef,
+        )]);
+        let new = table(vec![(
+            "a",
+            Arc::new(Int32Array::from(vec![Some(1), Some(2)])) as ArrayRef,
+        )]);
+        let diff = old.diff(&new, &[]).unwrap();
+        // Only the nullability changed, so the cells are still compared.
+        assert_eq!(diff.changed_fields.len(), 1);
+        assert_eq!(diff.changed_cells.len(), 1);
+        assert_eq!(
+            diff.changed_cells[0].old_value,
+            Some(TableScalar { value: None })
+        );
+        assert_eq!(
+            (diff.changed_cells[0].old_row, diff.changed_cells[0].new_row),
+            (1, 1)
+        );
+        assert_eq!(diff.removed_rows, vec![old.row(2).unwrap()]);
+        assert!(diff.added_rows.is_empty());
+    }
+
+    #[test]
+    fn test_diff_nested_keys() {
+        // Enough fields that the `HashMap` of each struct scalar iterates in its own order.
+        let ids = |order: [i32; 3]| {
+            StructArray::from(
+                (0..8)
+                    .map(|i| {
+                        (
+                            Field::new(&format!("f{}", i), DataType::Int32, false),
+                            Arc::new(Int32Array::from(order.map(|id| id * 10 + i).to_vec()))
+                                as ArrayRef,
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+            )
+        };
+        let tags = |order: [i32; 3]| {
+            ListArray::from_iter_primitive::<Int32Type, _, _>(
+                order.map(|id| Some(vec![Some(id), None])),
+            )
+        };
+        let old = table(vec![
+            ("id", Arc::new(ids([1, 2, 3])) as ArrayRef),
+            ("tags", Arc::new(tags([1, 2, 3])) as ArrayRef),
+            (
+                "name",
+                Arc::new(StringArray::from(vec!["a", "b", "c"])) as ArrayRef,
+            ),
+        ]);
+        let mut new = table(vec![
+            ("id", Arc::new(ids([3, 1, 2])) as ArrayRef),
+            ("tags", Arc::new(tags([3, 1, 2])) as ArrayRef),
+            (
+                "name",
+                Arc::new(StringArray::from(vec!["c", "x", "b"])) as ArrayRef,
+            ),
+        ]);
+        // Equal lists in another validity form still give the same key.
+        new.values[1].unpack_validity();
+
+        let diff = old.diff(&new, &["id", "tags"]).unwrap();
+        assert!(diff.removed_rows.is_empty());
+        assert!(diff.added_rows.is_empty());
+        assert_eq!(diff.changed_cells.len(), 1);
+        assert_eq!(
+            (diff.changed_cells[0].old_row, diff.changed_cells[0].new_row),
+            (0, 1)
+        );
+        assert_eq!(
+            diff.changed_cells[0].new_value,
+            Some(TableScalar::utf8("x".to_string()))
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use std::fmt;
+
+use arrow::error::ArrowError;
+use thiserror::Error;
+
+use crate::TableScalar;
+
+#[derive(Error, Debug)]
+pub enum ArrowScalarError {
+    #[error("Method `{0}` is not available for type `{1}`")]
+    Unimplemented(&'static str, &'static str),
+    /// A required field of a message is missing or holds an invalid value.
+    #[error("Invalid Protobuf: `{0}` is missing or invalid")]
+    InvalidProtobuf(&'static str),
+    /// The scalar does not fit the list it was pushed to, and is handed back.
+    #[error("Invalid Scalar")]
+    InvalidScalar(Box<TableScalar>),
+    #[error("Out of Bounds Access Error: index `{index}` for length `{len}`")]
+    AccessError { index: usize, len: usize },
+    #[error("No column named `{0}`")]
+    UnknownColumn(String),
+    #[error("A column named `{0}` already exists")]
+    DuplicateColumn(String),
+    #[error("Length mismatch: expected `{expected}`, found `{actual}`")]
+    LengthMismatch { expected: usize, actual: usize },
+    #[error("Type mismatch: expected `{expected}`, found `{actual}`")]
+    TypeMismatch { expected: String, actual: String },
+    /// A list breaks one of the invariants checked by [`crate::TableList::validate`].
+    #[error("Invalid list: {0}")]
+    InvalidList(String),
+    /// A path such as `a.b[2]` that does not parse, or names a field, key or item that is not
+    /// there.
+    #[error("Invalid path: {0}")]
+    InvalidPath(String),
+    #[error("Arrow Error: `{0}`")]
+    ArrowError(#[from] ArrowError),
+    /// Another error, with where in a table or list it happened.
+    #[error("{context}: {source}")]
+    Context {
+        context: ErrorContext,
+        #[source]
+        source: Box<ArrowScalarError>,
+    },
+}
+
+/// Where an error happened: the column, the row and the path to a nested list, such as
+/// `.address.lines[2]` for the third item of a list in a struct field. Parts that are not
+/// known are left empty.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ErrorContext {
+    pub column: Option<String>,
+    pub row: Option<usize>,
+    pub path: String,
+}
+
+impl fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = vec![];
+        if let Some(column) = &self.column {
+            parts.push(format!("column `{}`", column));
+        }
+        if let Some(row) = self.row {
+            parts.push(format!("row {}", row));
+        }
+        if !self.path.is_empty() {
+            parts.push(format!("at `{}`", self.path));
+        }
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
+impl ArrowScalarError {
+    pub(crate) fn type_mismatch(expected: impl fmt::Debug, actual: impl fmt::Debug) -> Self {
+        ArrowScalarError::TypeMismatch {
+            expected: format!("{:?}", expected),
+            actual: format!("{:?}", actual),
+        }
+    }
+
+    /// The error without any [`ErrorContext`], for matching on what went wrong.
+    pub fn root(&self) -> &ArrowScalarError {
+        match self {
+            ArrowScalarError::Context { source, .. } => source.root(),
+            error => error,
+        }
+    }
+
+    /// Where the error happened, if it is known.
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            ArrowScalarError::Context { context, .. } => Some(context),
+            _ => None,
+        }
+    }
+
+    fn with_context(self, update: impl FnOnce(&mut ErrorContext)) -> Self {
+        match self {
+            ArrowScalarError::Context {
+                mut context,
+                source,
+            } => {
+                update(&mut context);
+                ArrowScalarError::Context { context, source }
+            }
+            error => {
+                let mut context = ErrorContext::default();
+                update(&mut context);
+                ArrowScalarError::Context {
+                    context,
+                    source: Box::new(error),
+                }
+            }
+        }
+    }
+
+    /// Records the column the error happened in, unless a column is already known.
+    pub(crate) fn in_column(self, column: &str) -> Self {
+        self.with_context(|context| {
+            context.column.get_or_insert_with(|| column.to_string());
+        })
+    }
+
+    /// Records the row the error happened in, unless a row is already known.
+    pub(crate) fn at_row(self, row: usize) -> Self {
+        self.with_context(|context| {
+            context.row.get_or_insert(row);
+        })
+    }
+
+    /// Prepends a segment to the path, as the error travels out of a nested list.
+    pub(crate) fn at_path(self, segment: &str) -> Self {
+        self.with_context(|context| context.path.insert_str(0, segment))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use super::*;
+
+    #[test]
+    fn test_context() {
+        let error = ArrowScalarError::InvalidProtobuf("list_type")
+            .at_path("[2]")
+            .at_path(".lines")
+            .at_row(4)
+            .in_column("address")
+            .in_column("ignored");
+        assert_eq!(
+            error.to_string(),
+            "column `address`, row 4, at `.lines[2]`: Invalid Protobuf: `list_type` is missing or invalid"
+        );
+        assert!(matches!(
+            error.root(),
+            ArrowScalarError::InvalidProtobuf("list_type")
+        ));
+        assert_eq!(error.context().unwrap().path, ".lines[2]");
+        assert!(error.source().is_some());
+
+        let error = ArrowScalarError::from(ArrowError::ComputeError("bad".to_string()));
+        assert!(error.source().is_some());
+        assert!(error.context().is_none());
+    }
+}
@@ -0,0 +1,406 @@
+use arrow::array::{
+    make_array, Array, BooleanArray, FixedSizeBinaryArray, GenericBinaryArray, GenericStringArray,
+    OffsetSizeTrait, PrimitiveArray,
+};
+use arrow::datatypes::*;
+use arrow::record_batch::RecordBatch;
+
+use crate::column::ListColumn;
+use crate::list::downcast;
+use crate::{
+    table_list, ArrowScalarError, FieldProto, ListPrimitiveType, ListValuable, Table, TableList,
+};
+
+/// Appends each row of `array` as its value, or `null` for a null row, along with its
+/// validity.
+fn extend_rows<T>(
+    values: &mut Vec<T>,
+    set: &mut Vec<bool>,
+    array: &dyn Array,
+    value: impl Fn(usize) -> T,
+    null: impl Fn() -> T,
+) {
+    values.reserve(array.len());
+    set.reserve(array.len());
+    for i in 0..array.len() {
+        let valid = array.is_valid(i);
+        values.push(if valid { value(i) } else { null() });
+        set.push(valid);
+    }
+}
+
+impl TableList {
+    fn extend_native<T: ListPrimitiveType>(
+        values: &mut table_list::Values,
+        array: &dyn Array,
+    ) -> Result<bool, ArrowScalarError> {
+        let array = downcast::<PrimitiveArray<T>>(array)?;
+        let Some((values, set)) = T::storage_mut(values) else {
+            return Ok(false);
+        };
+        extend_rows(
+            values,
+            set,
+            array,
+            |i| T::to_stored(array.value(i)),
+            || T::to_stored(T::default_value()),
+        );
+        Ok(true)
+    }
+
+    fn extend_strings<O: OffsetSizeTrait>(
+        values: &mut table_list::Values,
+        array: &dyn Array,
+    ) -> Result<bool, ArrowScalarError> {
+        let array = downcast::<GenericStringArray<O>>(array)?;
+        let (table_list::Values::Utf8(list) | table_list::Values::LargeUtf8(list)) = values else {
+            return Ok(false);
+        };
+        extend_rows(
+            &mut list.values,
+            &mut list.set,
+            array,
+            |i| array.value(i).to_string(),
+            String::new,
+        );
+        Ok(true)
+    }
+
+    fn extend_binary<O: OffsetSizeTrait>(
+        values: &mut table_list::Values,
+        array: &dyn Array,
+    ) -> Result<bool, ArrowScalarError> {
+        let array = downcast::<GenericBinaryArray<O>>(array)?;
+        let (table_list::Values::Binary(list) | table_list::Values::LargeBinary(list)) = values
+        else {
+            return Ok(false);
+        };
+        extend_rows(
+            &mut list.values,
+            &mut list.set,
+            array,
+            |i| array.value(i).to_vec(),
+            Vec::new,
+        );
+        Ok(true)
+    }
+
+    /// Appends flat arrays straight into the storage of this list, which already has the
+    /// type of the array. Returns `false` for the types that need a conversion first.
+    fn extend_flat(&mut self, array: &dyn Array) -> Result<bool, ArrowScalarError> {
+        let Some(values) = self.values.as_mut() else {
+            return Ok(false);
+        };
+        match array.data_type() {
+            DataType::Boolean => {
+                let array = downcast::<BooleanArray>(array)?;
+                let table_list::Values::Boolean(list) = values else {
+                    return Ok(false);
+                };
+                extend_rows(
+                    &mut list.values,
+                    &mut list.set,
+                    array,
+                    |i| array.value(i),
+                    || false,
+                );
+                Ok(true)
+            }
+            DataType::Int8 => Self::extend_native::<Int8Type>(values, array),
+            DataType::Int16 => Self::extend_native::<Int16Type>(values, array),
+            DataType::Int32 => Self::extend_native::<Int32Type>(values, array),
+            DataType::Int64 => Self::extend_native::<Int64Type>(values, array),
+            DataType::UInt8 => Self::extend_native::<UInt8Type>(values, array),
+            DataType::UInt16 => Self::extend_native::<UInt16Type>(values, array),
+            DataType::UInt32 => Self::extend_native::<UInt32Type>(values, array),
+            DataType::UInt64 => Self::extend_native::<UInt64Type>(values, array),
+            DataType::Float16 => Self::extend_native::<Float16Type>(values, array),
+            DataType::Float32 => Self::extend_native::<Float32Type>(values, array),
+            DataType::Float64 => Self::extend_native::<Float64Type>(values, array),
+            DataType::Date32 => Self::extend_native::<Date32Type>(values, array),
+            DataType::Date64 => Self::extend_native::<Date64Type>(values, array),
+            DataType::Time32(TimeUnit::Second) => {
+                Self::extend_native::<Time32SecondType>(values, array)
+            }
+            DataType::Time32(TimeUnit::Millisecond) => {
+                Self::extend_native::<Time32MillisecondType>(values, array)
+            }
+            DataType::Time64(TimeUnit::Microsecond) => {
+                Self::extend_native::<Time64MicrosecondType>(values, array)
+            }
+            DataType::Time64(TimeUnit::Nanosecond) => {
+                Self::extend_native::<Time64NanosecondType>(values, array)
+            }
+            DataType::Timestamp(TimeUnit::Second, _) => {
+                Self::extend_native::<TimestampSecondType>(values, array)
+            }
+            DataType::Timestamp(TimeUnit::Millisecond, _) => {
+                Self::extend_native::<TimestampMillisecondType>(values, array)
+            }
+            DataType::Timestamp(TimeUnit::Microsecond, _) => {
+                Self::extend_native::<TimestampMicrosecondType>(values, array)
+            }
+            DataType::Timestamp(TimeUnit::Nanosecond, _) => {
+                Self::extend_native::<TimestampNanosecondType>(values, array)
+            }
+            DataType::Duration(TimeUnit::Second) => {
+                Self::extend_native::<DurationSecondType>(values, array)
+            }
+            DataType::Duration(TimeUnit::Millisecond) => {
+                Self::extend_native::<DurationMillisecondType>(values, array)
+            }
+            DataType::Duration(TimeUnit::Microsecond) => {
+                Self::extend_native::<DurationMicrosecondType>(values, array)
+            }
+            DataType::Duration(TimeUnit::Nanosecond) => {
+                Self::extend_native::<DurationNanosecondType>(values, array)
+            }
+            DataType::Interval(IntervalUnit::YearMonth) => {
+                Self::extend_native::<IntervalYearMonthType>(values, array)
+            }
+            DataType::Interval(IntervalUnit::DayTime) => {
+                Self::extend_native::<IntervalDayTimeType>(values, array)
+            }
+            DataType::Utf8 => Self::extend_strings::<i32>(values, array),
+            DataType::LargeUtf8 => Self::extend_strings::<i64>(values, array),
+            DataType::Binary => Self::extend_binary::<i32>(values, array),
+            DataType::LargeBinary => Self::extend_binary::<i64>(values, array),
+            DataType::FixedSizeBinary(_) => {
+                let array = downcast::<FixedSizeBinaryArray>(array)?;
+                let table_list::Values::FixedSizeBinary(list) = values else {
+                    return Ok(false);
+                };
+                let size = usize::try_from(array.value_length()).unwrap_or_default();
+                extend_rows(
+                    &mut list.values,
+                    &mut list.set,
+                    array,
+                    |i| array.value(i).to_vec(),
+                    || vec![0; size],
+                );
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    /// Appends the rows of `array`, which must have the type of this list. Flat types are
+    /// copied straight into the existing storage; nested and dictionary arrays are
+    /// converted with [`ListValuable::clone_as_list`] first.
+    pub fn extend_from_array(&mut self, array: &dyn Array) -> Result<(), ArrowScalarError> {
+        let flat = match &self.values {
+            None
+            | Some(table_list::Values::List(_))
+            | Some(table_list::Values::LargeList(_))
+            | Some(table_list::Values::FixedSizeList(_))
+            | Some(table_list::Values::Struct(_))
+            | Some(table_list::Values::Union(_))
+            | Some(table_list::Values::Dictionary(_)) => false,
+            Some(_) => {
+                if &self.data_type()? != array.data_type() {
+                    return Err(ArrowScalarError::TypeMismatch {
+                        expected: self.type_name(),
+                        actual: format!("{:?}", array.data_type()),
+                    });
+                }
+                true
+            }
+        };
+        if flat && self.stage_validity(0, |list| list.extend_flat(array))? {
+            return Ok(());
+        }
+        self.extend_from(&make_array(array.data().clone()).clone_as_list()?)
+    }
+}
+
+impl Table {
+    /// Appends the rows of `batch`, whose columns must have the names and types of the
+    /// fields of this table. Nothing is appended unless every column can be.
+    pub fn extend_from_batch(&mut self, batch: &RecordBatch) -> Result<(), ArrowScalarError> {
+        let schema = batch.schema();
+        let matches = self.fields.len() == schema.fields().len()
+            && self
+                .fields
+                .iter()
+                .zip(schema.fields())
+                .all(|(field, other)| {
+                    let other = FieldProto::from_arrow(other);
+                    field.name == other.name && field.data_type == other.data_type
+                });
+        if !matches {
+            return Err(ArrowScalarError::type_mismatch(self.schema()?, schema));
+        }
+
+        let len = self.len();
+        let mut result = Ok(());
+        for ((field, column), array) in self
+            .fields
+            .iter()
+            .zip(self.values.iter_mut())
+            .zip(batch.columns())
+        {
+            result = column
+                .extend_from_array(array.as_ref())
+                .map_err(|error| error.in_column(&field.name));
+            if result.is_err() {
+                break;
+            }
+        }
+        if result.is_err() {
+            for column in self.values.iter_mut() {
+                let rows = column.len();
+                if rows > len {
+                    let empty = column.slice_rows(0, 0);
+                    column.splice_rows(len..rows, &empty);
+                }
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::array::{
+        ArrayRef, DictionaryArray, Float16Array, Int32Array, LargeStringArray, ListArray,
+        NullArray, StringArray, TimestampMicrosecondArray,
+    };
+    use half::f16;
+
+    use super::*;
+
+    #[test]
+    fn test_extend_from_array() {
+        let arrays: Vec<(ArrayRef, ArrayRef)> = vec![
+            (
+                Arc::new(Int32Array::from(vec![Some(1), None])),
+                Arc::new(Int32Array::from(vec![None, Some(4)])),
+            ),
+            (
+                Arc::new(vec![Some(f16::ONE)].into_iter().collect::<Float16Array>()),
+                Arc::new(
+                    vec![None, Some(f16::ZERO)]
+                        .into_iter()
+                        .collect::<Float16Array>(),
+                ),
+            ),
+            (
+                Arc::new(LargeStringArray::from(vec![Some("a"), None])),
+                Arc::new(LargeStringArray::from(vec![Some("bc")])),
+            ),
+            (
+                Arc::new(
+                    TimestampMicrosecondArray::from(vec![Some(1), None])
+                        .with_timezone("UTC".to_string()),
+                ),
+                Arc::new(
+                    TimestampMicrosecondArray::from(vec![Some(7)]).with_timezone("UTC".to_string()),
+                ),
+            ),
+            (
+                Arc::new(
+                    vec![Some("x"), None]
+                        .into_iter()
+                        .collect::<DictionaryArray<Int8Type>>(),
+                ),
+                Arc::new(
+                    vec![Some("y"), Some("x")]
+                        .into_iter()
+                        .collect::<DictionaryArray<Int8Type>>(),
+                ),
+            ),
+            (
+                Arc::new(ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+                    Some(vec![Some(1)]),
+                    None,
+                ])),
+                Arc::new(ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+                    Some(vec![]),
+                ])),
+            ),
+        ];
+        for (first, second) in arrays {
+            let mut list = first.clone_as_list().unwrap();
+            list.extend_from_array(second.as_ref()).unwrap();
+            let expected = arrow::compute::concat(&[first.as_ref(), second.as_ref()]).unwrap();
+            let expected = expected.clone_as_list().unwrap();
+            assert_eq!(list, expected);
+        }
+
+        let mut list = TableList::new(&DataType::Int32).unwrap();
+        list.extend_from_array(&Int32Array::from(vec![1, 2]))
+            .unwrap();
+        assert_eq!(list.len(), 2);
+        assert!(matches!(
+            list.extend_from_array(&StringArray::from(vec!["a"])),
+            Err(ArrowScalarError::TypeMismatch { .. })
+        ));
+        let utc = TimestampMicrosecondArray::from(vec![1]).with_timezone("UTC".to_string());
+        let mut list = TimestampMicrosecondArray::from(vec![1])
+            .clone_as_list()
+            .unwrap();
+        assert!(list.extend_from_array(&utc).is_err());
+        assert_eq!(list.len(), 1);
+    }
+
+    #[test]
+    fn test_extend_from_batch() {
+        let batch = |a: Vec<i32>, b: Vec<&str>| {
+            RecordBatch::try_from_iter(vec![
+                ("a", Arc::new(Int32Array::from(a)) as ArrayRef),
+                ("b", Arc::new(StringArray::from(b)) as ArrayRef),
+            ])
+            .unwrap()
+        };
+        let mut table = Table::from_arrow(&batch(vec![1], vec!["x"])).unwrap();
+        table
+            .extend_from_batch(&batch(vec![2, 3], vec!["y", "z"]))
+            .unwrap();
+        assert_eq!(
+            table.to_arrow().unwrap(),
+            batch(vec![1, 2, 3], vec!["x", "y", "z"])
+        );
+
+        let other = RecordBatch::try_from_iter(vec![(
+            "a",
+            Arc::new(Int32Array::from(vec![4])) as ArrayRef,
+        )])
+        .unwrap();
+        assert!(matches!(
+            table.extend_from_batch(&other),
+            Err(ArrowScalarError::TypeMismatch { .. })
+        ));
+        assert_eq!(table.len(), 3);
+
+        // The `n` column cannot be converted, so `a` is truncated back in place.
+        let fields = vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("n", DataType::Null, true),
+        ];
+        let mut table = Table {
+            fields: fields.iter().map(FieldProto::from_arrow).collect(),
+            values: vec![
+                Int32Array::from(vec![Some(1), None])
+                    .clone_as_list()
+                    .unwrap(),
+                TableList::default(),
+            ],
+        };
+        let before = table.clone();
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(fields)),
+            vec![
+                Arc::new(Int32Array::from(vec![2])) as ArrayRef,
+                Arc::new(NullArray::new(1)) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        assert!(matches!(
+            table.extend_from_batch(&batch).unwrap_err().root(),
+            ArrowScalarError::Unimplemented(..)
+        ));
+        assert_eq!(table, before);
+    }
+}
@@ -1,5 +1,17 @@
+//! Library code returns [`ArrowScalarError`] instead of panicking, as messages often come from
+//! untrusted clients. Tests are free to unwrap.
+#![cfg_attr(
+    not(test),
+    deny(
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::unreachable,
+        clippy::todo,
+        clippy::unimplemented
+    )
+)]
 mod scalar;
-use arrow::error::ArrowError;
 pub use scalar::*;
 mod list;
 pub use list::*;
@@ -7,21 +19,30 @@ mod arrow_scalars;
 pub use crate::arrow_scalars::*;
 mod record_batch;
 pub use crate::record_batch::*;
-mod proto_types;
-pub use crate::proto_types::*;
+mod column;
 mod dict_array_builder;
-use thiserror::Error;
-
-#[derive(Error, Debug)]
-pub enum ArrowScalarError {
-    #[error("Method `{0}` is not available for type `{1}`")]
-    Unimplemented(&'static str, &'static str),
-    #[error("Invalid Protobuf")]
-    InvalidProtobuf,
-    #[error("Invalid Scalar")]
-    InvalidScalar(TableScalar),
-    #[error("Out of Bounds Access Error")]
-    AccessError,
-    #[error("Arrow Error: `{0}`")]
-    ArrowError(ArrowError),
-}
+mod primitive;
+mod proto_types;
+pub use primitive::*;
+mod validity;
+pub use validity::*;
+mod sort;
+mod statistics;
+mod validate;
+pub use statistics::*;
+mod approx_eq;
+pub use approx_eq::*;
+mod capacity;
+mod diff;
+mod extend;
+mod rows;
+mod size;
+pub use rows::*;
+mod row_ref;
+pub use row_ref::*;
+mod path;
+mod projection;
+pub use projection::*;
+mod chunks;
+mod error;
+pub use error::*;
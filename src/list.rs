@@ -1,14 +1,19 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use crate::column::ListColumn;
+use crate::dict_array_builder::{dict_array_to_proto, dict_builder, list_dict_builder};
+use crate::primitive::primitive_array;
+use crate::validity::{pack_bitmap, ListValidity, ValidityIter, ValidityRef};
 use crate::ScalarValuable;
-use crate::dict_array_builder::{dict_builder, list_dict_builder, dict_array_to_proto};
 use crate::{
     data_type_proto, table_list, table_scalar, ArrowScalarError, DataTypeProto, FieldProto,
     TableList, TableScalar,
 };
 use arrow::array::*;
+use arrow::buffer::Buffer;
 use arrow::datatypes::*;
+use arrow::error::ArrowError;
 use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
 use half::f16;
 
@@ -32,7 +37,11 @@ impl<T: Array> ListValuable for T {
                     }
                     set.push(!array.is_null(i));
                 }
-                let list = table_list::Int8List { values, set };
+                let list = table_list::Int8List {
+                    values,
+                    set,
+                    validity: None,
+                };
                 Some(table_list::Values::Int8(list))
             }
             DataType::Int16 => {
@@ -47,7 +56,11 @@ impl<T: Array> ListValuable for T {
                     }
                     set.push(!array.is_null(i));
                 }
-                let list = table_list::Int16List { values, set };
+                let list = table_list::Int16List {
+                    values,
+                    set,
+                    validity: None,
+                };
                 Some(table_list::Values::Int16(list))
             }
             DataType::Int32 => {
@@ -62,7 +75,11 @@ impl<T: Array> ListValuable for T {
                     }
                     set.push(!array.is_null(i));
                 }
-                let list = table_list::Int32List { values, set };
+                let list = table_list::Int32List {
+                    values,
+                    set,
+                    validity: None,
+                };
                 Some(table_list::Values::Int32(list))
             }
             DataType::Int64 => {
@@ -77,7 +94,11 @@ impl<T: Array> ListValuable for T {
                     }
                     set.push(!array.is_null(i));
                 }
-                let list = table_list::Int64List { values, set };
+                let list = table_list::Int64List {
+                    values,
+                    set,
+                    validity: None,
+                };
                 Some(table_list::Values::Int64(list))
             }
             DataType::UInt8 => {
@@ -92,7 +113,11 @@ impl<T: Array> ListValuable for T {
                     }
                     set.push(!array.is_null(i));
                 }
-                let list = table_list::UInt8List { values, set };
+                let list = table_list::UInt8List {
+                    values,
+                    set,
+                    validity: None,
+                };
                 Some(table_list::Values::Uint8(list))
             }
             DataType::UInt16 => {
@@ -107,7 +132,11 @@ impl<T: Array> ListValuable for T {
                     }
                     set.push(!array.is_null(i));
                 }
-                let list = table_list::UInt16List { values, set };
+                let list = table_list::UInt16List {
+                    values,
+                    set,
+                    validity: None,
+                };
                 Some(table_list::Values::Uint16(list))
             }
             DataType::UInt32 => {
@@ -122,7 +151,11 @@ impl<T: Array> ListValuable for T {
                     }
                     set.push(!array.is_null(i));
                 }
-                let list = table_list::UInt32List { values, set };
+                let list = table_list::UInt32List {
+                    values,
+                    set,
+                    validity: None,
+                };
                 Some(table_list::Values::Uint32(list))
             }
             DataType::UInt64 => {
@@ -137,7 +170,11 @@ impl<T: Array> ListValuable for T {
                     }
                     set.push(!array.is_null(i));
                 }
-                let list = table_list::UInt64List { values, set };
+                let list = table_list::UInt64List {
+                    values,
+                    set,
+                    validity: None,
+                };
                 Some(table_list::Values::Uint64(list))
             }
             DataType::Float16 => {
@@ -152,7 +189,11 @@ impl<T: Array> ListValuable for T {
                     }
                     set.push(!array.is_null(i));
                 }
-                let list = table_list::Float16List { values, set };
+                let list = table_list::Float16List {
+                    values,
+                    set,
+                    validity: None,
+                };
                 Some(table_list::Values::Float16(list))
             }
             DataType::Float32 => {
@@ -167,7 +208,11 @@ impl<T: Array> ListValuable for T {
                     }
                     set.push(!array.is_null(i));
                 }
-                let list = table_list::Float32List { values, set };
+                let list = table_list::Float32List {
+                    values,
+                    set,
+                    validity: None,
+                };
                 Some(table_list::Values::Float32(list))
             }
             DataType::Float64 => {
@@ -182,7 +227,11 @@ impl<T: Array> ListValuable for T {
                     }
                     set.push(!array.is_null(i));
                 }
-                let list = table_list::Float64List { values, set };
+                let list = table_list::Float64List {
+                    values,
+                    set,
+                    validity: None,
+                };
                 Some(table_list::Values::Float64(list))
             }
             DataType::Date32 => {
@@ -197,7 +246,11 @@ impl<T: Array> ListValuable for T {
                     }
                     set.push(!array.is_null(i));
                 }
-                let list = table_list::Int32List { values, set };
+                let list = table_list::Int32List {
+                    values,
+                    set,
+                    validity: None,
+                };
                 Some(table_list::Values::Date32(list))
             }
             DataType::Date64 => {
@@ -212,7 +265,11 @@ impl<T: Array> ListValuable for T {
                     }
                     set.push(!array.is_null(i));
                 }
-                let list = table_list::Int64List { values, set };
+                let list = table_list::Int64List {
+                    values,
+                    set,
+                    validity: None,
+                };
                 Some(table_list::Values::Date64(list))
             }
             DataType::Boolean => {
@@ -227,7 +284,11 @@ impl<T: Array> ListValuable for T {
                     }
                     set.push(!array.is_null(i));
                 }
-                let list = table_list::BooleanList { values, set };
+                let list = table_list::BooleanList {
+                    values,
+                    set,
+                    validity: None,
+                };
                 Some(table_list::Values::Boolean(list))
             }
             DataType::Utf8 => {
@@ -242,7 +303,11 @@ impl<T: Array> ListValuable for T {
                     }
                     set.push(!array.is_null(i));
                 }
-                let list = table_list::Utf8List { values, set };
+                let list = table_list::Utf8List {
+                    values,
+                    set,
+                    validity: None,
+                };
                 Some(table_list::Values::Utf8(list))
             }
             DataType::LargeUtf8 => {
@@ -257,7 +322,11 @@ impl<T: Array> ListValuable for T {
                     }
                     set.push(!array.is_null(i));
                 }
-                let list = table_list::Utf8List { values, set };
+                let list = table_list::Utf8List {
+                    values,
+                    set,
+                    validity: None,
+                };
                 Some(table_list::Values::LargeUtf8(list))
             }
             DataType::List(list_type) => {
@@ -279,6 +348,7 @@ impl<T: Array> ListValuable for T {
                     set,
                     list_type,
                     size: None,
+                    validity: None,
                 }))
             }
             DataType::LargeList(list_type) => {
@@ -300,11 +370,15 @@ impl<T: Array> ListValuable for T {
                     set,
                     list_type,
                     size: None,
+                    validity: None,
                 }))
             }
             DataType::FixedSizeList(list_type, len) => {
                 let list_type = Some(FieldProto::from_arrow(list_type));
-                let array = self.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+                let array = self
+                    .as_any()
+                    .downcast_ref::<FixedSizeListArray>()
+                    .ok_or_else(|| downcast_error(self))?;
 
                 let mut values = Vec::with_capacity(array.len());
                 let mut set = Vec::with_capacity(array.len());
@@ -323,6 +397,7 @@ impl<T: Array> ListValuable for T {
                     set,
                     list_type,
                     size: Some(*len),
+                    validity: None,
                 }))
             }
             DataType::Timestamp(time_unit, tz) => match time_unit {
@@ -339,7 +414,12 @@ impl<T: Array> ListValuable for T {
                         set.push(!array.is_null(i));
                     }
                     let tz = tz.as_ref().map(|tz| tz.to_string());
-                    let time_list = table_list::TimeList { tz, times, set };
+                    let time_list = table_list::TimeList {
+                        tz,
+                        times,
+                        set,
+                        validity: None,
+                    };
                     Some(table_list::Values::TimestampSecond(time_list))
                 }
                 TimeUnit::Millisecond => {
@@ -355,7 +435,12 @@ impl<T: Array> ListValuable for T {
                         set.push(!array.is_null(i));
                     }
                     let tz = tz.as_ref().map(|tz| tz.to_string());
-                    let time_list = table_list::TimeList { tz, times, set };
+                    let time_list = table_list::TimeList {
+                        tz,
+                        times,
+                        set,
+                        validity: None,
+                    };
                     Some(table_list::Values::TimestampMillisecond(time_list))
                 }
                 TimeUnit::Microsecond => {
@@ -371,7 +456,12 @@ impl<T: Array> ListValuable for T {
                         set.push(!array.is_null(i));
                     }
                     let tz = tz.as_ref().map(|tz| tz.to_string());
-                    let time_list = table_list::TimeList { tz, times, set };
+                    let time_list = table_list::TimeList {
+                        tz,
+                        times,
+                        set,
+                        validity: None,
+                    };
                     Some(table_list::Values::TimestampMicrosecond(time_list))
                 }
                 TimeUnit::Nanosecond => {
@@ -387,7 +477,12 @@ impl<T: Array> ListValuable for T {
                         set.push(!array.is_null(i));
                     }
                     let tz = tz.as_ref().map(|tz| tz.to_string());
-                    let time_list = table_list::TimeList { tz, times, set };
+                    let time_list = table_list::TimeList {
+                        tz,
+                        times,
+                        set,
+                        validity: None,
+                    };
                     Some(table_list::Values::TimestampNanosecond(time_list))
                 }
             },
@@ -404,7 +499,11 @@ impl<T: Array> ListValuable for T {
                         }
                         set.push(!array.is_null(i));
                     }
-                    let time_list = table_list::Int32List { values: times, set };
+                    let time_list = table_list::Int32List {
+                        values: times,
+                        set,
+                        validity: None,
+                    };
                     Some(table_list::Values::Time32Second(time_list))
                 }
                 TimeUnit::Millisecond => {
@@ -419,7 +518,11 @@ impl<T: Array> ListValuable for T {
                         }
                         set.push(!array.is_null(i));
                     }
-                    let time_list = table_list::Int32List { values: times, set };
+                    let time_list = table_list::Int32List {
+                        values: times,
+                        set,
+                        validity: None,
+                    };
                     Some(table_list::Values::Time32Millisecond(time_list))
                 }
                 _ => None,
@@ -437,7 +540,11 @@ impl<T: Array> ListValuable for T {
                         }
                         set.push(!array.is_null(i));
                     }
-                    let time_list = table_list::Int64List { values: times, set };
+                    let time_list = table_list::Int64List {
+                        values: times,
+                        set,
+                        validity: None,
+                    };
                     Some(table_list::Values::Time64Microsecond(time_list))
                 }
                 TimeUnit::Nanosecond => {
@@ -452,7 +559,11 @@ impl<T: Array> ListValuable for T {
                         }
                         set.push(!array.is_null(i));
                     }
-                    let time_list = table_list::Int64List { values: times, set };
+                    let time_list = table_list::Int64List {
+                        values: times,
+                        set,
+                        validity: None,
+                    };
                     Some(table_list::Values::Time64Nanosecond(time_list))
                 }
                 _ => None,
@@ -473,6 +584,7 @@ impl<T: Array> ListValuable for T {
                     values,
                     set,
                     size: None,
+                    validity: None,
                 };
                 Some(table_list::Values::Binary(binary_list))
             }
@@ -492,14 +604,15 @@ impl<T: Array> ListValuable for T {
                     values,
                     set,
                     size: None,
+                    validity: None,
                 };
-                Some(table_list::Values::Binary(binary_list))
+                Some(table_list::Values::LargeBinary(binary_list))
             }
             DataType::FixedSizeBinary(size) => {
                 let array = self
                     .as_any()
                     .downcast_ref::<FixedSizeBinaryArray>()
-                    .expect("This is a bug");
+                    .ok_or_else(|| downcast_error(self))?;
                 let mut values = Vec::with_capacity(array.len());
                 let mut set = Vec::with_capacity(array.len());
                 for i in 0..array.len() {
@@ -514,8 +627,9 @@ impl<T: Array> ListValuable for T {
                     values,
                     set,
                     size: Some(*size),
+                    validity: None,
                 };
-                Some(table_list::Values::Binary(binary_list))
+                Some(table_list::Values::FixedSizeBinary(binary_list))
             }
             DataType::Duration(time_unit) => match time_unit {
                 TimeUnit::Second => {
@@ -533,6 +647,7 @@ impl<T: Array> ListValuable for T {
                     let time_list = table_list::Int64List {
                         values: durations,
                         set,
+                        validity: None,
                     };
                     Some(table_list::Values::DurationSecond(time_list))
                 }
@@ -551,6 +666,7 @@ impl<T: Array> ListValuable for T {
                     let time_list = table_list::Int64List {
                         values: durations,
                         set,
+                        validity: None,
                     };
                     Some(table_list::Values::DurationMillisecond(time_list))
                 }
@@ -569,6 +685,7 @@ impl<T: Array> ListValuable for T {
                     let time_list = table_list::Int64List {
                         values: durations,
                         set,
+                        validity: None,
                     };
                     Some(table_list::Values::DurationMicrosecond(time_list))
                 }
@@ -587,6 +704,7 @@ impl<T: Array> ListValuable for T {
                     let time_list = table_list::Int64List {
                         values: durations,
                         set,
+                        validity: None,
                     };
                     Some(table_list::Values::DurationNanosecond(time_list))
                 }
@@ -607,6 +725,7 @@ impl<T: Array> ListValuable for T {
                     let interval_list = table_list::Int32List {
                         values: intervals,
                         set,
+                        validity: None,
                     };
                     Some(table_list::Values::IntervalYearMonth(interval_list))
                 }
@@ -625,6 +744,7 @@ impl<T: Array> ListValuable for T {
                     let interval_list = table_list::Int64List {
                         values: intervals,
                         set,
+                        validity: None,
                     };
                     Some(table_list::Values::IntervalDayTime(interval_list))
                 }
@@ -646,13 +766,16 @@ impl<T: Array> ListValuable for T {
                 let values = (0..fields.len())
                     .map(|i| {
                         let field_array = array.column(i);
-                        field_array.clone_as_list()
+                        field_array
+                            .clone_as_list()
+                            .map_err(|error| error.at_path(&format!(".{}", fields[i].name)))
                     })
                     .collect::<Result<Vec<_>, _>>()?;
                 let struct_list = table_list::StructList {
                     fields,
                     values,
                     set,
+                    validity: None,
                 };
                 Some(table_list::Values::Struct(struct_list))
             }
@@ -709,7 +832,7 @@ impl<T: Array> ListValuable for T {
                         dict_arr.index_type = Some(DataTypeProto::from_arrow(&DataType::UInt64));
                         dict_arr
                     }
-                    _ => unreachable!(),
+                    _ => return Err(downcast_error(self)),
                 };
                 Some(table_list::Values::Dictionary(Box::new(array)))
             }
@@ -729,19 +852,30 @@ impl<T: Array> ListValuable for T {
                 return Err(ArrowScalarError::Unimplemented("clone_as_list", "Map"));
             }
             DataType::Null => {
-                unreachable!();
+                return Err(ArrowScalarError::Unimplemented("clone_as_list", "Null"));
             }
         };
-        Ok(TableList { values })
+        let mut list = TableList { values };
+        list.pack_validity();
+        Ok(list)
     }
 }
 
 impl ScalarValuable for TableList {
     fn scalar(&self, i: usize) -> Result<TableScalar, ArrowScalarError> {
+        if i >= self.len() {
+            return Err(ArrowScalarError::AccessError {
+                index: i,
+                len: self.len(),
+            });
+        }
+        if let Some(list) = self.list_validity() {
+            list.check().map_err(ArrowScalarError::InvalidList)?;
+        }
         let scalar = match self.values.as_ref() {
             Some(table_list::Values::Boolean(list)) => {
                 let value = list.values[i];
-                if list.set[i] {
+                if list.is_valid(i) {
                     TableScalar {
                         value: Some(table_scalar::Value::Boolean(value)),
                     }
@@ -751,7 +885,7 @@ impl ScalarValuable for TableList {
             }
             Some(table_list::Values::Int8(list)) => {
                 let value = list.values[i];
-                if list.set[i] {
+                if list.is_valid(i) {
                     TableScalar {
                         value: Some(table_scalar::Value::Int8(value)),
                     }
@@ -761,7 +895,7 @@ impl ScalarValuable for TableList {
             }
             Some(table_list::Values::Int16(list)) => {
                 let value = list.values[i];
-                if list.set[i] {
+                if list.is_valid(i) {
                     TableScalar {
                         value: Some(table_scalar::Value::Int16(value)),
                     }
@@ -771,7 +905,7 @@ impl ScalarValuable for TableList {
             }
             Some(table_list::Values::Int32(list)) => {
                 let value = list.values[i];
-                if list.set[i] {
+                if list.is_valid(i) {
                     TableScalar {
                         value: Some(table_scalar::Value::Int32(value)),
                     }
@@ -781,7 +915,7 @@ impl ScalarValuable for TableList {
             }
             Some(table_list::Values::Int64(list)) => {
                 let value = list.values[i];
-                if list.set[i] {
+                if list.is_valid(i) {
                     TableScalar {
                         value: Some(table_scalar::Value::Int64(value)),
                     }
@@ -791,7 +925,7 @@ impl ScalarValuable for TableList {
             }
             Some(table_list::Values::Uint8(list)) => {
                 let value = list.values[i];
-                if list.set[i] {
+                if list.is_valid(i) {
                     TableScalar {
                         value: Some(table_scalar::Value::Uint8(value)),
                     }
@@ -801,7 +935,7 @@ impl ScalarValuable for TableList {
             }
             Some(table_list::Values::Uint16(list)) => {
                 let value = list.values[i];
-                if list.set[i] {
+                if list.is_valid(i) {
                     TableScalar {
                         value: Some(table_scalar::Value::Uint16(value)),
                     }
@@ -811,7 +945,7 @@ impl ScalarValuable for TableList {
             }
             Some(table_list::Values::Uint32(list)) => {
                 let value = list.values[i];
-                if list.set[i] {
+                if list.is_valid(i) {
                     TableScalar {
                         value: Some(table_scalar::Value::Uint32(value)),
                     }
@@ -821,7 +955,7 @@ impl ScalarValuable for TableList {
             }
             Some(table_list::Values::Uint64(list)) => {
                 let value = list.values[i];
-                if list.set[i] {
+                if list.is_valid(i) {
                     TableScalar {
                         value: Some(table_scalar::Value::Uint64(value)),
                     }
@@ -831,7 +965,7 @@ impl ScalarValuable for TableList {
             }
             Some(table_list::Values::Float16(list)) => {
                 let value = list.values[i];
-                if list.set[i] {
+                if list.is_valid(i) {
                     TableScalar {
                         value: Some(table_scalar::Value::Float16(value)),
                     }
@@ -841,7 +975,7 @@ impl ScalarValuable for TableList {
             }
             Some(table_list::Values::Float32(list)) => {
                 let value = list.values[i];
-                if list.set[i] {
+                if list.is_valid(i) {
                     TableScalar {
                         value: Some(table_scalar::Value::Float32(value)),
                     }
@@ -851,7 +985,7 @@ impl ScalarValuable for TableList {
             }
             Some(table_list::Values::Float64(list)) => {
                 let value = list.values[i];
-                if list.set[i] {
+                if list.is_valid(i) {
                     TableScalar {
                         value: Some(table_scalar::Value::Float64(value)),
                     }
@@ -861,7 +995,7 @@ impl ScalarValuable for TableList {
             }
             Some(table_list::Values::Utf8(list)) => {
                 let value = list.values[i].clone();
-                if list.set[i] {
+                if list.is_valid(i) {
                     TableScalar {
                         value: Some(table_scalar::Value::Utf8(value)),
                     }
@@ -871,7 +1005,7 @@ impl ScalarValuable for TableList {
             }
             Some(table_list::Values::LargeUtf8(list)) => {
                 let value = list.values[i].clone();
-                if list.set[i] {
+                if list.is_valid(i) {
                     TableScalar {
                         value: Some(table_scalar::Value::LargeUtf8(value)),
                     }
@@ -881,7 +1015,7 @@ impl ScalarValuable for TableList {
             }
             Some(table_list::Values::Binary(list)) => {
                 let value = list.values[i].clone();
-                if list.set[i] {
+                if list.is_valid(i) {
                     TableScalar {
                         value: Some(table_scalar::Value::Binary(value)),
                     }
@@ -891,7 +1025,7 @@ impl ScalarValuable for TableList {
             }
             Some(table_list::Values::LargeBinary(list)) => {
                 let value = list.values[i].clone();
-                if list.set[i] {
+                if list.is_valid(i) {
                     TableScalar {
                         value: Some(table_scalar::Value::Binary(value)),
                     }
@@ -901,7 +1035,7 @@ impl ScalarValuable for TableList {
             }
             Some(table_list::Values::List(list)) => {
                 let value = list.values[i].clone();
-                if list.set[i] {
+                if list.is_valid(i) {
                     TableScalar {
                         value: Some(table_scalar::Value::List(value)),
                     }
@@ -911,7 +1045,7 @@ impl ScalarValuable for TableList {
             }
             Some(table_list::Values::LargeList(list)) => {
                 let value = list.values[i].clone();
-                if list.set[i] {
+                if list.is_valid(i) {
                     TableScalar {
                         value: Some(table_scalar::Value::List(value)),
                     }
@@ -921,7 +1055,7 @@ impl ScalarValuable for TableList {
             }
             Some(table_list::Values::Date32(list)) => {
                 let value = list.values[i];
-                if list.set[i] {
+                if list.is_valid(i) {
                     TableScalar {
                         value: Some(table_scalar::Value::Date32(value)),
                     }
@@ -931,7 +1065,7 @@ impl ScalarValuable for TableList {
             }
             Some(table_list::Values::Date64(list)) => {
                 let value = list.values[i];
-                if list.set[i] {
+                if list.is_valid(i) {
                     TableScalar {
                         value: Some(table_scalar::Value::Date64(value)),
                     }
@@ -941,7 +1075,7 @@ impl ScalarValuable for TableList {
             }
             Some(table_list::Values::Time32Second(list)) => {
                 let value = list.values[i];
-                if list.set[i] {
+                if list.is_valid(i) {
                     TableScalar {
                         value: Some(table_scalar::Value::Time32Second(value)),
                     }
@@ -951,7 +1085,7 @@ impl ScalarValuable for TableList {
             }
             Some(table_list::Values::Time32Millisecond(list)) => {
                 let value = list.values[i];
-                if list.set[i] {
+                if list.is_valid(i) {
                     TableScalar {
                         value: Some(table_scalar::Value::Time32Millisecond(value)),
                     }
@@ -961,7 +1095,7 @@ impl ScalarValuable for TableList {
             }
             Some(table_list::Values::Time64Microsecond(list)) => {
                 let value = list.values[i];
-                if list.set[i] {
+                if list.is_valid(i) {
                     TableScalar {
                         value: Some(table_scalar::Value::Time64Microsecond(value)),
                     }
@@ -971,7 +1105,7 @@ impl ScalarValuable for TableList {
             }
             Some(table_list::Values::Time64Nanosecond(list)) => {
                 let value = list.values[i];
-                if list.set[i] {
+                if list.is_valid(i) {
                     TableScalar {
                         value: Some(table_scalar::Value::Time64Nanosecond(value)),
                     }
@@ -981,7 +1115,7 @@ impl ScalarValuable for TableList {
             }
             Some(table_list::Values::DurationSecond(list)) => {
                 let value = list.values[i];
-                if list.set[i] {
+                if list.is_valid(i) {
                     TableScalar {
                         value: Some(table_scalar::Value::DurationSecond(value)),
                     }
@@ -991,7 +1125,7 @@ impl ScalarValuable for TableList {
             }
             Some(table_list::Values::DurationMillisecond(list)) => {
                 let value = list.values[i];
-                if list.set[i] {
+                if list.is_valid(i) {
                     TableScalar {
                         value: Some(table_scalar::Value::DurationMillisecond(value)),
                     }
@@ -1001,7 +1135,7 @@ impl ScalarValuable for TableList {
             }
             Some(table_list::Values::DurationMicrosecond(list)) => {
                 let value = list.values[i];
-                if list.set[i] {
+                if list.is_valid(i) {
                     TableScalar {
                         value: Some(table_scalar::Value::DurationMicrosecond(value)),
                     }
@@ -1011,7 +1145,7 @@ impl ScalarValuable for TableList {
             }
             Some(table_list::Values::DurationNanosecond(list)) => {
                 let value = list.values[i];
-                if list.set[i] {
+                if list.is_valid(i) {
                     TableScalar {
                         value: Some(table_scalar::Value::DurationNanosecond(value)),
                     }
@@ -1021,7 +1155,7 @@ impl ScalarValuable for TableList {
             }
             Some(table_list::Values::IntervalYearMonth(list)) => {
                 let value = list.values[i];
-                if list.set[i] {
+                if list.is_valid(i) {
                     TableScalar {
                         value: Some(table_scalar::Value::IntervalYearMonth(value)),
                     }
@@ -1031,7 +1165,7 @@ impl ScalarValuable for TableList {
             }
             Some(table_list::Values::IntervalDayTime(list)) => {
                 let value = list.values[i];
-                if list.set[i] {
+                if list.is_valid(i) {
                     TableScalar {
                         value: Some(table_scalar::Value::IntervalDayTime(value)),
                     }
@@ -1041,7 +1175,7 @@ impl ScalarValuable for TableList {
             }
             Some(table_list::Values::TimestampSecond(list)) => {
                 let value = list.times[i];
-                if list.set[i] {
+                if list.is_valid(i) {
                     TableScalar {
                         value: Some(table_scalar::Value::TimestampSecond(value)),
                     }
@@ -1051,7 +1185,7 @@ impl ScalarValuable for TableList {
             }
             Some(table_list::Values::TimestampMillisecond(list)) => {
                 let value = list.times[i];
-                if list.set[i] {
+                if list.is_valid(i) {
                     TableScalar {
                         value: Some(table_scalar::Value::TimestampMillisecond(value)),
                     }
@@ -1061,7 +1195,7 @@ impl ScalarValuable for TableList {
             }
             Some(table_list::Values::TimestampMicrosecond(list)) => {
                 let value = list.times[i];
-                if list.set[i] {
+                if list.is_valid(i) {
                     TableScalar {
                         value: Some(table_scalar::Value::TimestampMicrosecond(value)),
                     }
@@ -1071,7 +1205,7 @@ impl ScalarValuable for TableList {
             }
             Some(table_list::Values::TimestampNanosecond(list)) => {
                 let value = list.times[i];
-                if list.set[i] {
+                if list.is_valid(i) {
                     TableScalar {
                         value: Some(table_scalar::Value::TimestampNanosecond(value)),
                     }
@@ -1080,7 +1214,7 @@ impl ScalarValuable for TableList {
                 }
             }
             Some(table_list::Values::Struct(list)) => {
-                if list.set[i] {
+                if list.is_valid(i) {
                     let elements: Result<HashMap<String, TableScalar>, ArrowScalarError> = list
                         .fields
                         .iter()
@@ -1107,7 +1241,7 @@ impl ScalarValuable for TableList {
             }
             Some(table_list::Values::Union(list)) => {
                 let value = list.values[i].clone();
-                if list.set[i] {
+                if list.is_valid(i) {
                     TableScalar {
                         value: Some(table_scalar::Value::Union(Box::new(value))),
                     }
@@ -1117,7 +1251,7 @@ impl ScalarValuable for TableList {
             }
             Some(table_list::Values::FixedSizeBinary(list)) => {
                 let value = list.values[i].clone();
-                if list.set[i] {
+                if list.is_valid(i) {
                     TableScalar {
                         value: Some(table_scalar::Value::FixedSizeBinary(value)),
                     }
@@ -1127,7 +1261,7 @@ impl ScalarValuable for TableList {
             }
             Some(table_list::Values::FixedSizeList(list)) => {
                 let value = list.values[i].clone();
-                if list.set[i] {
+                if list.is_valid(i) {
                     TableScalar {
                         value: Some(table_scalar::Value::FixedSizeList(value)),
                     }
@@ -1143,7 +1277,7 @@ impl ScalarValuable for TableList {
 
 struct TableListIter<'a, T: Iterator> {
     values: T,
-    set: std::slice::Iter<'a, bool>,
+    set: ValidityIter<'a>,
 }
 
 impl<'a, T: Iterator> Iterator for TableListIter<'a, T> {
@@ -1160,6 +1294,400 @@ impl<'a, T: Iterator> Iterator for TableListIter<'a, T> {
     }
 }
 
+fn copied_list_iter<'a, T: Copy>(
+    values: &'a [T],
+    validity: ValidityRef<'a>,
+) -> TableListIter<'a, std::iter::Copied<std::slice::Iter<'a, T>>> {
+    TableListIter {
+        values: values.iter().copied(),
+        set: validity.iter(values.len()),
+    }
+}
+
+/// Iterator over the scalars of a borrowed [`TableList`], see [`TableList::iter`]. It stops
+/// at the first row whose scalar cannot be built, so it may yield fewer than `len` rows.
+pub struct ScalarIter<'a> {
+    list: &'a TableList,
+    index: usize,
+    len: usize,
+}
+
+impl<'a> Iterator for ScalarIter<'a> {
+    type Item = TableScalar;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            return None;
+        }
+        match self.list.scalar(self.index) {
+            Ok(scalar) => {
+                self.index += 1;
+                Some(scalar)
+            }
+            Err(_) => {
+                self.index = self.len;
+                None
+            }
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.len - self.index))
+    }
+}
+
+impl<'a> std::iter::FusedIterator for ScalarIter<'a> {}
+
+/// Iterator over the scalars of an owned [`TableList`], see [`TableList::into_iter`]. Like
+/// [`ScalarIter`], it stops at the first row whose scalar cannot be built.
+pub struct IntoScalarIter {
+    list: TableList,
+    index: usize,
+    len: usize,
+}
+
+impl Iterator for IntoScalarIter {
+    type Item = TableScalar;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            return None;
+        }
+        match self.list.scalar(self.index) {
+            Ok(scalar) => {
+                self.index += 1;
+                Some(scalar)
+            }
+            Err(_) => {
+                self.index = self.len;
+                None
+            }
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.len - self.index))
+    }
+}
+
+impl std::iter::FusedIterator for IntoScalarIter {}
+
+impl IntoIterator for TableList {
+    type Item = TableScalar;
+    type IntoIter = IntoScalarIter;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        IntoScalarIter {
+            list: self,
+            index: 0,
+            len,
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a TableList {
+    type Item = TableScalar;
+    type IntoIter = ScalarIter<'a>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl TableList {
+    /// Iterates over the list, yielding the same scalars as [`ScalarValuable::scalar`].
+    /// Iteration stops at the first row that fails on a malformed list; call
+    /// [`TableList::validate`] first to tell that apart from the end of the list.
+    pub fn iter(&self) -> ScalarIter<'_> {
+        ScalarIter {
+            list: self,
+            index: 0,
+            len: self.len(),
+        }
+    }
+
+    /// Iterates over lists stored as `bool`, without building a `TableScalar` per element.
+    pub fn iter_bool(&self) -> Option<impl Iterator<Item = Option<bool>> + '_> {
+        match self.values.as_ref()? {
+            table_list::Values::Boolean(list) => {
+                Some(copied_list_iter(&list.values, list.validity_ref()))
+            }
+            table_list::Values::Dictionary(dict) => dict.values.as_ref()?.iter_bool(),
+            _ => None,
+        }
+    }
+
+    /// Iterates over lists stored as `i32`: the small signed integers, `Date32`, `Time32` and
+    /// `IntervalYearMonth`.
+    pub fn iter_i32(&self) -> Option<impl Iterator<Item = Option<i32>> + '_> {
+        match self.values.as_ref()? {
+            table_list::Values::Int8(list) => {
+                Some(copied_list_iter(&list.values, list.validity_ref()))
+            }
+            table_list::Values::Int16(list) => {
+                Some(copied_list_iter(&list.values, list.validity_ref()))
+            }
+            table_list::Values::Int32(list)
+            | table_list::Values::Date32(list)
+            | table_list::Values::Time32Second(list)
+            | table_list::Values::Time32Millisecond(list)
+            | table_list::Values::IntervalYearMonth(list) => {
+                Some(copied_list_iter(&list.values, list.validity_ref()))
+            }
+            table_list::Values::Dictionary(dict) => dict.values.as_ref()?.iter_i32(),
+            _ => None,
+        }
+    }
+
+    /// Iterates over lists stored as `i64`: `Int64`, `Date64`, `Time64`, timestamps, durations
+    /// and `IntervalDayTime`.
+    pub fn iter_i64(&self) -> Option<impl Iterator<Item = Option<i64>> + '_> {
+        match self.values.as_ref()? {
+            table_list::Values::Int64(list)
+            | table_list::Values::Date64(list)
+            | table_list::Values::Time64Microsecond(list)
+            | table_list::Values::Time64Nanosecond(list)
+            | table_list::Values::DurationSecond(list)
+            | table_list::Values::DurationMillisecond(list)
+            | table_list::Values::DurationMicrosecond(list)
+            | table_list::Values::DurationNanosecond(list)
+            | table_list::Values::IntervalDayTime(list) => {
+                Some(copied_list_iter(&list.values, list.validity_ref()))
+            }
+            table_list::Values::TimestampSecond(list)
+            | table_list::Values::TimestampMillisecond(list)
+            | table_list::Values::TimestampMicrosecond(list)
+            | table_list::Values::TimestampNanosecond(list) => {
+                Some(copied_list_iter(&list.times, list.validity_ref()))
+            }
+            table_list::Values::Dictionary(dict) => dict.values.as_ref()?.iter_i64(),
+            _ => None,
+        }
+    }
+
+    /// Iterates over lists stored as `u32`: `UInt8`, `UInt16` and `UInt32`.
+    pub fn iter_u32(&self) -> Option<impl Iterator<Item = Option<u32>> + '_> {
+        match self.values.as_ref()? {
+            table_list::Values::Uint8(list) => {
+                Some(copied_list_iter(&list.values, list.validity_ref()))
+            }
+            table_list::Values::Uint16(list) => {
+                Some(copied_list_iter(&list.values, list.validity_ref()))
+            }
+            table_list::Values::Uint32(list) => {
+                Some(copied_list_iter(&list.values, list.validity_ref()))
+            }
+            table_list::Values::Dictionary(dict) => dict.values.as_ref()?.iter_u32(),
+            _ => None,
+        }
+    }
+
+    /// Iterates over `UInt64` lists.
+    pub fn iter_u64(&self) -> Option<impl Iterator<Item = Option<u64>> + '_> {
+        match self.values.as_ref()? {
+            table_list::Values::Uint64(list) => {
+                Some(copied_list_iter(&list.values, list.validity_ref()))
+            }
+            table_list::Values::Dictionary(dict) => dict.values.as_ref()?.iter_u64(),
+            _ => None,
+        }
+    }
+
+    /// Iterates over lists stored as `f32`: `Float16` and `Float32`.
+    pub fn iter_f32(&self) -> Option<impl Iterator<Item = Option<f32>> + '_> {
+        match self.values.as_ref()? {
+            table_list::Values::Float16(list) => {
+                Some(copied_list_iter(&list.values, list.validity_ref()))
+            }
+            table_list::Values::Float32(list) => {
+                Some(copied_list_iter(&list.values, list.validity_ref()))
+            }
+            table_list::Values::Dictionary(dict) => dict.values.as_ref()?.iter_f32(),
+            _ => None,
+        }
+    }
+
+    /// Iterates over `Float64` lists.
+    pub fn iter_f64(&self) -> Option<impl Iterator<Item = Option<f64>> + '_> {
+        match self.values.as_ref()? {
+            table_list::Values::Float64(list) => {
+                Some(copied_list_iter(&list.values, list.validity_ref()))
+            }
+            table_list::Values::Dictionary(dict) => dict.values.as_ref()?.iter_f64(),
+            _ => None,
+        }
+    }
+
+    /// Iterates over `Utf8` and `LargeUtf8` lists, borrowing the strings.
+    pub fn iter_str(&self) -> Option<impl Iterator<Item = Option<&str>> + '_> {
+        match self.values.as_ref()? {
+            table_list::Values::Utf8(list) | table_list::Values::LargeUtf8(list) => {
+                Some(TableListIter {
+                    values: list
+                        .values
+                        .iter()
+                        .map(String::as_str as fn(&String) -> &str),
+                    set: list.validity_ref().iter(list.values.len()),
+                })
+            }
+            table_list::Values::Dictionary(dict) => dict.values.as_ref()?.iter_str(),
+            _ => None,
+        }
+    }
+
+    /// Iterates over `Binary`, `LargeBinary` and `FixedSizeBinary` lists, borrowing the bytes.
+    pub fn iter_bytes(&self) -> Option<impl Iterator<Item = Option<&[u8]>> + '_> {
+        match self.values.as_ref()? {
+            table_list::Values::Binary(list)
+            | table_list::Values::LargeBinary(list)
+            | table_list::Values::FixedSizeBinary(list) => Some(TableListIter {
+                values: list
+                    .values
+                    .iter()
+                    .map(Vec::as_slice as fn(&Vec<u8>) -> &[u8]),
+                set: list.validity_ref().iter(list.values.len()),
+            }),
+            table_list::Values::Dictionary(dict) => dict.values.as_ref()?.iter_bytes(),
+            _ => None,
+        }
+    }
+
+    /// Borrows the storage of a `Boolean` list as `(values, validity)`.
+    pub fn as_bool_values(&self) -> Option<(&[bool], ValidityRef<'_>)> {
+        match self.values.as_ref()? {
+            table_list::Values::Boolean(list) => Some((&list.values, list.validity_ref())),
+            table_list::Values::Dictionary(dict) => dict.values.as_ref()?.as_bool_values(),
+            _ => None,
+        }
+    }
+
+    /// Borrows the storage of a list stored as `i32`, see [`TableList::iter_i32`].
+    pub fn as_i32_values(&self) -> Option<(&[i32], ValidityRef<'_>)> {
+        match self.values.as_ref()? {
+            table_list::Values::Int8(list) => Some((&list.values, list.validity_ref())),
+            table_list::Values::Int16(list) => Some((&list.values, list.validity_ref())),
+            table_list::Values::Int32(list)
+            | table_list::Values::Date32(list)
+            | table_list::Values::Time32Second(list)
+            | table_list::Values::Time32Millisecond(list)
+            | table_list::Values::IntervalYearMonth(list) => {
+                Some((&list.values, list.validity_ref()))
+            }
+            table_list::Values::Dictionary(dict) => dict.values.as_ref()?.as_i32_values(),
+            _ => None,
+        }
+    }
+
+    /// Borrows the storage of a list stored as `i64`, see [`TableList::iter_i64`].
+    pub fn as_i64_values(&self) -> Option<(&[i64], ValidityRef<'_>)> {
+        match self.values.as_ref()? {
+            table_list::Values::Int64(list)
+            | table_list::Values::Date64(list)
+            | table_list::Values::Time64Microsecond(list)
+            | table_list::Values::Time64Nanosecond(list)
+            | table_list::Values::DurationSecond(list)
+            | table_list::Values::DurationMillisecond(list)
+            | table_list::Values::DurationMicrosecond(list)
+            | table_list::Values::DurationNanosecond(list)
+            | table_list::Values::IntervalDayTime(list) => {
+                Some((&list.values, list.validity_ref()))
+            }
+            table_list::Values::TimestampSecond(list)
+            | table_list::Values::TimestampMillisecond(list)
+            | table_list::Values::TimestampMicrosecond(list)
+            | table_list::Values::TimestampNanosecond(list) => {
+                Some((&list.times, list.validity_ref()))
+            }
+            table_list::Values::Dictionary(dict) => dict.values.as_ref()?.as_i64_values(),
+            _ => None,
+        }
+    }
+
+    /// Borrows the storage of a list stored as `u32`, see [`TableList::iter_u32`].
+    pub fn as_u32_values(&self) -> Option<(&[u32], ValidityRef<'_>)> {
+        match self.values.as_ref()? {
+            table_list::Values::Uint8(list) => Some((&list.values, list.validity_ref())),
+            table_list::Values::Uint16(list) => Some((&list.values, list.validity_ref())),
+            table_list::Values::Uint32(list) => Some((&list.values, list.validity_ref())),
+            table_list::Values::Dictionary(dict) => dict.values.as_ref()?.as_u32_values(),
+            _ => None,
+        }
+    }
+
+    /// Borrows the storage of a `UInt64` list.
+    pub fn as_u64_values(&self) -> Option<(&[u64], ValidityRef<'_>)> {
+        match self.values.as_ref()? {
+            table_list::Values::Uint64(list) => Some((&list.values, list.validity_ref())),
+            table_list::Values::Dictionary(dict) => dict.values.as_ref()?.as_u64_values(),
+            _ => None,
+        }
+    }
+
+    /// Borrows the storage of a list stored as `f32`: `Float16` and `Float32`.
+    pub fn as_f32_values(&self) -> Option<(&[f32], ValidityRef<'_>)> {
+        match self.values.as_ref()? {
+            table_list::Values::Float16(list) => Some((&list.values, list.validity_ref())),
+            table_list::Values::Float32(list) => Some((&list.values, list.validity_ref())),
+            table_list::Values::Dictionary(dict) => dict.values.as_ref()?.as_f32_values(),
+            _ => None,
+        }
+    }
+
+    /// Borrows the storage of a `Float64` list.
+    pub fn as_f64_values(&self) -> Option<(&[f64], ValidityRef<'_>)> {
+        match self.values.as_ref()? {
+            table_list::Values::Float64(list) => Some((&list.values, list.validity_ref())),
+            table_list::Values::Dictionary(dict) => dict.values.as_ref()?.as_f64_values(),
+            _ => None,
+        }
+    }
+
+    /// Borrows the storage of a `Utf8` or `LargeUtf8` list.
+    pub fn as_str_values(&self) -> Option<(&[String], ValidityRef<'_>)> {
+        match self.values.as_ref()? {
+            table_list::Values::Utf8(list) | table_list::Values::LargeUtf8(list) => {
+                Some((&list.values, list.validity_ref()))
+            }
+            table_list::Values::Dictionary(dict) => dict.values.as_ref()?.as_str_values(),
+            _ => None,
+        }
+    }
+
+    /// Borrows the storage of a `Binary`, `LargeBinary` or `FixedSizeBinary` list.
+    pub fn as_bytes_values(&self) -> Option<(&[Vec<u8>], ValidityRef<'_>)> {
+        match self.values.as_ref()? {
+            table_list::Values::Binary(list)
+            | table_list::Values::LargeBinary(list)
+            | table_list::Values::FixedSizeBinary(list) => {
+                Some((&list.values, list.validity_ref()))
+            }
+            table_list::Values::Dictionary(dict) => dict.values.as_ref()?.as_bytes_values(),
+            _ => None,
+        }
+    }
+}
+
+/// The error for an array that cannot be downcast to the type its data type implies.
+pub(crate) fn downcast_error(array: &dyn Array) -> ArrowScalarError {
+    ArrowScalarError::TypeMismatch {
+        expected: format!("{:?}", array.data_type()),
+        actual: "an array of another type".to_string(),
+    }
+}
+
+/// Downcasts `array` to the concrete array type its data type implies.
+pub(crate) fn downcast<A: 'static>(array: &dyn Array) -> Result<&A, ArrowScalarError> {
+    array
+        .as_any()
+        .downcast_ref::<A>()
+        .ok_or_else(|| downcast_error(array))
+}
+
+/// Converts a dictionary key to an index into the dictionary values.
+pub(crate) fn dictionary_key(key: impl ArrowNativeType) -> Result<usize, ArrowScalarError> {
+    key.to_usize().ok_or_else(|| {
+        ArrowScalarError::ArrowError(ArrowError::InvalidArgumentError(format!(
+            "negative dictionary key {:?}",
+            key
+        )))
+    })
+}
+
 fn pop_value_ret(
     value: Option<table_scalar::Value>,
     set: Option<bool>,
@@ -1207,7 +1735,7 @@ impl TableList {
                 TimeUnit::Millisecond => {
                     table_list::Values::Time32Millisecond(table_list::Int32List::default())
                 }
-                _ => unreachable!(),
+                _ => return Err(ArrowScalarError::Unimplemented("TableList::new", "Time32")),
             },
             DataType::Time64(unit) => match unit {
                 TimeUnit::Microsecond => {
@@ -1216,7 +1744,7 @@ impl TableList {
                 TimeUnit::Nanosecond => {
                     table_list::Values::Time64Nanosecond(table_list::Int64List::default())
                 }
-                _ => unreachable!(),
+                _ => return Err(ArrowScalarError::Unimplemented("TableList::new", "Time64")),
             },
             DataType::Timestamp(unit, tz) => match unit {
                 TimeUnit::Second => table_list::Values::TimestampSecond(table_list::TimeList {
@@ -1289,7 +1817,6 @@ impl TableList {
                 table_list::Values::Dictionary(Box::new(table_list::DictionaryList {
                     index_type: Some(DataTypeProto::from_arrow(key_type)),
                     values: Some(Box::new(TableList::new(value_type)?)),
-                    ..Default::default()
                 }))
             }
             DataType::Struct(fields) => {
@@ -1327,151 +1854,226 @@ impl TableList {
                 return Ok(TableList { values: None });
             }
         };
-        Ok(TableList {
+        let mut list = TableList {
             values: Some(values),
-        })
+        };
+        list.pack_validity();
+        Ok(list)
     }
 
     pub fn push(&mut self, scalar: TableScalar) -> Result<(), ArrowScalarError> {
-        if self.values.is_none() {
-            return Err(ArrowScalarError::InvalidScalar(scalar));
-        }
-        if scalar.value.is_none() {
-            self.push_null();
-            return Ok(());
-        }
-        match (self.values.as_mut().unwrap(), scalar.value.unwrap()) {
+        self.stage_validity(0, |list| list.push_staged(scalar))
+    }
+
+    fn push_staged(&mut self, scalar: TableScalar) -> Result<(), ArrowScalarError> {
+        let (values, value) = match (self.values.as_mut(), scalar.value) {
+            (None, value) => {
+                return Err(ArrowScalarError::InvalidScalar(Box::new(TableScalar {
+                    value,
+                })))
+            }
+            (Some(_), None) => {
+                self.push_null();
+                return Ok(());
+            }
+            (Some(values), Some(value)) => (values, value),
+        };
+        match (values, value) {
             (
-                table_list::Values::Boolean(table_list::BooleanList { values, set }),
+                table_list::Values::Boolean(table_list::BooleanList {
+                    values,
+                    set,
+                    validity: _,
+                }),
                 table_scalar::Value::Boolean(b),
             ) => {
                 values.push(b);
                 set.push(true);
             }
             (
-                table_list::Values::Int8(table_list::Int8List { values, set }),
+                table_list::Values::Int8(table_list::Int8List {
+                    values,
+                    set,
+                    validity: _,
+                }),
                 table_scalar::Value::Int8(b),
             ) => {
                 values.push(b);
                 set.push(true);
             }
             (
-                table_list::Values::Int16(table_list::Int16List { values, set }),
+                table_list::Values::Int16(table_list::Int16List {
+                    values,
+                    set,
+                    validity: _,
+                }),
                 table_scalar::Value::Int16(b),
             ) => {
                 values.push(b);
                 set.push(true);
             }
             (
-                table_list::Values::Int32(table_list::Int32List { values, set }),
+                table_list::Values::Int32(table_list::Int32List {
+                    values,
+                    set,
+                    validity: _,
+                }),
                 table_scalar::Value::Int32(b),
             ) => {
                 values.push(b);
                 set.push(true);
             }
             (
-                table_list::Values::Int64(table_list::Int64List { values, set }),
+                table_list::Values::Int64(table_list::Int64List {
+                    values,
+                    set,
+                    validity: _,
+                }),
                 table_scalar::Value::Int64(b),
             ) => {
                 values.push(b);
                 set.push(true);
             }
             (
-                table_list::Values::Uint8(table_list::UInt8List { values, set }),
+                table_list::Values::Uint8(table_list::UInt8List {
+                    values,
+                    set,
+                    validity: _,
+                }),
                 table_scalar::Value::Uint8(b),
             ) => {
                 values.push(b);
                 set.push(true);
             }
             (
-                table_list::Values::Uint16(table_list::UInt16List { values, set }),
+                table_list::Values::Uint16(table_list::UInt16List {
+                    values,
+                    set,
+                    validity: _,
+                }),
                 table_scalar::Value::Uint16(b),
             ) => {
                 values.push(b);
                 set.push(true);
             }
             (
-                table_list::Values::Uint32(table_list::UInt32List { values, set }),
+                table_list::Values::Uint32(table_list::UInt32List {
+                    values,
+                    set,
+                    validity: _,
+                }),
                 table_scalar::Value::Uint32(b),
             ) => {
                 values.push(b);
                 set.push(true);
             }
             (
-                table_list::Values::Uint64(table_list::UInt64List { values, set }),
+                table_list::Values::Uint64(table_list::UInt64List {
+                    values,
+                    set,
+                    validity: _,
+                }),
                 table_scalar::Value::Uint64(b),
             ) => {
                 values.push(b);
                 set.push(true);
             }
             (
-                table_list::Values::Float16(table_list::Float16List { values, set }),
+                table_list::Values::Float16(table_list::Float16List {
+                    values,
+                    set,
+                    validity: _,
+                }),
                 table_scalar::Value::Float16(b),
             ) => {
                 values.push(b);
                 set.push(true);
             }
             (
-                table_list::Values::Float32(table_list::Float32List { values, set }),
+                table_list::Values::Float32(table_list::Float32List {
+                    values,
+                    set,
+                    validity: _,
+                }),
                 table_scalar::Value::Float32(b),
             ) => {
                 values.push(b);
                 set.push(true);
             }
             (
-                table_list::Values::Float64(table_list::Float64List { values, set }),
+                table_list::Values::Float64(table_list::Float64List {
+                    values,
+                    set,
+                    validity: _,
+                }),
                 table_scalar::Value::Float64(b),
             ) => {
                 values.push(b);
                 set.push(true);
             }
             (
-                table_list::Values::Utf8(table_list::Utf8List { values, set }),
+                table_list::Values::Utf8(table_list::Utf8List {
+                    values,
+                    set,
+                    validity: _,
+                }),
                 table_scalar::Value::Utf8(b),
             ) => {
                 values.push(b);
                 set.push(true);
             }
             (
-                table_list::Values::Utf8(table_list::Utf8List { values, set }),
+                table_list::Values::Utf8(table_list::Utf8List {
+                    values,
+                    set,
+                    validity: _,
+                }),
                 table_scalar::Value::LargeUtf8(b),
             ) => {
                 values.push(b);
                 set.push(true);
             }
             (
-                table_list::Values::LargeUtf8(table_list::Utf8List { values, set }),
+                table_list::Values::LargeUtf8(table_list::Utf8List {
+                    values,
+                    set,
+                    validity: _,
+                }),
                 table_scalar::Value::Utf8(b),
             ) => {
                 values.push(b);
                 set.push(true);
             }
             (
-                table_list::Values::LargeUtf8(table_list::Utf8List { values, set }),
+                table_list::Values::LargeUtf8(table_list::Utf8List {
+                    values,
+                    set,
+                    validity: _,
+                }),
                 table_scalar::Value::LargeUtf8(b),
             ) => {
                 values.push(b);
                 set.push(true);
             }
             (table_list::Values::List(values), table_scalar::Value::List(list)) => {
-                if let Err(list) = values.push(list) {
-                    return Err(ArrowScalarError::InvalidScalar(TableScalar {
+                if let Err(list) = values.push_staged(list) {
+                    return Err(ArrowScalarError::InvalidScalar(Box::new(TableScalar {
                         value: Some(table_scalar::Value::List(list)),
-                    }));
+                    })));
                 }
             }
             (table_list::Values::LargeList(values), table_scalar::Value::List(list)) => {
-                if let Err(list) = values.push(list) {
-                    return Err(ArrowScalarError::InvalidScalar(TableScalar {
+                if let Err(list) = values.push_staged(list) {
+                    return Err(ArrowScalarError::InvalidScalar(Box::new(TableScalar {
                         value: Some(table_scalar::Value::List(list)),
-                    }));
+                    })));
                 }
             }
             (table_list::Values::FixedSizeList(values), table_scalar::Value::List(list)) => {
-                if let Err(list) = values.push(list) {
-                    return Err(ArrowScalarError::InvalidScalar(TableScalar {
+                if let Err(list) = values.push_staged(list) {
+                    return Err(ArrowScalarError::InvalidScalar(Box::new(TableScalar {
                         value: Some(table_scalar::Value::List(list)),
-                    }));
+                    })));
                 }
             }
             (table_list::Values::Struct(struct_list), table_scalar::Value::Struct(mut values)) => {
@@ -1481,15 +2083,24 @@ impl TableList {
                         .iter()
                         .all(|k| values.elements.contains_key(&k.name))
                 {
-                    for (field, value) in
-                        struct_list.fields.iter().zip(struct_list.values.iter_mut())
-                    {
-                        value.push(values.elements.remove(&field.name).unwrap())?;
+                    // Push every element onto an empty copy of its child first, so a failed
+                    // push leaves the list unchanged.
+                    let mut rows = Vec::with_capacity(struct_list.values.len());
+                    for (field, value) in struct_list.fields.iter().zip(struct_list.values.iter()) {
+                        let mut row = value.slice_rows(0, 0);
+                        if let Some(element) = values.elements.remove(&field.name) {
+                            row.push(element)?;
+                        }
+                        rows.push(row);
                     }
+                    for (value, row) in struct_list.values.iter_mut().zip(rows.iter()) {
+                        value.append(row);
+                    }
+                    struct_list.set.push(true);
                 } else {
-                    return Err(ArrowScalarError::InvalidScalar(TableScalar {
+                    return Err(ArrowScalarError::InvalidScalar(Box::new(TableScalar {
                         value: Some(table_scalar::Value::Struct(values)),
-                    }));
+                    })));
                 }
             }
             (table_list::Values::Binary(values), table_scalar::Value::Binary(b)) => {
@@ -1502,9 +2113,9 @@ impl TableList {
             }
             (table_list::Values::FixedSizeBinary(values), table_scalar::Value::Binary(b)) => {
                 if values.size != Some(b.len() as i32) {
-                    return Err(ArrowScalarError::InvalidScalar(TableScalar {
+                    return Err(ArrowScalarError::InvalidScalar(Box::new(TableScalar {
                         value: Some(table_scalar::Value::Binary(b)),
-                    }));
+                    })));
                 }
                 values.values.push(b);
                 values.set.push(true);
@@ -1617,87 +2228,148 @@ impl TableList {
                 values.set.push(true);
             }
             (table_list::Values::Dictionary(values), table_scalar::Value::Dictionary(dict)) => {
-                values.values.as_mut().expect("valid proto").push(*dict)?;
+                match values.values.as_mut() {
+                    Some(values) => values.push(*dict)?,
+                    None => return Err(ArrowScalarError::InvalidProtobuf("DictionaryList.values")),
+                }
             }
             (_, val) => {
-                return Err(ArrowScalarError::InvalidScalar(TableScalar {
+                return Err(ArrowScalarError::InvalidScalar(Box::new(TableScalar {
                     value: Some(val),
-                }));
+                })));
             }
         }
         Ok(())
     }
 
-    //todo! test this
     pub fn pop(&mut self) -> Option<TableScalar> {
-        self.values.as_ref()?;
-        let value = match self.values.as_mut().unwrap() {
-            table_list::Values::Boolean(table_list::BooleanList { values, set }) => {
+        self.stage_validity(1, |list| list.pop_staged())
+    }
+
+    fn pop_staged(&mut self) -> Option<TableScalar> {
+        let value = match self.values.as_mut()? {
+            table_list::Values::Boolean(table_list::BooleanList {
+                values,
+                set,
+                validity: _,
+            }) => {
                 let value = values.pop().map(table_scalar::Value::Boolean);
                 let set = set.pop();
                 pop_value_ret(value, set)
             }
-            table_list::Values::Int8(table_list::Int8List { values, set }) => {
+            table_list::Values::Int8(table_list::Int8List {
+                values,
+                set,
+                validity: _,
+            }) => {
                 let value = values.pop().map(table_scalar::Value::Int8);
                 let set = set.pop();
                 pop_value_ret(value, set)
             }
-            table_list::Values::Int16(table_list::Int16List { values, set }) => {
+            table_list::Values::Int16(table_list::Int16List {
+                values,
+                set,
+                validity: _,
+            }) => {
                 let value = values.pop().map(table_scalar::Value::Int16);
                 let set = set.pop();
                 pop_value_ret(value, set)
             }
-            table_list::Values::Int32(table_list::Int32List { values, set }) => {
+            table_list::Values::Int32(table_list::Int32List {
+                values,
+                set,
+                validity: _,
+            }) => {
                 let value = values.pop().map(table_scalar::Value::Int32);
                 let set = set.pop();
                 pop_value_ret(value, set)
             }
-            table_list::Values::Int64(table_list::Int64List { values, set }) => {
+            table_list::Values::Int64(table_list::Int64List {
+                values,
+                set,
+                validity: _,
+            }) => {
                 let value = values.pop().map(table_scalar::Value::Int64);
                 let set = set.pop();
                 pop_value_ret(value, set)
             }
-            table_list::Values::Uint8(table_list::UInt8List { values, set }) => {
+            table_list::Values::Uint8(table_list::UInt8List {
+                values,
+                set,
+                validity: _,
+            }) => {
                 let value = values.pop().map(table_scalar::Value::Uint8);
                 let set = set.pop();
                 pop_value_ret(value, set)
             }
-            table_list::Values::Uint16(table_list::UInt16List { values, set }) => {
+            table_list::Values::Uint16(table_list::UInt16List {
+                values,
+                set,
+                validity: _,
+            }) => {
                 let value = values.pop().map(table_scalar::Value::Uint16);
                 let set = set.pop();
                 pop_value_ret(value, set)
             }
-            table_list::Values::Uint32(table_list::UInt32List { values, set }) => {
+            table_list::Values::Uint32(table_list::UInt32List {
+                values,
+                set,
+                validity: _,
+            }) => {
                 let value = values.pop().map(table_scalar::Value::Uint32);
                 let set = set.pop();
                 pop_value_ret(value, set)
             }
-            table_list::Values::Uint64(table_list::UInt64List { values, set }) => {
+            table_list::Values::Uint64(table_list::UInt64List {
+                values,
+                set,
+                validity: _,
+            }) => {
                 let value = values.pop().map(table_scalar::Value::Uint64);
                 let set = set.pop();
                 pop_value_ret(value, set)
             }
-            table_list::Values::Float16(table_list::Float16List { values, set }) => {
+            table_list::Values::Float16(table_list::Float16List {
+                values,
+                set,
+                validity: _,
+            }) => {
                 let value = values.pop().map(table_scalar::Value::Float16);
                 let set = set.pop();
                 pop_value_ret(value, set)
             }
-            table_list::Values::Float32(table_list::Float32List { values, set }) => {
+            table_list::Values::Float32(table_list::Float32List {
+                values,
+                set,
+                validity: _,
+            }) => {
                 let value = values.pop().map(table_scalar::Value::Float32);
                 let set = set.pop();
                 pop_value_ret(value, set)
             }
-            table_list::Values::Float64(table_list::Float64List { values, set }) => {
+            table_list::Values::Float64(table_list::Float64List {
+                values,
+                set,
+                validity: _,
+            }) => {
                 let value = values.pop().map(table_scalar::Value::Float64);
                 let set = set.pop();
                 pop_value_ret(value, set)
             }
-            table_list::Values::Utf8(table_list::Utf8List { values, set }) => {
+            table_list::Values::Utf8(table_list::Utf8List {
+                values,
+                set,
+                validity: _,
+            }) => {
                 let value = values.pop().map(table_scalar::Value::Utf8);
                 let set = set.pop();
                 pop_value_ret(value, set)
             }
-            table_list::Values::LargeUtf8(table_list::Utf8List { values, set }) => {
+            table_list::Values::LargeUtf8(table_list::Utf8List {
+                values,
+                set,
+                validity: _,
+            }) => {
                 let value = values.pop().map(table_scalar::Value::LargeUtf8);
                 let set = set.pop();
                 pop_value_ret(value, set)
@@ -1707,6 +2379,7 @@ impl TableList {
                 set,
                 list_type: _,
                 size: _,
+                validity: _,
             }) => {
                 let value = values.pop().map(table_scalar::Value::List);
                 let set = set.pop();
@@ -1717,6 +2390,7 @@ impl TableList {
                 set,
                 list_type: _,
                 size: _,
+                validity: _,
             }) => {
                 let value = values.pop().map(table_scalar::Value::LargeList);
                 let set = set.pop();
@@ -1727,6 +2401,7 @@ impl TableList {
                 set,
                 list_type: _,
                 size: _,
+                validity: _,
             }) => {
                 let value = values.pop().map(table_scalar::Value::FixedSizeList);
                 let set = set.pop();
@@ -1736,6 +2411,7 @@ impl TableList {
                 values,
                 set,
                 size: _,
+                validity: _,
             }) => {
                 let value = values.pop().map(table_scalar::Value::Binary);
                 let set = set.pop();
@@ -1745,6 +2421,7 @@ impl TableList {
                 values,
                 set,
                 size: _,
+                validity: _,
             }) => {
                 let value = values.pop().map(table_scalar::Value::LargeBinary);
                 let set = set.pop();
@@ -1754,6 +2431,7 @@ impl TableList {
                 values,
                 set,
                 size: _,
+                validity: _,
             }) => {
                 let value = values.pop().map(table_scalar::Value::FixedSizeBinary);
                 let set = set.pop();
@@ -1763,14 +2441,14 @@ impl TableList {
                 fields,
                 values,
                 set,
+                validity: _,
             }) => {
-                //todo!("Make this resilient")
+                let elements: HashMap<String, TableScalar> = fields
+                    .iter()
+                    .zip(values.iter_mut())
+                    .filter_map(|(field, values)| Some((field.name.to_owned(), values.pop()?)))
+                    .collect();
                 if Some(true) == set.pop() {
-                    let elements: HashMap<String, TableScalar> = fields
-                        .iter()
-                        .zip(values.iter_mut())
-                        .map(|(field, values)| (field.name.to_owned(), values.pop().unwrap()))
-                        .collect();
                     Some(table_scalar::Value::Struct(table_scalar::Struct {
                         elements,
                     }))
@@ -1778,7 +2456,11 @@ impl TableList {
                     None
                 }
             }
-            table_list::Values::Union(table_list::UnionList { values, set }) => {
+            table_list::Values::Union(table_list::UnionList {
+                values,
+                set,
+                validity: _,
+            }) => {
                 let value = values
                     .pop()
                     .map(|val| table_scalar::Value::Union(Box::new(val)));
@@ -1795,27 +2477,48 @@ impl TableList {
                         .map(|val| table_scalar::Value::Dictionary(Box::new(val)))
                 })
             }
-            table_list::Values::Time32Second(table_list::Int32List { values, set }) => {
+            table_list::Values::Time32Second(table_list::Int32List {
+                values,
+                set,
+                validity: _,
+            }) => {
                 let value = values.pop().map(table_scalar::Value::Time32Second);
                 let set = set.pop();
                 pop_value_ret(value, set)
             }
-            table_list::Values::Time32Millisecond(table_list::Int32List { values, set }) => {
+            table_list::Values::Time32Millisecond(table_list::Int32List {
+                values,
+                set,
+                validity: _,
+            }) => {
                 let value = values.pop().map(table_scalar::Value::Time32Millisecond);
                 let set = set.pop();
                 pop_value_ret(value, set)
             }
-            table_list::Values::Time64Microsecond(table_list::Int64List { values, set }) => {
+            table_list::Values::Time64Microsecond(table_list::Int64List {
+                values,
+                set,
+                validity: _,
+            }) => {
                 let value = values.pop().map(table_scalar::Value::Time64Microsecond);
                 let set = set.pop();
                 pop_value_ret(value, set)
             }
-            table_list::Values::Time64Nanosecond(table_list::Int64List { values, set }) => {
+            table_list::Values::Time64Nanosecond(table_list::Int64List {
+                values,
+                set,
+                validity: _,
+            }) => {
                 let value = values.pop().map(table_scalar::Value::Time64Nanosecond);
                 let set = set.pop();
                 pop_value_ret(value, set)
             }
-            table_list::Values::TimestampSecond(table_list::TimeList { times, tz: _, set }) => {
+            table_list::Values::TimestampSecond(table_list::TimeList {
+                times,
+                tz: _,
+                set,
+                validity: _,
+            }) => {
                 let value = times.pop().map(table_scalar::Value::TimestampSecond);
                 let set = set.pop();
                 pop_value_ret(value, set)
@@ -1824,6 +2527,7 @@ impl TableList {
                 times,
                 tz: _,
                 set,
+                validity: _,
             }) => {
                 let value = times.pop().map(table_scalar::Value::TimestampMillisecond);
                 let set = set.pop();
@@ -1833,53 +2537,91 @@ impl TableList {
                 times,
                 tz: _,
                 set,
+                validity: _,
             }) => {
                 let value = times.pop().map(table_scalar::Value::TimestampMicrosecond);
                 let set = set.pop();
                 pop_value_ret(value, set)
             }
-            table_list::Values::TimestampNanosecond(table_list::TimeList { times, tz: _, set }) => {
+            table_list::Values::TimestampNanosecond(table_list::TimeList {
+                times,
+                tz: _,
+                set,
+                validity: _,
+            }) => {
                 let value = times.pop().map(table_scalar::Value::TimestampNanosecond);
                 let set = set.pop();
                 pop_value_ret(value, set)
             }
 
-            table_list::Values::Date32(table_list::Int32List { values, set }) => {
+            table_list::Values::Date32(table_list::Int32List {
+                values,
+                set,
+                validity: _,
+            }) => {
                 let value = values.pop().map(table_scalar::Value::Date32);
                 let set = set.pop();
                 pop_value_ret(value, set)
             }
-            table_list::Values::Date64(table_list::Int64List { values, set }) => {
+            table_list::Values::Date64(table_list::Int64List {
+                values,
+                set,
+                validity: _,
+            }) => {
                 let value = values.pop().map(table_scalar::Value::Date64);
                 let set = set.pop();
                 pop_value_ret(value, set)
             }
-            table_list::Values::IntervalYearMonth(table_list::Int32List { values, set }) => {
+            table_list::Values::IntervalYearMonth(table_list::Int32List {
+                values,
+                set,
+                validity: _,
+            }) => {
                 let value = values.pop().map(table_scalar::Value::IntervalYearMonth);
                 let set = set.pop();
                 pop_value_ret(value, set)
             }
-            table_list::Values::IntervalDayTime(table_list::Int64List { values, set }) => {
+            table_list::Values::IntervalDayTime(table_list::Int64List {
+                values,
+                set,
+                validity: _,
+            }) => {
                 let value = values.pop().map(table_scalar::Value::IntervalDayTime);
                 let set = set.pop();
                 pop_value_ret(value, set)
             }
-            table_list::Values::DurationSecond(table_list::Int64List { values, set }) => {
+            table_list::Values::DurationSecond(table_list::Int64List {
+                values,
+                set,
+                validity: _,
+            }) => {
                 let value = values.pop().map(table_scalar::Value::DurationSecond);
                 let set = set.pop();
                 pop_value_ret(value, set)
             }
-            table_list::Values::DurationMillisecond(table_list::Int64List { values, set }) => {
+            table_list::Values::DurationMillisecond(table_list::Int64List {
+                values,
+                set,
+                validity: _,
+            }) => {
                 let value = values.pop().map(table_scalar::Value::DurationMillisecond);
                 let set = set.pop();
                 pop_value_ret(value, set)
             }
-            table_list::Values::DurationMicrosecond(table_list::Int64List { values, set }) => {
+            table_list::Values::DurationMicrosecond(table_list::Int64List {
+                values,
+                set,
+                validity: _,
+            }) => {
                 let value = values.pop().map(table_scalar::Value::DurationMicrosecond);
                 let set = set.pop();
                 pop_value_ret(value, set)
             }
-            table_list::Values::DurationNanosecond(table_list::Int64List { values, set }) => {
+            table_list::Values::DurationNanosecond(table_list::Int64List {
+                values,
+                set,
+                validity: _,
+            }) => {
                 let value = values.pop().map(table_scalar::Value::DurationNanosecond);
                 let set = set.pop();
                 pop_value_ret(value, set)
@@ -1890,61 +2632,121 @@ impl TableList {
     }
 
     pub fn push_null(&mut self) {
+        self.stage_validity(0, |list| list.push_null_staged())
+    }
+
+    fn push_null_staged(&mut self) {
         if let Some(values) = self.values.as_mut() {
             match values {
-                table_list::Values::Boolean(table_list::BooleanList { values, set }) => {
+                table_list::Values::Boolean(table_list::BooleanList {
+                    values,
+                    set,
+                    validity: _,
+                }) => {
                     values.push(false);
                     set.push(false);
                 }
-                table_list::Values::Int8(table_list::Int8List { values, set }) => {
+                table_list::Values::Int8(table_list::Int8List {
+                    values,
+                    set,
+                    validity: _,
+                }) => {
                     values.push(0);
                     set.push(false);
                 }
-                table_list::Values::Int16(table_list::Int16List { values, set }) => {
+                table_list::Values::Int16(table_list::Int16List {
+                    values,
+                    set,
+                    validity: _,
+                }) => {
                     values.push(0);
                     set.push(false);
                 }
-                table_list::Values::Int32(table_list::Int32List { values, set }) => {
+                table_list::Values::Int32(table_list::Int32List {
+                    values,
+                    set,
+                    validity: _,
+                }) => {
                     values.push(0);
                     set.push(false);
                 }
-                table_list::Values::Int64(table_list::Int64List { values, set }) => {
+                table_list::Values::Int64(table_list::Int64List {
+                    values,
+                    set,
+                    validity: _,
+                }) => {
                     values.push(0);
                     set.push(false);
                 }
-                table_list::Values::Uint8(table_list::UInt8List { values, set }) => {
+                table_list::Values::Uint8(table_list::UInt8List {
+                    values,
+                    set,
+                    validity: _,
+                }) => {
                     values.push(0);
                     set.push(false);
                 }
-                table_list::Values::Uint16(table_list::UInt16List { values, set }) => {
+                table_list::Values::Uint16(table_list::UInt16List {
+                    values,
+                    set,
+                    validity: _,
+                }) => {
                     values.push(0);
                     set.push(false);
                 }
-                table_list::Values::Uint32(table_list::UInt32List { values, set }) => {
+                table_list::Values::Uint32(table_list::UInt32List {
+                    values,
+                    set,
+                    validity: _,
+                }) => {
                     values.push(0);
                     set.push(false);
                 }
-                table_list::Values::Uint64(table_list::UInt64List { values, set }) => {
+                table_list::Values::Uint64(table_list::UInt64List {
+                    values,
+                    set,
+                    validity: _,
+                }) => {
                     values.push(0);
                     set.push(false);
                 }
-                table_list::Values::Float16(table_list::Float16List { values, set }) => {
+                table_list::Values::Float16(table_list::Float16List {
+                    values,
+                    set,
+                    validity: _,
+                }) => {
                     values.push(0.0);
                     set.push(false);
                 }
-                table_list::Values::Float32(table_list::Float32List { values, set }) => {
+                table_list::Values::Float32(table_list::Float32List {
+                    values,
+                    set,
+                    validity: _,
+                }) => {
                     values.push(0.0);
                     set.push(false);
                 }
-                table_list::Values::Float64(table_list::Float64List { values, set }) => {
+                table_list::Values::Float64(table_list::Float64List {
+                    values,
+                    set,
+                    validity: _,
+                }) => {
                     values.push(0.0);
                     set.push(false);
                 }
-                table_list::Values::Utf8(table_list::Utf8List { values, set }) => {
+                table_list::Values::Utf8(table_list::Utf8List {
+                    values,
+                    set,
+                    validity: _,
+                }) => {
                     values.push(String::new());
                     set.push(false);
                 }
-                table_list::Values::LargeUtf8(table_list::Utf8List { values, set }) => {
+                table_list::Values::LargeUtf8(table_list::Utf8List {
+                    values,
+                    set,
+                    validity: _,
+                }) => {
                     values.push(String::new());
                     set.push(false);
                 }
@@ -1953,27 +2755,99 @@ impl TableList {
                     set,
                     list_type: _,
                     size: _,
+                    validity: _,
                 }) => {
                     values.push(TableList::default());
                     set.push(false);
                 }
-                _ => {}
+                table_list::Values::LargeList(list) | table_list::Values::FixedSizeList(list) => {
+                    list.values.push(TableList::default());
+                    list.set.push(false);
+                }
+                table_list::Values::Date32(list)
+                | table_list::Values::Time32Second(list)
+                | table_list::Values::Time32Millisecond(list)
+                | table_list::Values::IntervalYearMonth(list) => {
+                    list.values.push(0);
+                    list.set.push(false);
+                }
+                table_list::Values::Date64(list)
+                | table_list::Values::Time64Microsecond(list)
+                | table_list::Values::Time64Nanosecond(list)
+                | table_list::Values::DurationSecond(list)
+                | table_list::Values::DurationMillisecond(list)
+                | table_list::Values::DurationMicrosecond(list)
+                | table_list::Values::DurationNanosecond(list)
+                | table_list::Values::IntervalDayTime(list) => {
+                    list.values.push(0);
+                    list.set.push(false);
+                }
+                table_list::Values::TimestampSecond(list)
+                | table_list::Values::TimestampMillisecond(list)
+                | table_list::Values::TimestampMicrosecond(list)
+                | table_list::Values::TimestampNanosecond(list) => {
+                    list.times.push(0);
+                    list.set.push(false);
+                }
+                table_list::Values::Binary(list)
+                | table_list::Values::LargeBinary(list)
+                | table_list::Values::FixedSizeBinary(list) => {
+                    // A fixed-size value keeps its size even behind a null.
+                    let size = list
+                        .size
+                        .and_then(|size| usize::try_from(size).ok())
+                        .unwrap_or(0);
+                    list.values.push(vec![0; size]);
+                    list.set.push(false);
+                }
+                table_list::Values::Union(list) => {
+                    list.values.push(TableScalar::default());
+                    list.set.push(false);
+                }
+                table_list::Values::Struct(list) => {
+                    for child in list.values.iter_mut() {
+                        child.push_null();
+                    }
+                    list.set.push(false);
+                }
+                table_list::Values::Dictionary(dict) => {
+                    if let Some(values) = dict.values.as_mut() {
+                        values.push_null();
+                    }
+                }
             }
         }
     }
 
     pub fn push_date_time(&mut self, time: NaiveDateTime) -> Result<(), NaiveDateTime> {
+        self.stage_validity(0, |list| list.push_date_time_staged(time))
+    }
+
+    fn push_date_time_staged(&mut self, time: NaiveDateTime) -> Result<(), NaiveDateTime> {
         if let Some(values) = self.values.as_mut() {
             match values {
-                table_list::Values::Date32(table_list::Int32List { values, set }) => {
+                table_list::Values::Date32(table_list::Int32List {
+                    values,
+                    set,
+                    validity: _,
+                }) => {
                     values.push(time.num_days_from_ce());
                     set.push(true);
                 }
-                table_list::Values::Date64(table_list::Int64List { values, set }) => {
+                table_list::Values::Date64(table_list::Int64List {
+                    values,
+                    set,
+                    validity: _,
+                }) => {
                     values.push(time.timestamp_millis());
                     set.push(true);
                 }
-                table_list::Values::TimestampSecond(table_list::TimeList { times, tz: _, set }) => {
+                table_list::Values::TimestampSecond(table_list::TimeList {
+                    times,
+                    tz: _,
+                    set,
+                    validity: _,
+                }) => {
                     times.push(time.timestamp());
                     set.push(true);
                 }
@@ -1981,6 +2855,7 @@ impl TableList {
                     times,
                     tz: _,
                     set,
+                    validity: _,
                 }) => {
                     times.push(time.timestamp_millis());
                     set.push(true);
@@ -1989,6 +2864,7 @@ impl TableList {
                     times,
                     tz: _,
                     set,
+                    validity: _,
                 }) => {
                     times.push(time.timestamp_nanos() * 1000);
                     set.push(true);
@@ -1997,27 +2873,44 @@ impl TableList {
                     times,
                     tz: _,
                     set,
+                    validity: _,
                 }) => {
                     times.push(time.timestamp_nanos());
                     set.push(true);
                 }
-                table_list::Values::Time32Second(table_list::Int32List { values, set }) => {
+                table_list::Values::Time32Second(table_list::Int32List {
+                    values,
+                    set,
+                    validity: _,
+                }) => {
                     values.push(time.num_seconds_from_midnight() as i32);
                     set.push(true);
                 }
-                table_list::Values::Time32Millisecond(table_list::Int32List { values, set }) => {
+                table_list::Values::Time32Millisecond(table_list::Int32List {
+                    values,
+                    set,
+                    validity: _,
+                }) => {
                     let seconds: i64 = time.num_seconds_from_midnight().into();
                     let milli: i64 = time.nanosecond().into();
                     values.push((seconds * 1000 + milli / 1000000) as i32);
                     set.push(true);
                 }
-                table_list::Values::Time64Microsecond(table_list::Int64List { values, set }) => {
+                table_list::Values::Time64Microsecond(table_list::Int64List {
+                    values,
+                    set,
+                    validity: _,
+                }) => {
                     let seconds: i64 = time.num_seconds_from_midnight().into();
                     let micro: i64 = time.nanosecond().into();
                     values.push(seconds * 1000000 + micro / 1000);
                     set.push(true);
                 }
-                table_list::Values::Time64Nanosecond(table_list::Int64List { values, set }) => {
+                table_list::Values::Time64Nanosecond(table_list::Int64List {
+                    values,
+                    set,
+                    validity: _,
+                }) => {
                     let seconds: i64 = time.num_seconds_from_midnight().into();
                     let nano: i64 = time.nanosecond().into();
                     values.push(seconds * 1000000000 + nano);
@@ -2030,25 +2923,45 @@ impl TableList {
     }
 
     pub fn push_time(&mut self, time: NaiveTime) -> Result<(), NaiveTime> {
+        self.stage_validity(0, |list| list.push_time_staged(time))
+    }
+
+    fn push_time_staged(&mut self, time: NaiveTime) -> Result<(), NaiveTime> {
         if let Some(values) = self.values.as_mut() {
             match values {
-                table_list::Values::Time32Second(table_list::Int32List { values, set }) => {
+                table_list::Values::Time32Second(table_list::Int32List {
+                    values,
+                    set,
+                    validity: _,
+                }) => {
                     values.push(time.num_seconds_from_midnight() as i32);
                     set.push(true);
                 }
-                table_list::Values::Time32Millisecond(table_list::Int32List { values, set }) => {
+                table_list::Values::Time32Millisecond(table_list::Int32List {
+                    values,
+                    set,
+                    validity: _,
+                }) => {
                     let seconds: i64 = time.num_seconds_from_midnight().into();
                     let milli: i64 = time.nanosecond().into();
                     values.push((seconds * 1000 + milli / 1000000) as i32);
                     set.push(true);
                 }
-                table_list::Values::Time64Microsecond(table_list::Int64List { values, set }) => {
+                table_list::Values::Time64Microsecond(table_list::Int64List {
+                    values,
+                    set,
+                    validity: _,
+                }) => {
                     let seconds: i64 = time.num_seconds_from_midnight().into();
                     let micro: i64 = time.nanosecond().into();
                     values.push(seconds * 1000000 + micro / 1000);
                     set.push(true);
                 }
-                table_list::Values::Time64Nanosecond(table_list::Int64List { values, set }) => {
+                table_list::Values::Time64Nanosecond(table_list::Int64List {
+                    values,
+                    set,
+                    validity: _,
+                }) => {
                     let seconds: i64 = time.num_seconds_from_midnight().into();
                     let nano: i64 = time.nanosecond().into();
                     values.push(seconds * 1000000000 + nano);
@@ -2061,14 +2974,26 @@ impl TableList {
     }
 
     pub fn push_date(&mut self, date: NaiveDate) -> Result<(), NaiveDate> {
+        self.stage_validity(0, |list| list.push_date_staged(date))
+    }
+
+    fn push_date_staged(&mut self, date: NaiveDate) -> Result<(), NaiveDate> {
         if let Some(values) = self.values.as_mut() {
             match values {
-                table_list::Values::Date32(table_list::Int32List { values, set }) => {
+                table_list::Values::Date32(table_list::Int32List {
+                    values,
+                    set,
+                    validity: _,
+                }) => {
                     values.push(date.num_days_from_ce());
                     set.push(true);
                 }
-                table_list::Values::Date64(table_list::Int64List { values, set }) => {
-                    values.push(date.and_hms_opt(0, 0, 0).unwrap().timestamp_millis());
+                table_list::Values::Date64(table_list::Int64List {
+                    values,
+                    set,
+                    validity: _,
+                }) => {
+                    values.push(date.and_time(NaiveTime::default()).timestamp_millis());
                     set.push(true);
                 }
                 _ => return Err(date),
@@ -2077,186 +3002,184 @@ impl TableList {
         Ok(())
     }
 
+    /// Converts this list to an Arrow array, after checking it with [`TableList::validate`].
     pub fn to_array(&self) -> Result<ArrayRef, ArrowScalarError> {
-        if self.values.is_none() {
-            panic!("Cannot make an null list into an array.");
-        }
-        let array = match self.values.as_ref().unwrap() {
-            table_list::Values::Boolean(table_list::BooleanList { values, set }) => {
-                if set.iter().all(|f| !f) {
+        self.validate()?;
+        self.build_array()
+    }
+
+    /// Converts a list that has passed [`TableList::validate`] to an Arrow array.
+    pub(crate) fn build_array(&self) -> Result<ArrayRef, ArrowScalarError> {
+        let values = self
+            .values
+            .as_ref()
+            .ok_or(ArrowScalarError::InvalidProtobuf("TableList.values"))?;
+        let array = match values {
+            table_list::Values::Boolean(list) => {
+                let (values, validity) = (&list.values, list.validity_ref());
+                if validity == ValidityRef::AllValid {
                     Arc::new(BooleanArray::from(values.clone()))
                 } else {
                     let iter = TableListIter {
                         values: values.iter().cloned(),
-                        set: set.iter(),
+                        set: validity.iter(values.len()),
                     };
                     Arc::new(BooleanArray::from_iter(iter)) as ArrayRef
                 }
             }
-            table_list::Values::Int8(table_list::Int8List { values, set }) => {
-                if set.iter().all(|f| !f) {
+            table_list::Values::Int8(list) => {
+                let (values, validity) = (&list.values, list.validity_ref());
+                if validity == ValidityRef::AllValid {
                     Arc::new(Int8Array::from_iter_values(values.iter().map(|f| *f as i8)))
                 } else {
                     let iter = TableListIter {
                         values: values.iter().map(|f| *f as i8),
-                        set: set.iter(),
+                        set: validity.iter(values.len()),
                     };
                     Arc::new(Int8Array::from_iter(iter))
                 }
             }
-            table_list::Values::Int16(table_list::Int16List { values, set }) => {
-                if set.iter().all(|f| !f) {
+            table_list::Values::Int16(list) => {
+                let (values, validity) = (&list.values, list.validity_ref());
+                if validity == ValidityRef::AllValid {
                     Arc::new(Int16Array::from_iter_values(
                         values.iter().map(|f| *f as i16),
                     ))
                 } else {
                     let iter = TableListIter {
                         values: values.iter().map(|f| *f as i16),
-                        set: set.iter(),
+                        set: validity.iter(values.len()),
                     };
                     Arc::new(Int16Array::from_iter(iter))
                 }
             }
-            table_list::Values::Int32(table_list::Int32List { values, set }) => {
-                if set.iter().all(|f| !f) {
-                    Arc::new(Int32Array::from_iter_values(values.iter().cloned()))
-                } else {
-                    let iter = TableListIter {
-                        values: values.iter().cloned(),
-                        set: set.iter(),
-                    };
-                    Arc::new(Int32Array::from_iter(iter))
-                }
+            table_list::Values::Int32(list) => {
+                let (values, validity) = (&list.values, list.validity_ref());
+                Arc::new(primitive_array::<Int32Type>(
+                    values,
+                    validity,
+                    Int32Type::DATA_TYPE,
+                )?)
             }
-            table_list::Values::Int64(table_list::Int64List { values, set }) => {
-                if set.iter().all(|f| !f) {
-                    Arc::new(Int64Array::from_iter_values(values.iter().cloned()))
-                } else {
-                    let iter = TableListIter {
-                        values: values.iter().cloned(),
-                        set: set.iter(),
-                    };
-                    Arc::new(Int64Array::from_iter(iter))
-                }
+            table_list::Values::Int64(list) => {
+                let (values, validity) = (&list.values, list.validity_ref());
+                Arc::new(primitive_array::<Int64Type>(
+                    values,
+                    validity,
+                    Int64Type::DATA_TYPE,
+                )?)
             }
-            table_list::Values::Uint8(table_list::UInt8List { values, set }) => {
-                if set.iter().all(|f| !f) {
+            table_list::Values::Uint8(list) => {
+                let (values, validity) = (&list.values, list.validity_ref());
+                if validity == ValidityRef::AllValid {
                     Arc::new(UInt8Array::from_iter_values(
                         values.iter().map(|f| *f as u8),
                     ))
                 } else {
                     let iter = TableListIter {
                         values: values.iter().map(|f| *f as u8),
-                        set: set.iter(),
+                        set: validity.iter(values.len()),
                     };
                     Arc::new(UInt8Array::from_iter(iter))
                 }
             }
-            table_list::Values::Uint16(table_list::UInt16List { values, set }) => {
-                if set.iter().all(|f| !f) {
+            table_list::Values::Uint16(list) => {
+                let (values, validity) = (&list.values, list.validity_ref());
+                if validity == ValidityRef::AllValid {
                     Arc::new(UInt16Array::from_iter_values(
                         values.iter().map(|f| *f as u16),
                     ))
                 } else {
                     let iter = TableListIter {
                         values: values.iter().map(|f| *f as u16),
-                        set: set.iter(),
+                        set: validity.iter(values.len()),
                     };
                     Arc::new(UInt16Array::from_iter(iter))
                 }
             }
-            table_list::Values::Uint32(table_list::UInt32List { values, set }) => {
-                if set.iter().all(|f| !f) {
-                    Arc::new(UInt32Array::from_iter_values(values.iter().cloned()))
-                } else {
-                    let iter = TableListIter {
-                        values: values.iter().cloned(),
-                        set: set.iter(),
-                    };
-                    Arc::new(UInt32Array::from_iter(iter))
-                }
+            table_list::Values::Uint32(list) => {
+                let (values, validity) = (&list.values, list.validity_ref());
+                Arc::new(primitive_array::<UInt32Type>(
+                    values,
+                    validity,
+                    UInt32Type::DATA_TYPE,
+                )?)
             }
-            table_list::Values::Uint64(table_list::UInt64List { values, set }) => {
-                if set.iter().all(|f| !f) {
-                    Arc::new(UInt64Array::from_iter_values(values.iter().cloned()))
-                } else {
-                    let iter = TableListIter {
-                        values: values.iter().cloned(),
-                        set: set.iter(),
-                    };
-                    Arc::new(UInt64Array::from_iter(iter))
-                }
+            table_list::Values::Uint64(list) => {
+                let (values, validity) = (&list.values, list.validity_ref());
+                Arc::new(primitive_array::<UInt64Type>(
+                    values,
+                    validity,
+                    UInt64Type::DATA_TYPE,
+                )?)
             }
-            table_list::Values::Float16(table_list::Float16List { values, set }) => {
-                if set.iter().all(|f| !f) {
+            table_list::Values::Float16(list) => {
+                let (values, validity) = (&list.values, list.validity_ref());
+                if validity == ValidityRef::AllValid {
                     Arc::new(Float16Array::from_iter_values(
                         values.iter().map(|f| f16::from_f32(*f)),
                     ))
                 } else {
                     let iter = TableListIter {
                         values: values.iter().map(|f| f16::from_f32(*f)),
-                        set: set.iter(),
+                        set: validity.iter(values.len()),
                     };
                     Arc::new(iter.collect::<Float16Array>())
                 }
             }
-            table_list::Values::Float32(table_list::Float32List { values, set }) => {
-                if set.iter().all(|f| !f) {
-                    Arc::new(Float32Array::from_iter_values(values.iter().cloned()))
-                } else {
-                    let iter = TableListIter {
-                        values: values.iter().cloned(),
-                        set: set.iter(),
-                    };
-                    Arc::new(Float32Array::from_iter(iter))
-                }
+            table_list::Values::Float32(list) => {
+                let (values, validity) = (&list.values, list.validity_ref());
+                Arc::new(primitive_array::<Float32Type>(
+                    values,
+                    validity,
+                    Float32Type::DATA_TYPE,
+                )?)
             }
-            table_list::Values::Float64(table_list::Float64List { values, set }) => {
-                if set.iter().all(|f| !f) {
-                    Arc::new(Float64Array::from_iter_values(values.iter().cloned()))
-                } else {
-                    let iter = TableListIter {
-                        values: values.iter().cloned(),
-                        set: set.iter(),
-                    };
-                    Arc::new(Float64Array::from_iter(iter))
-                }
+            table_list::Values::Float64(list) => {
+                let (values, validity) = (&list.values, list.validity_ref());
+                Arc::new(primitive_array::<Float64Type>(
+                    values,
+                    validity,
+                    Float64Type::DATA_TYPE,
+                )?)
             }
-            table_list::Values::Utf8(table_list::Utf8List { values, set }) => {
-                if set.iter().all(|f| !f) {
+            table_list::Values::Utf8(list) => {
+                let (values, validity) = (&list.values, list.validity_ref());
+                if validity == ValidityRef::AllValid {
                     Arc::new(StringArray::from_iter_values(values.iter()))
                 } else {
                     let iter = TableListIter {
                         values: values.iter(),
-                        set: set.iter(),
+                        set: validity.iter(values.len()),
                     };
                     Arc::new(StringArray::from_iter(iter))
                 }
             }
-            table_list::Values::LargeUtf8(table_list::Utf8List { values, set }) => {
-                if set.iter().all(|f| !f) {
+            table_list::Values::LargeUtf8(list) => {
+                let (values, validity) = (&list.values, list.validity_ref());
+                if validity == ValidityRef::AllValid {
                     Arc::new(LargeStringArray::from_iter_values(values.iter()))
                 } else {
                     let iter = TableListIter {
                         values: values.iter(),
-                        set: set.iter(),
+                        set: validity.iter(values.len()),
                     };
                     Arc::new(LargeStringArray::from_iter(iter))
                 }
             }
             table_list::Values::List(list_list) => {
-                if list_list.list_type.is_none() {
-                    return Err(ArrowScalarError::InvalidProtobuf);
-                }
-                let list_type = list_list.list_type.as_ref().unwrap();
-                if list_type.data_type.is_none() {
-                    return Err(ArrowScalarError::InvalidProtobuf);
-                }
-                let list_data_type = list_type.data_type.as_ref().unwrap();
-                if list_data_type.data_type.is_none() {
-                    return Err(ArrowScalarError::InvalidProtobuf);
-                }
-                let list_data_type = list_data_type.data_type.as_ref().unwrap();
+                let list_type = list_list
+                    .list_type
+                    .as_ref()
+                    .ok_or(ArrowScalarError::InvalidProtobuf("ListList.list_type"))?;
+                let list_data_type = list_type
+                    .data_type
+                    .as_ref()
+                    .ok_or(ArrowScalarError::InvalidProtobuf("FieldProto.data_type"))?;
+                let list_data_type = list_data_type
+                    .data_type
+                    .as_ref()
+                    .ok_or(ArrowScalarError::InvalidProtobuf("DataTypeProto.data_type"))?;
                 match list_data_type {
                     data_type_proto::DataType::Int8(_) => {
                         primitive_list_list_builder_int8(list_list)
@@ -2331,10 +3254,21 @@ impl TableList {
                         string_list_list_builder(list_list)
                     }
                     data_type_proto::DataType::Dictionary(dict) => {
-                        let data_type_proto::Dictionary {key_type, value_type} = dict.as_ref();
-                        let value_type = value_type.as_ref().ok_or(ArrowScalarError::InvalidProtobuf)?;
-                        let key_type = key_type.as_ref().ok_or(ArrowScalarError::InvalidProtobuf)?;
-                        match key_type.data_type.as_ref().ok_or(ArrowScalarError::InvalidProtobuf)? {
+                        let data_type_proto::Dictionary {
+                            key_type,
+                            value_type,
+                        } = dict.as_ref();
+                        let value_type = value_type
+                            .as_ref()
+                            .ok_or(ArrowScalarError::InvalidProtobuf("Dictionary.value_type"))?;
+                        let key_type = key_type
+                            .as_ref()
+                            .ok_or(ArrowScalarError::InvalidProtobuf("Dictionary.key_type"))?;
+                        match key_type
+                            .data_type
+                            .as_ref()
+                            .ok_or(ArrowScalarError::InvalidProtobuf("DataTypeProto.data_type"))?
+                        {
                             data_type_proto::DataType::Int8(_) => {
                                 list_dict_builder::<Int8Type>(value_type.to_arrow()?, list_list)?
                             }
@@ -2359,9 +3293,12 @@ impl TableList {
                             data_type_proto::DataType::Uint64(_) => {
                                 list_dict_builder::<UInt64Type>(value_type.to_arrow()?, list_list)?
                             }
-                            _ => return Err(ArrowScalarError::InvalidProtobuf),
+                            _ => {
+                                return Err(ArrowScalarError::InvalidProtobuf(
+                                    "Dictionary.key_type",
+                                ))
+                            }
                         }
-                        
                     }
 
                     _ => {
@@ -2373,9 +3310,18 @@ impl TableList {
                 }
             }
             table_list::Values::LargeList(list_list) => {
-                let list_type = list_list.list_type.as_ref().ok_or(ArrowScalarError::InvalidProtobuf)?;
-                let list_data_type = list_type.data_type.as_ref().ok_or(ArrowScalarError::InvalidProtobuf)?;
-                let list_data_type = list_data_type.data_type.as_ref().ok_or(ArrowScalarError::InvalidProtobuf)?;
+                let list_type = list_list
+                    .list_type
+                    .as_ref()
+                    .ok_or(ArrowScalarError::InvalidProtobuf("ListList.list_type"))?;
+                let list_data_type = list_type
+                    .data_type
+                    .as_ref()
+                    .ok_or(ArrowScalarError::InvalidProtobuf("FieldProto.data_type"))?;
+                let list_data_type = list_data_type
+                    .data_type
+                    .as_ref()
+                    .ok_or(ArrowScalarError::InvalidProtobuf("DataTypeProto.data_type"))?;
                 match list_data_type {
                     data_type_proto::DataType::Int8(_) => {
                         primitive_large_list_list_builder_int8(list_list)
@@ -2466,7 +3412,7 @@ impl TableList {
             table_list::Values::Binary(list) => {
                 let mut builder = BinaryBuilder::new();
                 for (i, value) in list.values.iter().enumerate() {
-                    if list.set[i] {
+                    if list.is_valid(i) {
                         builder.append_value(value);
                     } else {
                         builder.append_null();
@@ -2477,7 +3423,7 @@ impl TableList {
             table_list::Values::LargeBinary(list) => {
                 let mut builder = LargeBinaryBuilder::new();
                 for (i, value) in list.values.iter().enumerate() {
-                    if list.set[i] {
+                    if list.is_valid(i) {
                         builder.append_value(value);
                     } else {
                         builder.append_null();
@@ -2490,14 +3436,25 @@ impl TableList {
                     .fields
                     .iter()
                     .zip(struct_list.values.iter())
-                    .map(|(field, list)| Ok((field.to_arrow()?, list.to_array()?)))
+                    .map(|(field, list)| {
+                        let array = list
+                            .build_array()
+                            .map_err(|error| error.at_path(&format!(".{}", field.name)))?;
+                        Ok((field.to_arrow()?, array))
+                    })
                     .collect::<Result<Vec<_>, ArrowScalarError>>()?;
-                Arc::new(StructArray::from(arrays))
+                match struct_list.validity_ref() {
+                    ValidityRef::AllValid => Arc::new(StructArray::from(arrays)),
+                    validity => {
+                        let bitmap = pack_bitmap(validity.iter(struct_list.row_count()));
+                        Arc::new(StructArray::from((arrays, Buffer::from_slice_ref(&bitmap))))
+                    }
+                }
             }
             table_list::Values::TimestampSecond(list) => {
                 let mut builder = TimestampSecondBuilder::new();
                 for (i, value) in list.times.iter().enumerate() {
-                    if list.set[i] {
+                    if list.is_valid(i) {
                         builder.append_value(*value);
                     } else {
                         builder.append_null();
@@ -2508,7 +3465,7 @@ impl TableList {
             table_list::Values::TimestampMillisecond(list) => {
                 let mut builder = TimestampMillisecondBuilder::new();
                 for (i, value) in list.times.iter().enumerate() {
-                    if list.set[i] {
+                    if list.is_valid(i) {
                         builder.append_value(*value);
                     } else {
                         builder.append_null();
@@ -2519,7 +3476,7 @@ impl TableList {
             table_list::Values::TimestampMicrosecond(list) => {
                 let mut builder = TimestampMicrosecondBuilder::new();
                 for (i, value) in list.times.iter().enumerate() {
-                    if list.set[i] {
+                    if list.is_valid(i) {
                         builder.append_value(*value);
                     } else {
                         builder.append_null();
@@ -2530,7 +3487,7 @@ impl TableList {
             table_list::Values::TimestampNanosecond(list) => {
                 let mut builder = TimestampNanosecondBuilder::new();
                 for (i, value) in list.times.iter().enumerate() {
-                    if list.set[i] {
+                    if list.is_valid(i) {
                         builder.append_value(*value);
                     } else {
                         builder.append_null();
@@ -2542,7 +3499,7 @@ impl TableList {
             table_list::Values::Time32Second(list) => {
                 let mut builder = Time32SecondBuilder::new();
                 for (i, value) in list.values.iter().enumerate() {
-                    if list.set[i] {
+                    if list.is_valid(i) {
                         builder.append_value(*value);
                     } else {
                         builder.append_null();
@@ -2553,7 +3510,7 @@ impl TableList {
             table_list::Values::Time32Millisecond(list) => {
                 let mut builder = Time32MillisecondBuilder::new();
                 for (i, value) in list.values.iter().enumerate() {
-                    if list.set[i] {
+                    if list.is_valid(i) {
                         builder.append_value(*value);
                     } else {
                         builder.append_null();
@@ -2564,7 +3521,7 @@ impl TableList {
             table_list::Values::Time64Microsecond(list) => {
                 let mut builder = Time64MicrosecondBuilder::new();
                 for (i, value) in list.values.iter().enumerate() {
-                    if list.set[i] {
+                    if list.is_valid(i) {
                         builder.append_value(*value);
                     } else {
                         builder.append_null();
@@ -2575,7 +3532,7 @@ impl TableList {
             table_list::Values::Time64Nanosecond(list) => {
                 let mut builder = Time64NanosecondBuilder::new();
                 for (i, value) in list.values.iter().enumerate() {
-                    if list.set[i] {
+                    if list.is_valid(i) {
                         builder.append_value(*value);
                     } else {
                         builder.append_null();
@@ -2587,7 +3544,7 @@ impl TableList {
             table_list::Values::Date32(list) => {
                 let mut builder = Date32Builder::new();
                 for (i, value) in list.values.iter().enumerate() {
-                    if list.set[i] {
+                    if list.is_valid(i) {
                         builder.append_value(*value);
                     } else {
                         builder.append_null();
@@ -2598,7 +3555,7 @@ impl TableList {
             table_list::Values::Date64(list) => {
                 let mut builder = Date64Builder::new();
                 for (i, value) in list.values.iter().enumerate() {
-                    if list.set[i] {
+                    if list.is_valid(i) {
                         builder.append_value(*value);
                     } else {
                         builder.append_null();
@@ -2609,7 +3566,7 @@ impl TableList {
             table_list::Values::DurationSecond(list) => {
                 let mut builder = DurationSecondBuilder::new();
                 for (i, value) in list.values.iter().enumerate() {
-                    if list.set[i] {
+                    if list.is_valid(i) {
                         builder.append_value(*value);
                     } else {
                         builder.append_null();
@@ -2620,7 +3577,7 @@ impl TableList {
             table_list::Values::DurationMillisecond(list) => {
                 let mut builder = DurationMillisecondBuilder::new();
                 for (i, value) in list.values.iter().enumerate() {
-                    if list.set[i] {
+                    if list.is_valid(i) {
                         builder.append_value(*value);
                     } else {
                         builder.append_null();
@@ -2631,7 +3588,7 @@ impl TableList {
             table_list::Values::DurationMicrosecond(list) => {
                 let mut builder = DurationMicrosecondBuilder::new();
                 for (i, value) in list.values.iter().enumerate() {
-                    if list.set[i] {
+                    if list.is_valid(i) {
                         builder.append_value(*value);
                     } else {
                         builder.append_null();
@@ -2642,7 +3599,7 @@ impl TableList {
             table_list::Values::DurationNanosecond(list) => {
                 let mut builder = DurationNanosecondBuilder::new();
                 for (i, value) in list.values.iter().enumerate() {
-                    if list.set[i] {
+                    if list.is_valid(i) {
                         builder.append_value(*value);
                     } else {
                         builder.append_null();
@@ -2653,7 +3610,7 @@ impl TableList {
             table_list::Values::IntervalYearMonth(list) => {
                 let mut builder = IntervalYearMonthBuilder::new();
                 for (i, value) in list.values.iter().enumerate() {
-                    if list.set[i] {
+                    if list.is_valid(i) {
                         builder.append_value(*value);
                     } else {
                         builder.append_null();
@@ -2664,7 +3621,7 @@ impl TableList {
             table_list::Values::IntervalDayTime(list) => {
                 let mut builder = IntervalDayTimeBuilder::new();
                 for (i, value) in list.values.iter().enumerate() {
-                    if list.set[i] {
+                    if list.is_valid(i) {
                         builder.append_value(*value);
                     } else {
                         builder.append_null();
@@ -2673,11 +3630,17 @@ impl TableList {
                 Arc::new(builder.finish())
             }
             table_list::Values::Dictionary(dictionary_list) => {
-                let key_type = dictionary_list.index_type.as_ref().ok_or(ArrowScalarError::InvalidProtobuf)?.to_arrow()?;
+                let key_type = dictionary_list
+                    .index_type
+                    .as_ref()
+                    .ok_or(ArrowScalarError::InvalidProtobuf(
+                        "DictionaryList.index_type",
+                    ))?
+                    .to_arrow()?;
                 let values = if let Some(values) = &dictionary_list.values {
                     values
                 } else {
-                    return Err(ArrowScalarError::InvalidProtobuf);
+                    return Err(ArrowScalarError::InvalidProtobuf("DictionaryList.values"));
                 };
                 match key_type {
                     DataType::Int8 => {
@@ -2705,7 +3668,9 @@ impl TableList {
                         dict_builder::<UInt64Type>(values)?
                     }
                     _ => {
-                        return Err(ArrowScalarError::InvalidProtobuf);
+                        return Err(ArrowScalarError::InvalidProtobuf(
+                            "DictionaryList.index_type",
+                        ));
                     }
                 }
             }
@@ -2718,8 +3683,10 @@ impl TableList {
             table_list::Values::FixedSizeBinary(list) => {
                 let mut builder = FixedSizeBinaryBuilder::new(list.size());
                 for (i, value) in list.values.iter().enumerate() {
-                    if list.set[i] {
-                        builder.append_value(value).expect("We check this on push");
+                    if list.is_valid(i) {
+                        builder
+                            .append_value(value)
+                            .map_err(ArrowScalarError::ArrowError)?;
                     } else {
                         builder.append_null();
                     }
@@ -2738,7 +3705,11 @@ impl TableList {
     }
 
     pub fn data_type(&self) -> Result<DataType, ArrowScalarError> {
-        match self.values.as_ref().unwrap() {
+        match self
+            .values
+            .as_ref()
+            .ok_or(ArrowScalarError::InvalidProtobuf("TableList.values"))?
+        {
             table_list::Values::Boolean(_) => Ok(DataType::Boolean),
             table_list::Values::Int8(_) => Ok(DataType::Int8),
             table_list::Values::Int16(_) => Ok(DataType::Int16),
@@ -2753,22 +3724,27 @@ impl TableList {
             table_list::Values::Float64(_) => Ok(DataType::Float64),
             table_list::Values::Utf8(_) => Ok(DataType::Utf8),
             table_list::Values::LargeUtf8(_) => Ok(DataType::LargeUtf8),
-            table_list::Values::Date32(table_list::Int32List { values: _, set: _ }) => {
-                Ok(DataType::Date32)
-            }
-            table_list::Values::Date64(table_list::Int64List { values: _, set: _ }) => {
-                Ok(DataType::Date64)
-            }
+            table_list::Values::Date32(table_list::Int32List {
+                values: _,
+                set: _,
+                validity: _,
+            }) => Ok(DataType::Date32),
+            table_list::Values::Date64(table_list::Int64List {
+                values: _,
+                set: _,
+                validity: _,
+            }) => Ok(DataType::Date64),
             table_list::Values::List(table_list::ListList {
                 values: _,
                 set: _,
                 list_type,
                 size: _,
+                validity: _,
             }) => {
                 if let Some(list_type) = list_type {
                     Ok(DataType::List(Box::new(list_type.to_arrow()?)))
                 } else {
-                    Err(ArrowScalarError::InvalidProtobuf)
+                    Err(ArrowScalarError::InvalidProtobuf("ListList.list_type"))
                 }
             }
             table_list::Values::LargeList(table_list::ListList {
@@ -2776,11 +3752,12 @@ impl TableList {
                 set: _,
                 list_type,
                 size: _,
+                validity: _,
             }) => {
                 if let Some(list_type) = list_type {
                     Ok(DataType::LargeList(Box::new(list_type.to_arrow()?)))
                 } else {
-                    Err(ArrowScalarError::InvalidProtobuf)
+                    Err(ArrowScalarError::InvalidProtobuf("ListList.list_type"))
                 }
             }
             table_list::Values::FixedSizeList(table_list::ListList {
@@ -2788,6 +3765,7 @@ impl TableList {
                 set: _,
                 list_type,
                 size,
+                validity: _,
             }) => {
                 if let (Some(list_type), Some(size)) = (list_type, size) {
                     Ok(DataType::FixedSizeList(
@@ -2795,7 +3773,7 @@ impl TableList {
                         *size,
                     ))
                 } else {
-                    Err(ArrowScalarError::InvalidProtobuf)
+                    Err(ArrowScalarError::InvalidProtobuf("ListList.size"))
                 }
             }
             table_list::Values::Binary(_) => Ok(DataType::Binary),
@@ -2804,17 +3782,19 @@ impl TableList {
                 values: _,
                 set: _,
                 size,
+                validity: _,
             }) => {
                 if let Some(size) = size {
                     Ok(DataType::FixedSizeBinary(*size))
                 } else {
-                    Err(ArrowScalarError::InvalidProtobuf)
+                    Err(ArrowScalarError::InvalidProtobuf("BinaryList.size"))
                 }
             }
             table_list::Values::Struct(table_list::StructList {
                 fields,
                 values: _,
                 set: _,
+                validity: _,
             }) => {
                 let fields = fields
                     .iter()
@@ -2822,9 +3802,14 @@ impl TableList {
                     .collect::<Result<Vec<_>, _>>()?;
                 Ok(DataType::Struct(fields))
             }
-            table_list::Values::Union(table_list::UnionList { values: _, set: _ }) => Err(
-                ArrowScalarError::Unimplemented("TableList::data_type", "Union"),
-            ),
+            table_list::Values::Union(table_list::UnionList {
+                values: _,
+                set: _,
+                validity: _,
+            }) => Err(ArrowScalarError::Unimplemented(
+                "TableList::data_type",
+                "Union",
+            )),
             table_list::Values::Dictionary(_dict) => Err(ArrowScalarError::Unimplemented(
                 "TableList::data_type",
                 "Union",
@@ -2837,21 +3822,25 @@ impl TableList {
                 times: _,
                 tz,
                 set: _,
+                validity: _,
             }) => Ok(DataType::Timestamp(TimeUnit::Second, tz.clone())),
             table_list::Values::TimestampMillisecond(table_list::TimeList {
                 times: _,
                 tz,
                 set: _,
+                validity: _,
             }) => Ok(DataType::Timestamp(TimeUnit::Millisecond, tz.clone())),
             table_list::Values::TimestampMicrosecond(table_list::TimeList {
                 times: _,
                 tz,
                 set: _,
+                validity: _,
             }) => Ok(DataType::Timestamp(TimeUnit::Microsecond, tz.clone())),
             table_list::Values::TimestampNanosecond(table_list::TimeList {
                 times: _,
                 tz,
                 set: _,
+                validity: _,
             }) => Ok(DataType::Timestamp(TimeUnit::Nanosecond, tz.clone())),
             table_list::Values::IntervalYearMonth(_) => {
                 Ok(DataType::Interval(IntervalUnit::YearMonth))
@@ -2874,64 +3863,133 @@ impl TableList {
         self.len() == 0
     }
 
+    /// The number of rows, null or not. A struct list has as many rows as its first field.
     pub fn len(&self) -> usize {
-        if self.values.is_none() {
-            return 0;
-        }
-        match self.values.as_ref().unwrap() {
-            table_list::Values::Boolean(table_list::BooleanList { values, set: _ }) => values.len(),
-            table_list::Values::Int8(table_list::Int8List { values, set: _ }) => values.len(),
-            table_list::Values::Int16(table_list::Int16List { values, set: _ }) => values.len(),
-            table_list::Values::Int32(table_list::Int32List { values, set: _ }) => values.len(),
-            table_list::Values::Int64(table_list::Int64List { values, set: _ }) => values.len(),
-            table_list::Values::Uint8(table_list::UInt8List { values, set: _ }) => values.len(),
-            table_list::Values::Uint16(table_list::UInt16List { values, set: _ }) => values.len(),
-            table_list::Values::Uint32(table_list::UInt32List { values, set: _ }) => values.len(),
-            table_list::Values::Uint64(table_list::UInt64List { values, set: _ }) => values.len(),
-            table_list::Values::Float16(table_list::Float16List { values, set: _ }) => values.len(),
-            table_list::Values::Float32(table_list::Float32List { values, set: _ }) => values.len(),
-            table_list::Values::Float64(table_list::Float64List { values, set: _ }) => values.len(),
-            table_list::Values::Utf8(table_list::Utf8List { values, set: _ }) => values.len(),
-            table_list::Values::LargeUtf8(table_list::Utf8List { values, set: _ }) => values.len(),
+        let values = match self.values.as_ref() {
+            Some(values) => values,
+            None => return 0,
+        };
+        match values {
+            table_list::Values::Boolean(table_list::BooleanList {
+                values,
+                set: _,
+                validity: _,
+            }) => values.len(),
+            table_list::Values::Int8(table_list::Int8List {
+                values,
+                set: _,
+                validity: _,
+            }) => values.len(),
+            table_list::Values::Int16(table_list::Int16List {
+                values,
+                set: _,
+                validity: _,
+            }) => values.len(),
+            table_list::Values::Int32(table_list::Int32List {
+                values,
+                set: _,
+                validity: _,
+            }) => values.len(),
+            table_list::Values::Int64(table_list::Int64List {
+                values,
+                set: _,
+                validity: _,
+            }) => values.len(),
+            table_list::Values::Uint8(table_list::UInt8List {
+                values,
+                set: _,
+                validity: _,
+            }) => values.len(),
+            table_list::Values::Uint16(table_list::UInt16List {
+                values,
+                set: _,
+                validity: _,
+            }) => values.len(),
+            table_list::Values::Uint32(table_list::UInt32List {
+                values,
+                set: _,
+                validity: _,
+            }) => values.len(),
+            table_list::Values::Uint64(table_list::UInt64List {
+                values,
+                set: _,
+                validity: _,
+            }) => values.len(),
+            table_list::Values::Float16(table_list::Float16List {
+                values,
+                set: _,
+                validity: _,
+            }) => values.len(),
+            table_list::Values::Float32(table_list::Float32List {
+                values,
+                set: _,
+                validity: _,
+            }) => values.len(),
+            table_list::Values::Float64(table_list::Float64List {
+                values,
+                set: _,
+                validity: _,
+            }) => values.len(),
+            table_list::Values::Utf8(table_list::Utf8List {
+                values,
+                set: _,
+                validity: _,
+            }) => values.len(),
+            table_list::Values::LargeUtf8(table_list::Utf8List {
+                values,
+                set: _,
+                validity: _,
+            }) => values.len(),
             table_list::Values::List(table_list::ListList {
                 values,
                 set: _,
                 list_type: _,
                 size: _,
+                validity: _,
             }) => values.len(),
             table_list::Values::LargeList(table_list::ListList {
                 values,
                 set: _,
                 list_type: _,
                 size: _,
+                validity: _,
             }) => values.len(),
             table_list::Values::FixedSizeList(table_list::ListList {
                 values,
                 set: _,
                 list_type: _,
                 size: _,
+                validity: _,
             }) => values.len(),
             table_list::Values::Binary(table_list::BinaryList {
                 values,
                 set: _,
                 size: _,
+                validity: _,
             }) => values.len(),
             table_list::Values::LargeBinary(table_list::BinaryList {
                 values,
                 set: _,
                 size: _,
+                validity: _,
             }) => values.len(),
             table_list::Values::FixedSizeBinary(table_list::BinaryList {
                 values,
                 set: _,
                 size: _,
+                validity: _,
             }) => values.len(),
             table_list::Values::Struct(table_list::StructList {
                 fields: _,
                 values,
                 set: _,
+                validity: _,
             }) => values.first().map(|arr| arr.len()).unwrap_or(0),
-            table_list::Values::Union(table_list::UnionList { values, set: _ }) => values.len(),
+            table_list::Values::Union(table_list::UnionList {
+                values,
+                set: _,
+                validity: _,
+            }) => values.len(),
             table_list::Values::Dictionary(dict) => {
                 let table_list::DictionaryList {
                     values,
@@ -2939,151 +3997,286 @@ impl TableList {
                 } = dict.as_ref();
                 values.as_ref().map(|a| a.len()).unwrap_or(0)
             }
-            table_list::Values::Date32(table_list::Int32List { values, set: _ }) => values.len(),
-            table_list::Values::Date64(table_list::Int64List { values, set: _ }) => values.len(),
-            table_list::Values::Time32Second(table_list::Int32List { values, set: _ }) => {
-                values.len()
-            }
-            table_list::Values::Time32Millisecond(table_list::Int32List { values, set: _ }) => {
-                values.len()
-            }
-            table_list::Values::Time64Microsecond(table_list::Int64List { values, set: _ }) => {
-                values.len()
-            }
-            table_list::Values::Time64Nanosecond(table_list::Int64List { values, set: _ }) => {
-                values.len()
-            }
+            table_list::Values::Date32(table_list::Int32List {
+                values,
+                set: _,
+                validity: _,
+            }) => values.len(),
+            table_list::Values::Date64(table_list::Int64List {
+                values,
+                set: _,
+                validity: _,
+            }) => values.len(),
+            table_list::Values::Time32Second(table_list::Int32List {
+                values,
+                set: _,
+                validity: _,
+            }) => values.len(),
+            table_list::Values::Time32Millisecond(table_list::Int32List {
+                values,
+                set: _,
+                validity: _,
+            }) => values.len(),
+            table_list::Values::Time64Microsecond(table_list::Int64List {
+                values,
+                set: _,
+                validity: _,
+            }) => values.len(),
+            table_list::Values::Time64Nanosecond(table_list::Int64List {
+                values,
+                set: _,
+                validity: _,
+            }) => values.len(),
             table_list::Values::TimestampSecond(table_list::TimeList {
                 times,
                 tz: _,
                 set: _,
+                validity: _,
             }) => times.len(),
             table_list::Values::TimestampMillisecond(table_list::TimeList {
                 times,
                 tz: _,
                 set: _,
+                validity: _,
             }) => times.len(),
             table_list::Values::TimestampMicrosecond(table_list::TimeList {
                 times,
                 tz: _,
                 set: _,
+                validity: _,
             }) => times.len(),
             table_list::Values::TimestampNanosecond(table_list::TimeList {
                 times,
                 tz: _,
                 set: _,
+                validity: _,
             }) => times.len(),
-            table_list::Values::IntervalYearMonth(table_list::Int32List { values, set: _ }) => {
-                values.len()
-            }
-            table_list::Values::IntervalDayTime(table_list::Int64List { values, set: _ }) => {
-                values.len()
-            }
-            table_list::Values::DurationSecond(table_list::Int64List { values, set: _ }) => {
-                values.len()
-            }
-            table_list::Values::DurationMillisecond(table_list::Int64List { values, set: _ }) => {
-                values.len()
-            }
-            table_list::Values::DurationMicrosecond(table_list::Int64List { values, set: _ }) => {
-                values.len()
-            }
-            table_list::Values::DurationNanosecond(table_list::Int64List { values, set: _ }) => {
-                values.len()
-            }
+            table_list::Values::IntervalYearMonth(table_list::Int32List {
+                values,
+                set: _,
+                validity: _,
+            }) => values.len(),
+            table_list::Values::IntervalDayTime(table_list::Int64List {
+                values,
+                set: _,
+                validity: _,
+            }) => values.len(),
+            table_list::Values::DurationSecond(table_list::Int64List {
+                values,
+                set: _,
+                validity: _,
+            }) => values.len(),
+            table_list::Values::DurationMillisecond(table_list::Int64List {
+                values,
+                set: _,
+                validity: _,
+            }) => values.len(),
+            table_list::Values::DurationMicrosecond(table_list::Int64List {
+                values,
+                set: _,
+                validity: _,
+            }) => values.len(),
+            table_list::Values::DurationNanosecond(table_list::Int64List {
+                values,
+                set: _,
+                validity: _,
+            }) => values.len(),
         }
     }
 
     pub fn clear(&mut self) {
+        let len = self.len();
+        self.stage_validity(len, |list| list.clear_staged())
+    }
+
+    fn clear_staged(&mut self) {
         match self.values.as_mut() {
             None => {}
-            Some(table_list::Values::Boolean(table_list::BooleanList { values, set })) => {
+            Some(table_list::Values::Boolean(table_list::BooleanList {
+                values,
+                set,
+                validity: _,
+            })) => {
                 values.clear();
                 set.clear();
             }
-            Some(table_list::Values::Int8(table_list::Int8List { values, set })) => {
+            Some(table_list::Values::Int8(table_list::Int8List {
+                values,
+                set,
+                validity: _,
+            })) => {
                 values.clear();
                 set.clear();
             }
-            Some(table_list::Values::Int16(table_list::Int16List { values, set })) => {
+            Some(table_list::Values::Int16(table_list::Int16List {
+                values,
+                set,
+                validity: _,
+            })) => {
                 values.clear();
                 set.clear();
             }
-            Some(table_list::Values::Int32(table_list::Int32List { values, set })) => {
+            Some(table_list::Values::Int32(table_list::Int32List {
+                values,
+                set,
+                validity: _,
+            })) => {
                 values.clear();
                 set.clear();
             }
-            Some(table_list::Values::Int64(table_list::Int64List { values, set })) => {
+            Some(table_list::Values::Int64(table_list::Int64List {
+                values,
+                set,
+                validity: _,
+            })) => {
                 values.clear();
                 set.clear();
             }
-            Some(table_list::Values::Uint8(table_list::UInt8List { values, set })) => {
+            Some(table_list::Values::Uint8(table_list::UInt8List {
+                values,
+                set,
+                validity: _,
+            })) => {
                 values.clear();
                 set.clear();
             }
-            Some(table_list::Values::Uint16(table_list::UInt16List { values, set })) => {
+            Some(table_list::Values::Uint16(table_list::UInt16List {
+                values,
+                set,
+                validity: _,
+            })) => {
                 values.clear();
                 set.clear();
             }
-            Some(table_list::Values::Uint32(table_list::UInt32List { values, set })) => {
+            Some(table_list::Values::Uint32(table_list::UInt32List {
+                values,
+                set,
+                validity: _,
+            })) => {
                 values.clear();
                 set.clear();
             }
-            Some(table_list::Values::Uint64(table_list::UInt64List { values, set })) => {
+            Some(table_list::Values::Uint64(table_list::UInt64List {
+                values,
+                set,
+                validity: _,
+            })) => {
                 values.clear();
                 set.clear();
             }
-            Some(table_list::Values::Float16(table_list::Float16List { values, set })) => {
+            Some(table_list::Values::Float16(table_list::Float16List {
+                values,
+                set,
+                validity: _,
+            })) => {
                 values.clear();
                 set.clear();
             }
-            Some(table_list::Values::Float32(table_list::Float32List { values, set })) => {
+            Some(table_list::Values::Float32(table_list::Float32List {
+                values,
+                set,
+                validity: _,
+            })) => {
                 values.clear();
                 set.clear();
             }
-            Some(table_list::Values::Float64(table_list::Float64List { values, set })) => {
+            Some(table_list::Values::Float64(table_list::Float64List {
+                values,
+                set,
+                validity: _,
+            })) => {
                 values.clear();
                 set.clear();
             }
-            Some(table_list::Values::Utf8(table_list::Utf8List { values, set })) => {
+            Some(table_list::Values::Utf8(table_list::Utf8List {
+                values,
+                set,
+                validity: _,
+            })) => {
                 values.clear();
                 set.clear();
             }
-            Some(table_list::Values::LargeUtf8(table_list::Utf8List { values, set })) => {
+            Some(table_list::Values::LargeUtf8(table_list::Utf8List {
+                values,
+                set,
+                validity: _,
+            })) => {
                 values.clear();
                 set.clear();
             }
-            Some(table_list::Values::Binary(table_list::BinaryList { values, set, size: _ })) => {
+            Some(table_list::Values::Binary(table_list::BinaryList {
+                values,
+                set,
+                size: _,
+                validity: _,
+            })) => {
                 values.clear();
                 set.clear();
             }
-            Some(table_list::Values::LargeBinary(table_list::BinaryList { values, set, size: _ })) => {
+            Some(table_list::Values::LargeBinary(table_list::BinaryList {
+                values,
+                set,
+                size: _,
+                validity: _,
+            })) => {
                 values.clear();
                 set.clear();
             }
-            Some(table_list::Values::FixedSizeBinary(table_list::BinaryList { values, set, size: _ })) => {
+            Some(table_list::Values::FixedSizeBinary(table_list::BinaryList {
+                values,
+                set,
+                size: _,
+                validity: _,
+            })) => {
                 values.clear();
                 set.clear();
             }
-            Some(table_list::Values::List(table_list::ListList { values, set, list_type: _, size: _ })) => {
+            Some(table_list::Values::List(table_list::ListList {
+                values,
+                set,
+                list_type: _,
+                size: _,
+                validity: _,
+            })) => {
                 values.clear();
                 set.clear();
             }
-            Some(table_list::Values::LargeList(table_list::ListList { values, set, list_type: _, size: _ })) => {
+            Some(table_list::Values::LargeList(table_list::ListList {
+                values,
+                set,
+                list_type: _,
+                size: _,
+                validity: _,
+            })) => {
                 values.clear();
                 set.clear();
             }
-            Some(table_list::Values::FixedSizeList(table_list::ListList { values, set, list_type: _, size: _ })) => {
+            Some(table_list::Values::FixedSizeList(table_list::ListList {
+                values,
+                set,
+                list_type: _,
+                size: _,
+                validity: _,
+            })) => {
                 values.clear();
                 set.clear();
             }
-            Some(table_list::Values::Struct(table_list::StructList { values, set, fields: _ })) => {
+            Some(table_list::Values::Struct(table_list::StructList {
+                values,
+                set,
+                fields: _,
+                validity: _,
+            })) => {
                 for value in values {
                     value.clear();
                 }
                 set.clear();
             }
-            Some(table_list::Values::Union(table_list::UnionList { values, set })) => {
+            Some(table_list::Values::Union(table_list::UnionList {
+                values,
+                set,
+                validity: _,
+            })) => {
                 values.clear();
                 set.clear();
             }
@@ -3092,77 +4285,135 @@ impl TableList {
                     values,
                     index_type: _,
                 } = dict.as_mut();
-                values.as_mut().map(|a| a.clear());
+                if let Some(values) = values.as_mut() {
+                    values.clear();
+                }
             }
-            Some(table_list::Values::Date32(table_list::Int32List { values, set })) => {
+            Some(table_list::Values::Date32(table_list::Int32List {
+                values,
+                set,
+                validity: _,
+            })) => {
                 values.clear();
                 set.clear();
             }
-            Some(table_list::Values::Date64(table_list::Int64List { values, set })) => {
+            Some(table_list::Values::Date64(table_list::Int64List {
+                values,
+                set,
+                validity: _,
+            })) => {
                 values.clear();
                 set.clear();
             }
-            Some(table_list::Values::Time32Second(table_list::Int32List { values, set })) => {
+            Some(table_list::Values::Time32Second(table_list::Int32List {
+                values,
+                set,
+                validity: _,
+            })) => {
                 values.clear();
                 set.clear();
             }
-            Some(table_list::Values::Time32Millisecond(table_list::Int32List { values, set })) => {
+            Some(table_list::Values::Time32Millisecond(table_list::Int32List {
+                values,
+                set,
+                validity: _,
+            })) => {
                 values.clear();
                 set.clear();
             }
-            Some(table_list::Values::Time64Microsecond(table_list::Int64List { values, set })) => {
+            Some(table_list::Values::Time64Microsecond(table_list::Int64List {
+                values,
+                set,
+                validity: _,
+            })) => {
                 values.clear();
                 set.clear();
             }
-            Some(table_list::Values::Time64Nanosecond(table_list::Int64List { values, set })) => {
+            Some(table_list::Values::Time64Nanosecond(table_list::Int64List {
+                values,
+                set,
+                validity: _,
+            })) => {
                 values.clear();
                 set.clear();
             }
             Some(table_list::Values::TimestampSecond(table_list::TimeList {times,
                 tz: _,
-                set})) => {
+                set,
+                validity: _,
+            })) => {
                 times.clear();
                 set.clear();
             }
             Some(table_list::Values::TimestampMillisecond(table_list::TimeList {times,
                 tz: _,
-                set})) => {
+                set,
+                validity: _,
+            })) => {
                 times.clear();
                 set.clear();
             }
             Some(table_list::Values::TimestampMicrosecond(table_list::TimeList {times,
                 tz: _,
-                set})) => {
+                set,
+                validity: _,
+            })) => {
                 times.clear();
                 set.clear();
             }
             Some(table_list::Values::TimestampNanosecond(table_list::TimeList {times,
                 tz: _,
-                set})) => {
+                set,
+                validity: _,
+            })) => {
                 times.clear();
                 set.clear();
             }
-            Some(table_list::Values::IntervalYearMonth(table_list::Int32List { values, set })) => {
+            Some(table_list::Values::IntervalYearMonth(table_list::Int32List {
+                values,
+                set,
+                validity: _,
+            })) => {
                 values.clear();
                 set.clear();
             }
-            Some(table_list::Values::IntervalDayTime(table_list::Int64List { values, set })) => {
+            Some(table_list::Values::IntervalDayTime(table_list::Int64List {
+                values,
+                set,
+                validity: _,
+            })) => {
                 values.clear();
                 set.clear();
             }
-            Some(table_list::Values::DurationSecond(table_list::Int64List { values, set })) => {
+            Some(table_list::Values::DurationSecond(table_list::Int64List {
+                values,
+                set,
+                validity: _,
+            })) => {
                 values.clear();
                 set.clear();
             }
-            Some(table_list::Values::DurationMillisecond(table_list::Int64List { values, set })) => {
+            Some(table_list::Values::DurationMillisecond(table_list::Int64List {
+                values,
+                set,
+                validity: _,
+            })) => {
                 values.clear();
                 set.clear();
             }
-            Some(table_list::Values::DurationMicrosecond(table_list::Int64List { values, set })) => {
+            Some(table_list::Values::DurationMicrosecond(table_list::Int64List {
+                values,
+                set,
+                validity: _,
+            })) => {
                 values.clear();
                 set.clear();
             }
-            Some(table_list::Values::DurationNanosecond(table_list::Int64List { values, set })) => {
+            Some(table_list::Values::DurationNanosecond(table_list::Int64List {
+                values,
+                set,
+                validity: _,
+            })) => {
                 values.clear();
                 set.clear();
             }
@@ -3171,21 +4422,37 @@ impl TableList {
 }
 
 impl table_list::ListList {
+    /// Appends a list of the item type, or hands it back if it does not match.
+    #[allow(clippy::result_large_err)]
     pub fn push(&mut self, list: TableList) -> Result<(), TableList> {
-        if list.values.is_none() {
-            self.values.push(TableList::default());
-            self.set.push(false);
-        }
-        if self.list_type.is_none() {
-            return Err(list);
+        let start = self.stage(0);
+        let result = self.push_staged(list);
+        if let Some(start) = start {
+            self.commit(start);
         }
-        let list_type = self.list_type.as_ref().unwrap();
-        if list_type.data_type.is_none() {
-            return Err(list);
-        }
-        let list_data_type = list_type.to_arrow().expect("valid type");
+        result
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn push_staged(&mut self, list: TableList) -> Result<(), TableList> {
+        let values = match list.values {
+            Some(values) => values,
+            None => {
+                self.values.push(TableList::default());
+                self.set.push(false);
+                return Ok(());
+            }
+        };
+        let list_data_type = match self.list_type.as_ref().map(FieldProto::to_arrow) {
+            Some(Ok(list_data_type)) => list_data_type,
+            _ => {
+                return Err(TableList {
+                    values: Some(values),
+                })
+            }
+        };
 
-        match (list_data_type.data_type(), list.values.unwrap()) {
+        match (list_data_type.data_type(), values) {
             (DataType::Boolean, table_list::Values::Boolean(value)) => {
                 let rebuilt_list = TableList {
                     values: Some(table_list::Values::Boolean(value)),
@@ -3494,20 +4761,27 @@ macro_rules! small_primitive_list_ingestor {
             let mut list_builder =
                 $list_size::with_capacity(primitive_list_builder, list.values.len());
 
-            for list in list.values.iter() {
-                if let Some(table_list::Values::$values_type(table_list::$list_type {
-                    values,
-                    set,
-                })) = &list.values
-                {
+            let validity = list.validity_ref();
+            for (list, valid) in list.values.iter().zip(validity.iter(list.values.len())) {
+                if !valid {
+                    list_builder.append(false);
+                    continue;
+                }
+                if let Some(table_list::Values::$values_type(child)) = &list.values {
+                    let (values, validity) = (&child.values, child.validity_ref());
                     let primitive_list_builder = list_builder.values();
-                    values.iter().zip(set.iter()).for_each(|(v, s)| {
-                        if *s {
-                            primitive_list_builder.append_value((*v).try_into().unwrap());
-                        } else {
-                            primitive_list_builder.append_null();
-                        }
-                    });
+                    values
+                        .iter()
+                        .zip(validity.iter(values.len()))
+                        .for_each(|(v, s)| {
+                            if s {
+                                primitive_list_builder.append_value(
+                                    *v as <$primitive_type as ArrowPrimitiveType>::Native,
+                                );
+                            } else {
+                                primitive_list_builder.append_null();
+                            }
+                        });
                     list_builder.append(true);
                 } else {
                     list_builder.append(false);
@@ -3526,14 +4800,23 @@ macro_rules! primitive_list_ingestor {
             let mut list_builder =
                 $list_size::with_capacity(primitive_list_builder, list.values.len());
 
-            for list in list.values.iter() {
-                if let Some(table_list::Values::$values_type(table_list::$list_type {
-                    values,
-                    set,
-                })) = &list.values
-                {
+            let validity = list.validity_ref();
+            for (list, valid) in list.values.iter().zip(validity.iter(list.values.len())) {
+                if !valid {
+                    list_builder.append(false);
+                    continue;
+                }
+                if let Some(table_list::Values::$values_type(child)) = &list.values {
+                    let (values, validity) = (&child.values, child.validity_ref());
                     let primitive_list_builder = list_builder.values();
-                    primitive_list_builder.append_values(values.as_slice(), set.as_slice());
+                    match validity {
+                        ValidityRef::AllValid => primitive_list_builder.append_slice(values),
+                        ValidityRef::Set(set) => primitive_list_builder.append_values(values, set),
+                        ValidityRef::Bitmap(_) => {
+                            let set = validity.iter(values.len()).collect::<Vec<_>>();
+                            primitive_list_builder.append_values(values, &set);
+                        }
+                    }
                     list_builder.append(true);
                 } else {
                     list_builder.append(false);
@@ -3549,18 +4832,25 @@ fn primitive_list_list_builder_float16(list: &table_list::ListList) -> ArrayRef
         PrimitiveBuilder::<Float16Type>::with_capacity(list.values.iter().map(|l| l.len()).sum());
     let mut list_builder = ListBuilder::with_capacity(primitive_list_builder, list.values.len());
 
-    for list in list.values.iter() {
-        if let Some(table_list::Values::Float16(table_list::Float16List { values, set })) =
-            &list.values
-        {
+    let validity = list.validity_ref();
+    for (list, valid) in list.values.iter().zip(validity.iter(list.values.len())) {
+        if !valid {
+            list_builder.append(false);
+            continue;
+        }
+        if let Some(table_list::Values::Float16(child)) = &list.values {
+            let (values, validity) = (&child.values, child.validity_ref());
             let primitive_list_builder = list_builder.values();
-            values.iter().zip(set.iter()).for_each(|(v, s)| {
-                if *s {
-                    primitive_list_builder.append_value(f16::from_f32(*v));
-                } else {
-                    primitive_list_builder.append_null();
-                }
-            });
+            values
+                .iter()
+                .zip(validity.iter(values.len()))
+                .for_each(|(v, s)| {
+                    if s {
+                        primitive_list_builder.append_value(f16::from_f32(*v));
+                    } else {
+                        primitive_list_builder.append_null();
+                    }
+                });
             list_builder.append(true);
         } else {
             list_builder.append(false);
@@ -3572,18 +4862,25 @@ fn primitive_list_list_builder_float16(list: &table_list::ListList) -> ArrayRef
 fn string_list_list_builder(list: &table_list::ListList) -> ArrayRef {
     let mut list_builder = ListBuilder::new(StringBuilder::new());
 
-    for list in list.values.iter() {
-        if let Some(table_list::Values::Utf8(table_list::Utf8List { values, set })) =
-            &list.values
-        {
+    let validity = list.validity_ref();
+    for (list, valid) in list.values.iter().zip(validity.iter(list.values.len())) {
+        if !valid {
+            list_builder.append(false);
+            continue;
+        }
+        if let Some(table_list::Values::Utf8(child)) = &list.values {
+            let (values, validity) = (&child.values, child.validity_ref());
             let string_list_builder = list_builder.values();
-            values.iter().zip(set.iter()).for_each(|(v, s)| {
-                if *s {
-                    string_list_builder.append_value(v);
-                } else {
-                    string_list_builder.append_null();
-                }
-            });
+            values
+                .iter()
+                .zip(validity.iter(values.len()))
+                .for_each(|(v, s)| {
+                    if s {
+                        string_list_builder.append_value(v);
+                    } else {
+                        string_list_builder.append_null();
+                    }
+                });
             list_builder.append(true);
         } else {
             list_builder.append(false);
@@ -3595,18 +4892,25 @@ fn string_list_list_builder(list: &table_list::ListList) -> ArrayRef {
 fn string_large_list_list_builder(list: &table_list::ListList) -> ArrayRef {
     let mut list_builder = LargeListBuilder::new(StringBuilder::new());
 
-    for list in list.values.iter() {
-        if let Some(table_list::Values::Utf8(table_list::Utf8List { values, set })) =
-            &list.values
-        {
+    let validity = list.validity_ref();
+    for (list, valid) in list.values.iter().zip(validity.iter(list.values.len())) {
+        if !valid {
+            list_builder.append(false);
+            continue;
+        }
+        if let Some(table_list::Values::Utf8(child)) = &list.values {
+            let (values, validity) = (&child.values, child.validity_ref());
             let string_list_builder = list_builder.values();
-            values.iter().zip(set.iter()).for_each(|(v, s)| {
-                if *s {
-                    string_list_builder.append_value(v);
-                } else {
-                    string_list_builder.append_null();
-                }
-            });
+            values
+                .iter()
+                .zip(validity.iter(values.len()))
+                .for_each(|(v, s)| {
+                    if s {
+                        string_list_builder.append_value(v);
+                    } else {
+                        string_list_builder.append_null();
+                    }
+                });
             list_builder.append(true);
         } else {
             list_builder.append(false);
@@ -3621,18 +4925,25 @@ fn primitive_large_list_list_builder_float16(list: &table_list::ListList) -> Arr
     let mut list_builder =
         LargeListBuilder::with_capacity(primitive_list_builder, list.values.len());
 
-    for list in list.values.iter() {
-        if let Some(table_list::Values::Float16(table_list::Float16List { values, set })) =
-            &list.values
-        {
+    let validity = list.validity_ref();
+    for (list, valid) in list.values.iter().zip(validity.iter(list.values.len())) {
+        if !valid {
+            list_builder.append(false);
+            continue;
+        }
+        if let Some(table_list::Values::Float16(child)) = &list.values {
+            let (values, validity) = (&child.values, child.validity_ref());
             let primitive_list_builder = list_builder.values();
-            values.iter().zip(set.iter()).for_each(|(v, s)| {
-                if *s {
-                    primitive_list_builder.append_value(f16::from_f32(*v));
-                } else {
-                    primitive_list_builder.append_null();
-                }
-            });
+            values
+                .iter()
+                .zip(validity.iter(values.len()))
+                .for_each(|(v, s)| {
+                    if s {
+                        primitive_list_builder.append_value(f16::from_f32(*v));
+                    } else {
+                        primitive_list_builder.append_null();
+                    }
+                });
             list_builder.append(true);
         } else {
             list_builder.append(false);
@@ -3954,7 +5265,7 @@ primitive_list_ingestor!(
 );
 
 #[cfg(test)]
-pub mod tests {
+mod tests {
     use std::ops::Deref;
 
     use super::*;
@@ -3967,12 +5278,14 @@ pub mod tests {
                 let values = $values;
                 let array = Arc::new(<$array_type>::from(values));
                 let list = array.clone_as_list().unwrap();
-                let intended_list = TableList {
+                let mut intended_list = TableList {
                     values: Some(table_list::Values::$values_type(table_list::$list_type {
                         values: $intended_values,
                         set: $set,
+                        validity: None,
                     })),
                 };
+                intended_list.pack_validity();
                 assert_eq!(intended_list, list);
                 assert_eq!($prim_type(&list.to_array().unwrap()), array.deref());
             }
@@ -3988,6 +5301,7 @@ pub mod tests {
                     values: Some(table_list::Values::$values_type(table_list::$list_type {
                         values: $intended_values,
                         set: $set,
+                        validity: None,
                     })),
                 };
                 list.push(TableScalar { value: None }).unwrap();
@@ -4412,12 +5726,14 @@ pub mod tests {
         .into_iter()
         .collect();
         let list = array.clone_as_list().unwrap();
-        let intended_list = TableList {
+        let mut intended_list = TableList {
             values: Some(table_list::Values::Float16(Float16List {
                 values: vec![1.0, 2.0, 0.0, 3.0, 4.0],
                 set: vec![true, true, false, true, true],
+                validity: None,
             })),
         };
+        intended_list.pack_validity();
         assert_eq!(intended_list, list);
         assert_eq!(
             as_primitive_array::<Float16Type>(&list.to_array().unwrap()),
@@ -4436,12 +5752,14 @@ pub mod tests {
         .into_iter()
         .collect();
         let list = array.clone_as_list().unwrap();
-        let intended_list = TableList {
+        let mut intended_list = TableList {
             values: Some(table_list::Values::Float16(Float16List {
                 values: vec![1.0, 2.0, 5.0, 3.0, 4.0],
                 set: vec![true, true, true, true, true],
+                validity: None,
             })),
         };
+        intended_list.pack_validity();
         assert_eq!(intended_list, list);
         assert_eq!(
             as_primitive_array::<Float16Type>(&list.to_array().unwrap()),
@@ -4454,9 +5772,10 @@ pub mod tests {
             values: Some(table_list::Values::Float32(table_list::Float32List {
                 values: vec![1.0, 2.0, 5.0, 3.0, 4.0],
                 set: vec![true, true, true, true, true],
+                validity: None,
             })),
         };
-        let intended_list = TableList {
+        let mut intended_list = TableList {
             values: Some(table_list::Values::List(ListList {
                 values: vec![
                     list.clone(),
@@ -4472,6 +5791,7 @@ pub mod tests {
                     nullable: true,
                 }),
                 size: None,
+                validity: None,
             })),
         };
         let mut list_builder = ListBuilder::new(PrimitiveBuilder::<Float32Type>::new());
@@ -4485,6 +5805,7 @@ pub mod tests {
         let array = list_builder.finish();
         let list = array.clone_as_list().unwrap();
 
+        intended_list.pack_validity();
         assert_eq!(intended_list, list);
         assert_eq!(as_list_array(&list.to_array().unwrap()), &array);
     }
@@ -4495,9 +5816,10 @@ pub mod tests {
             values: Some(table_list::Values::Float32(table_list::Float32List {
                 values: vec![1.0, 2.0, 5.0, 3.0, 4.0],
                 set: vec![true, true, true, true, true],
+                validity: None,
             })),
         };
-        let intended_list = TableList {
+        let mut intended_list = TableList {
             values: Some(table_list::Values::LargeList(ListList {
                 values: vec![
                     list.clone(),
@@ -4513,6 +5835,7 @@ pub mod tests {
                     nullable: true,
                 }),
                 size: None,
+                validity: None,
             })),
         };
         let mut list_builder = LargeListBuilder::new(PrimitiveBuilder::<Float32Type>::new());
@@ -4526,6 +5849,7 @@ pub mod tests {
         let array = list_builder.finish();
         let list = array.clone_as_list().unwrap();
 
+        intended_list.pack_validity();
         assert_eq!(intended_list, list);
         assert_eq!(as_large_list_array(&list.to_array().unwrap()), &array);
     }
@@ -4537,9 +5861,10 @@ pub mod tests {
             values: Some(table_list::Values::Utf8(table_list::Utf8List {
                 values: values.clone(),
                 set: vec![true, true, true, true, true],
+                validity: None,
             })),
         };
-        let intended_list = TableList {
+        let mut intended_list = TableList {
             values: Some(table_list::Values::List(ListList {
                 values: vec![
                     list.clone(),
@@ -4555,6 +5880,7 @@ pub mod tests {
                     nullable: true,
                 }),
                 size: None,
+                validity: None,
             })),
         };
         let mut list_builder = ListBuilder::new(StringBuilder::new());
@@ -4571,6 +5897,7 @@ pub mod tests {
         let array = list_builder.finish();
         let list = array.clone_as_list().unwrap();
 
+        intended_list.pack_validity();
         assert_eq!(intended_list, list);
         assert_eq!(as_list_array(&list.to_array().unwrap()), &array);
     }
@@ -4582,6 +5909,7 @@ pub mod tests {
             values: Some(table_list::Values::Utf8(table_list::Utf8List {
                 values: values.clone(),
                 set: vec![true, true, true, true, true],
+                validity: None,
             })),
         };
         let dict_list = TableList {
@@ -4590,7 +5918,7 @@ pub mod tests {
             index_type: Some(DataTypeProto::from_arrow(&DataType::Int32)),
         })))};
 
-        let intended_list = TableList {
+        let mut intended_list = TableList {
             values: Some(table_list::Values::List(ListList {
                 values: vec![
                     dict_list.clone(),
@@ -4606,9 +5934,9 @@ pub mod tests {
                     nullable: true,
                 }),
                 size: None,
+                validity: None,
             })),
         };
-    
 
         let mut list_builder = ListBuilder::new(StringDictionaryBuilder::<Int32Type>::new());
         for _ in 0..5 {
@@ -4622,7 +5950,133 @@ pub mod tests {
         }
         let array = list_builder.finish();
         let list = array.clone_as_list().unwrap();
+        intended_list.pack_validity();
         assert_eq!(intended_list, list);
         assert_eq!(as_list_array(&list.to_array().unwrap()), &array);
     }
+
+    #[test]
+    fn test_list_iter() {
+        let array = Int64Array::from(vec![Some(1), None, Some(3)]);
+        let list = array.clone_as_list().unwrap();
+        let scalars = list.iter().collect::<Vec<_>>();
+        assert_eq!(
+            scalars,
+            vec![
+                TableScalar::int64(1),
+                TableScalar { value: None },
+                TableScalar::int64(3)
+            ]
+        );
+        assert_eq!(list.iter().size_hint(), (0, Some(3)));
+        assert_eq!((&list).into_iter().collect::<Vec<_>>(), scalars);
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), scalars);
+
+        let malformed = TableList {
+            values: Some(table_list::Values::Int32(table_list::Int32List {
+                values: vec![1, 2],
+                set: vec![true],
+                validity: None,
+            })),
+        };
+        let mut iter = malformed.iter();
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(malformed.into_iter().count(), 0);
+    }
+
+    #[test]
+    fn test_typed_list_iter() {
+        let array = TimestampSecondArray::from(vec![Some(10), None, Some(30)]);
+        let list = array.clone_as_list().unwrap();
+        assert_eq!(
+            list.iter_i64().unwrap().collect::<Vec<_>>(),
+            vec![Some(10), None, Some(30)]
+        );
+        assert!(list.iter_str().is_none());
+
+        let array = StringArray::from(vec![Some("a"), None, Some("c")]);
+        let list = array.clone_as_list().unwrap();
+        assert_eq!(
+            list.iter_str().unwrap().collect::<Vec<_>>(),
+            vec![Some("a"), None, Some("c")]
+        );
+        assert!(list.iter_i64().is_none());
+
+        let array: DictionaryArray<Int8Type> = vec!["x", "y", "x"].into_iter().collect();
+        let list = array.clone_as_list().unwrap();
+        assert_eq!(
+            list.iter_str().unwrap().collect::<Vec<_>>(),
+            vec![Some("x"), Some("y"), Some("x")]
+        );
+    }
+
+    #[test]
+    fn test_bad_input_errors() {
+        assert!(TableList::default().to_array().is_err());
+        assert!(TableList::default().data_type().is_err());
+        assert!(TableList::from_values::<Int32Type>(vec![1])
+            .scalar(1)
+            .is_err());
+        assert!(Int32Array::from(vec![1]).scalar(1).is_err());
+
+        let decimal = DataTypeProto {
+            data_type: Some(data_type_proto::DataType::Decimal128(
+                data_type_proto::Decimal {
+                    precision: 1000,
+                    scale: 0,
+                },
+            )),
+        };
+        assert!(decimal.to_arrow().is_err());
+
+        let mut untyped = ListList::default();
+        let item = TableList::from_values::<Int32Type>(vec![1]);
+        assert_eq!(untyped.push(item.clone()), Err(item));
+        assert!(untyped.push(TableList::default()).is_ok());
+        assert_eq!(untyped.set, vec![false]);
+
+        let mut dictionary = TableList {
+            values: Some(table_list::Values::Dictionary(Box::default())),
+        };
+        let scalar = TableScalar {
+            value: Some(table_scalar::Value::Dictionary(Box::new(
+                TableScalar::utf8("a".to_string()),
+            ))),
+        };
+        assert!(dictionary.push(scalar).is_err());
+    }
+
+    #[test]
+    fn test_push_struct() {
+        let array = StructArray::from(vec![
+            (
+                Field::new("a", DataType::Int32, true),
+                Arc::new(Int32Array::from(vec![Some(1), None, Some(3)])) as ArrayRef,
+            ),
+            (
+                Field::new("b", DataType::Utf8, true),
+                Arc::new(StringArray::from(vec!["x", "y", "z"])) as ArrayRef,
+            ),
+        ]);
+        let mut list = array.clone_as_list().unwrap();
+        let row = TableScalar::struct_(HashMap::from([
+            ("a".to_string(), TableScalar::int32(4)),
+            ("b".to_string(), TableScalar::utf8("w".to_string())),
+        ]));
+        list.push(row.clone()).unwrap();
+        assert!(list.validate().is_ok());
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.to_array().unwrap().len(), 4);
+        assert_eq!(list.scalar(3).unwrap(), row);
+
+        // The second field does not match, so the first must not be pushed either.
+        let before = list.clone();
+        let bad = TableScalar::struct_(HashMap::from([
+            ("a".to_string(), TableScalar::int32(5)),
+            ("b".to_string(), TableScalar::int32(6)),
+        ]));
+        assert!(list.push(bad).is_err());
+        assert_eq!(list, before);
+    }
 }
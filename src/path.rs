@@ -0,0 +1,275 @@
+use crate::{table_scalar, ArrowScalarError, ScalarValuable, TableList, TableScalar};
+
+/// One step into a nested value after the column name of a path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    /// `.name`, a struct field.
+    Field(String),
+    /// `[3]`, an item of a list.
+    Index(usize),
+    /// `["key"]`, the value for a string key of a map, or a struct field of any name.
+    Key(String),
+}
+
+impl Segment {
+    fn to_path(&self) -> String {
+        match self {
+            Segment::Field(name) => format!(".{}", name),
+            Segment::Index(index) => format!("[{}]", index),
+            Segment::Key(key) => format!("[{:?}]", key),
+        }
+    }
+}
+
+fn invalid(path: &str, reason: impl std::fmt::Display) -> ArrowScalarError {
+    ArrowScalarError::InvalidPath(format!("{} in `{}`", reason, path))
+}
+
+/// Splits a path such as `a.b[3]["key"]` into its column name and the segments after it.
+fn parse(path: &str) -> Result<(&str, Vec<Segment>), ArrowScalarError> {
+    let end = path.find(['.', '[']).unwrap_or(path.len());
+    let (column, mut rest) = path.split_at(end);
+    if column.is_empty() {
+        return Err(invalid(path, "missing column name"));
+    }
+    let mut segments = vec![];
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix('.') {
+            let end = after.find(['.', '[']).unwrap_or(after.len());
+            if end == 0 {
+                return Err(invalid(path, "empty field name"));
+            }
+            segments.push(Segment::Field(after[..end].to_string()));
+            rest = &after[end..];
+        } else if let Some(after) = rest.strip_prefix("[\"") {
+            let mut key = String::new();
+            let mut chars = after.char_indices();
+            let close = loop {
+                match chars.next() {
+                    Some((i, '"')) => break i,
+                    Some((_, '\\')) => match chars.next() {
+                        Some((_, c)) => key.push(c),
+                        None => return Err(invalid(path, "unterminated key")),
+                    },
+                    Some((_, c)) => key.push(c),
+                    None => return Err(invalid(path, "unterminated key")),
+                }
+            };
+            rest = after[close + 1..]
+                .strip_prefix(']')
+                .ok_or_else(|| invalid(path, "expected `]` after key"))?;
+            segments.push(Segment::Key(key));
+        } else if let Some(after) = rest.strip_prefix('[') {
+            let close = after
+                .find(']')
+                .ok_or_else(|| invalid(path, "unterminated index"))?;
+            let index = after[..close]
+                .trim()
+                .parse::<usize>()
+                .map_err(|_| invalid(path, format!("`{}` is not an index", &after[..close])))?;
+            segments.push(Segment::Index(index));
+            rest = &after[close + 1..];
+        } else {
+            return Err(invalid(path, format!("unexpected `{}`", rest)));
+        }
+    }
+    Ok((column, segments))
+}
+
+fn item(list: &TableList, index: usize) -> Result<TableScalar, ArrowScalarError> {
+    if index >= list.len() {
+        return Err(ArrowScalarError::AccessError {
+            index,
+            len: list.len(),
+        });
+    }
+    list.scalar(index)
+}
+
+/// Applies one segment to `scalar`. Null values stay null.
+fn step(scalar: TableScalar, segment: &Segment) -> Result<TableScalar, ArrowScalarError> {
+    let value = match scalar.value {
+        None | Some(table_scalar::Value::Null(_)) => return Ok(TableScalar::default()),
+        Some(table_scalar::Value::Dictionary(inner) | table_scalar::Value::Union(inner)) => {
+            return step(*inner, segment)
+        }
+        Some(value) => value,
+    };
+    match (value, segment) {
+        (table_scalar::Value::Struct(mut value), Segment::Field(name) | Segment::Key(name)) => {
+            value
+                .elements
+                .remove(name)
+                .ok_or_else(|| ArrowScalarError::InvalidPath(format!("no field `{}`", name)))
+        }
+        (
+            table_scalar::Value::List(list)
+            | table_scalar::Value::LargeList(list)
+            | table_scalar::Value::FixedSizeList(list),
+            Segment::Index(index),
+        ) => item(&list, *index),
+        (table_scalar::Value::Map(map), Segment::Key(key)) => {
+            let keys = map
+                .keys
+                .as_ref()
+                .ok_or(ArrowScalarError::InvalidProtobuf("Map.keys"))?;
+            let values = map
+                .values
+                .as_ref()
+                .ok_or(ArrowScalarError::InvalidProtobuf("Map.values"))?;
+            let position = keys
+                .iter_str()
+                .and_then(|mut keys| keys.position(|candidate| candidate == Some(key.as_str())));
+            match position {
+                Some(position) => item(values, position),
+                None => Err(ArrowScalarError::InvalidPath(format!("no key {:?}", key))),
+            }
+        }
+        (value, segment) => {
+            let data_type = TableScalar { value: Some(value) }
+                .data_type()
+                .map(|data_type| format!("{:?}", data_type))
+                .unwrap_or_else(|_| "an unknown type".to_string());
+            Err(ArrowScalarError::InvalidPath(format!(
+                "cannot apply `{}` to {}",
+                segment.to_path(),
+                data_type
+            )))
+        }
+    }
+}
+
+/// Reads the value at `path` in row `index`, where `column` looks up the value of a top-level
+/// column by name. Paths are a column name followed by struct fields (`.name`), list items
+/// (`[3]`) and map keys or struct fields of any name (`["key"]`).
+pub(crate) fn path_value(
+    path: &str,
+    index: usize,
+    column: impl FnOnce(&str) -> Option<Result<TableScalar, ArrowScalarError>>,
+) -> Result<TableScalar, ArrowScalarError> {
+    let (name, segments) = parse(path)?;
+    if segments.is_empty() {
+        return Err(ArrowScalarError::UnknownColumn(path.to_string()));
+    }
+    let mut scalar =
+        column(name).ok_or_else(|| ArrowScalarError::UnknownColumn(name.to_string()))??;
+    let mut walked = String::new();
+    for segment in segments.iter() {
+        scalar = step(scalar, segment)
+            .map_err(|error| error.at_path(&walked).at_row(index).in_column(name))?;
+        walked.push_str(&segment.to_path());
+    }
+    Ok(scalar)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::array::{Array, ArrayRef, Int32Array, ListArray, StringArray, StructArray};
+    use arrow::datatypes::{DataType, Field, Int32Type};
+    use arrow::record_batch::RecordBatch;
+
+    use super::*;
+    use crate::{RowValuable, Table};
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse(r#"a.b[3]["k\"ey"].c"#).unwrap(),
+            (
+                "a",
+                vec![
+                    Segment::Field("b".to_string()),
+                    Segment::Index(3),
+                    Segment::Key("k\"ey".to_string()),
+                    Segment::Field("c".to_string()),
+                ]
+            )
+        );
+        assert_eq!(parse("a").unwrap(), ("a", vec![]));
+        for path in ["", ".a", "a.", "a[", "a[x]", "a[\"k", "a[\"k\""] {
+            assert!(
+                matches!(parse(path), Err(ArrowScalarError::InvalidPath(_))),
+                "{}",
+                path
+            );
+        }
+    }
+
+    #[test]
+    fn test_path_value() {
+        let tags = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            None,
+        ]);
+        let inner = StructArray::from(vec![(
+            Field::new("b", DataType::Int32, true),
+            Arc::new(Int32Array::from(vec![5, 6])) as ArrayRef,
+        )]);
+        let nested = StructArray::from(vec![
+            (
+                Field::new("inner", inner.data_type().clone(), true),
+                Arc::new(inner) as ArrayRef,
+            ),
+            (
+                Field::new("odd name", DataType::Utf8, true),
+                Arc::new(StringArray::from(vec!["x", "y"])) as ArrayRef,
+            ),
+        ]);
+        let batch = RecordBatch::try_from_iter(vec![
+            ("tags", Arc::new(tags) as ArrayRef),
+            ("a", Arc::new(nested) as ArrayRef),
+            (
+                "dotted.name",
+                Arc::new(Int32Array::from(vec![7, 8])) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+        let table = Table::from_arrow(&batch).unwrap();
+
+        for source in [&batch as &dyn RowValuable, &table] {
+            assert_eq!(
+                source.column_value("tags[1]", 0).unwrap(),
+                TableScalar::int32(2)
+            );
+            assert_eq!(
+                source.column_value("tags[1]", 1).unwrap(),
+                TableScalar::default()
+            );
+            assert_eq!(
+                source.column_value("a.inner.b", 1).unwrap(),
+                TableScalar::int32(6)
+            );
+            assert_eq!(
+                source.column_value(r#"a["odd name"]"#, 0).unwrap(),
+                TableScalar::utf8("x".to_string())
+            );
+            assert_eq!(
+                source.column_value("dotted.name", 1).unwrap(),
+                TableScalar::int32(8)
+            );
+
+            let error = source.column_value("a.inner.c", 0).unwrap_err();
+            assert!(matches!(error.root(), ArrowScalarError::InvalidPath(_)));
+            let context = error.context().unwrap();
+            assert_eq!(context.column.as_deref(), Some("a"));
+            assert_eq!(context.path, ".inner");
+            assert!(error.to_string().contains("no field `c`"));
+
+            let error = source.column_value("tags[2]", 0).unwrap_err();
+            assert!(matches!(
+                error.root(),
+                ArrowScalarError::AccessError { index: 2, len: 2 }
+            ));
+            assert!(matches!(
+                source.column_value("missing.b", 0),
+                Err(ArrowScalarError::UnknownColumn(name)) if name == "missing"
+            ));
+            assert!(matches!(
+                source.column_value("tags.b", 0).unwrap_err().root(),
+                ArrowScalarError::InvalidPath(_)
+            ));
+        }
+    }
+}
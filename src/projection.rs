@@ -0,0 +1,405 @@
+use std::sync::Arc;
+
+use arrow::array::ArrayRef;
+use arrow::datatypes::{Field, Schema};
+use arrow::record_batch::{RecordBatch, RecordBatchOptions};
+
+use crate::validate::check_column;
+use crate::{ArrowScalarError, FieldProto, Table, TableList};
+
+impl Table {
+    /// The index of the column called `name`.
+    pub(crate) fn column_index(&self, name: &str) -> Result<usize, ArrowScalarError> {
+        self.fields
+            .iter()
+            .position(|field| field.name == name)
+            .ok_or_else(|| ArrowScalarError::UnknownColumn(name.to_string()))
+    }
+
+    fn check_new_name(&self, name: &str) -> Result<(), ArrowScalarError> {
+        if self.fields.iter().any(|field| field.name == name) {
+            return Err(ArrowScalarError::DuplicateColumn(name.to_string()));
+        }
+        Ok(())
+    }
+
+    fn check_rows(&self, column: &TableList) -> Result<(), ArrowScalarError> {
+        if !self.values.is_empty() && column.len() != self.len() {
+            return Err(ArrowScalarError::LengthMismatch {
+                expected: self.len(),
+                actual: column.len(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns a table with only the columns called `names`, in that order. A name may not
+    /// be repeated.
+    pub fn select(&self, names: &[&str]) -> Result<Table, ArrowScalarError> {
+        let mut table = Table::default();
+        for name in names {
+            let index = self.column_index(name)?;
+            table.check_new_name(name)?;
+            let (Some(field), Some(column)) = (self.fields.get(index), self.values.get(index))
+            else {
+                return Err(ArrowScalarError::InvalidProtobuf("Table.values"));
+            };
+            table.fields.push(field.clone());
+            table.values.push(column.clone());
+        }
+        Ok(table)
+    }
+
+    /// Removes the columns called `names`. Nothing is removed unless every column exists.
+    pub fn drop_columns(&mut self, names: &[&str]) -> Result<(), ArrowScalarError> {
+        for name in names {
+            self.column_index(name)?;
+        }
+        let keep = self
+            .fields
+            .iter()
+            .map(|field| !names.contains(&field.name.as_str()))
+            .collect::<Vec<_>>();
+        let mut keep_field = keep.iter();
+        self.fields
+            .retain(|_| keep_field.next().copied().unwrap_or(true));
+        let mut keep_column = keep.iter();
+        self.values
+            .retain(|_| keep_column.next().copied().unwrap_or(true));
+        Ok(())
+    }
+
+    /// Renames the column called `from` to `to`, which must not name another column.
+    pub fn rename_column(&mut self, from: &str, to: &str) -> Result<(), ArrowScalarError> {
+        let index = self.column_index(from)?;
+        if from != to {
+            self.check_new_name(to)?;
+        }
+        if let Some(field) = self.fields.get_mut(index) {
+            field.name = to.to_string();
+        }
+        Ok(())
+    }
+
+    /// Appends a column, which must hold values of the type of `field` and have as many rows
+    /// as the table.
+    pub fn add_column(
+        &mut self,
+        field: FieldProto,
+        column: TableList,
+    ) -> Result<(), ArrowScalarError> {
+        self.check_new_name(&field.name)?;
+        check_column(&field, &column)?;
+        self.check_rows(&column)
+            .map_err(|error| error.in_column(&field.name))?;
+        self.fields.push(field);
+        self.values.push(column);
+        Ok(())
+    }
+
+    /// Replaces the values of the column called `name`, returning the old ones. The new
+    /// values must have the type of the column and as many rows as the table.
+    pub fn replace_column(
+        &mut self,
+        name: &str,
+        column: TableList,
+    ) -> Result<TableList, ArrowScalarError> {
+        let index = self.column_index(name)?;
+        if let Some(field) = self.fields.get(index) {
+            check_column(field, &column)?;
+        }
+        self.check_rows(&column)
+            .map_err(|error| error.in_column(name))?;
+        let old = self
+            .values
+            .get_mut(index)
+            .ok_or(ArrowScalarError::InvalidProtobuf("Table.values"))?;
+        Ok(std::mem::replace(old, column))
+    }
+}
+
+/// Schema changes for record batches, mirroring those of [`Table`]. Record batches are
+/// immutable, so each method returns a new batch sharing the column arrays.
+pub trait ColumnsValuable: Sized {
+    /// Returns a batch with only the columns called `names`, in that order. A name may not be
+    /// repeated.
+    fn select(&self, names: &[&str]) -> Result<Self, ArrowScalarError>;
+    /// Returns a batch without the columns called `names`, which must all exist.
+    fn drop_columns(&self, names: &[&str]) -> Result<Self, ArrowScalarError>;
+    /// Returns a batch with the column called `from` renamed to `to`.
+    fn rename_column(&self, from: &str, to: &str) -> Result<Self, ArrowScalarError>;
+    /// Returns a batch with `column` appended as `field`.
+    fn add_column(&self, field: Field, column: ArrayRef) -> Result<Self, ArrowScalarError>;
+    /// Returns a batch with the values of the column called `name` replaced by `column`.
+    fn replace_column(&self, name: &str, column: ArrayRef) -> Result<Self, ArrowScalarError>;
+}
+
+fn column_index(batch: &RecordBatch, name: &str) -> Result<usize, ArrowScalarError> {
+    batch
+        .schema()
+        .fields()
+        .iter()
+        .position(|field| field.name() == name)
+        .ok_or_else(|| ArrowScalarError::UnknownColumn(name.to_string()))
+}
+
+/// Builds a batch with the metadata and row count of `batch`, so a batch can lose all of
+/// its columns.
+fn rebuild(
+    batch: &RecordBatch,
+    fields: Vec<Field>,
+    columns: Vec<ArrayRef>,
+) -> Result<RecordBatch, ArrowScalarError> {
+    let schema = Schema::new_with_metadata(fields, batch.schema().metadata().clone());
+    let options = RecordBatchOptions::new().with_row_count(Some(batch.num_rows()));
+    Ok(RecordBatch::try_new_with_options(
+        Arc::new(schema),
+        columns,
+        &options,
+    )?)
+}
+
+impl ColumnsValuable for RecordBatch {
+    fn select(&self, names: &[&str]) -> Result<Self, ArrowScalarError> {
+        let mut indices = Vec::with_capacity(names.len());
+        for (i, name) in names.iter().enumerate() {
+            if names[..i].contains(name) {
+                return Err(ArrowScalarError::DuplicateColumn(name.to_string()));
+            }
+            indices.push(column_index(self, name)?);
+        }
+        Ok(self.project(&indices)?)
+    }
+
+    fn drop_columns(&self, names: &[&str]) -> Result<Self, ArrowScalarError> {
+        for name in names {
+            column_index(self, name)?;
+        }
+        let schema = self.schema();
+        let (fields, columns) = schema
+            .fields()
+            .iter()
+            .zip(self.columns())
+            .filter(|(field, _)| !names.contains(&field.name().as_str()))
+            .map(|(field, column)| (field.clone(), column.clone()))
+            .unzip();
+        rebuild(self, fields, columns)
+    }
+
+    fn rename_column(&self, from: &str, to: &str) -> Result<Self, ArrowScalarError> {
+        let index = column_index(self, from)?;
+        if from != to && column_index(self, to).is_ok() {
+            return Err(ArrowScalarError::DuplicateColumn(to.to_string()));
+        }
+        let fields = self
+            .schema()
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                if i == index {
+                    Field::new(to, field.data_type().clone(), field.is_nullable())
+                        .with_metadata(field.metadata().clone())
+                } else {
+                    field.clone()
+                }
+            })
+            .collect();
+        rebuild(self, fields, self.columns().to_vec())
+    }
+
+    fn add_column(&self, field: Field, column: ArrayRef) -> Result<Self, ArrowScalarError> {
+        if column_index(self, field.name()).is_ok() {
+            return Err(ArrowScalarError::DuplicateColumn(field.name().to_string()));
+        }
+        check_array(&field, &column, self.num_rows())?;
+        let mut fields = self.schema().fields().clone();
+        fields.push(field);
+        let mut columns = self.columns().to_vec();
+        columns.push(column);
+        rebuild(self, fields, columns)
+    }
+
+    fn replace_column(&self, name: &str, column: ArrayRef) -> Result<Self, ArrowScalarError> {
+        let index = column_index(self, name)?;
+        let schema = self.schema();
+        if let Some(field) = schema.fields().get(index) {
+            check_array(field, &column, self.num_rows())?;
+        }
+        let mut columns = self.columns().to_vec();
+        if let Some(old) = columns.get_mut(index) {
+            *old = column;
+        }
+        rebuild(self, schema.fields().clone(), columns)
+    }
+}
+
+/// Checks that `column` has the type of `field` and `rows` rows, with errors like those of
+/// [`Table::add_column`].
+fn check_array(field: &Field, column: &ArrayRef, rows: usize) -> Result<(), ArrowScalarError> {
+    if column.data_type() != field.data_type() {
+        return Err(
+            ArrowScalarError::type_mismatch(field.data_type(), column.data_type())
+                .in_column(field.name()),
+        );
+    }
+    if column.len() != rows {
+        return Err(ArrowScalarError::LengthMismatch {
+            expected: rows,
+            actual: column.len(),
+        }
+        .in_column(field.name()));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{Int32Array, Int64Array, StringArray};
+    use arrow::datatypes::DataType;
+
+    use super::*;
+    use crate::ListValuable;
+
+    fn batch() -> RecordBatch {
+        RecordBatch::try_from_iter(vec![
+            ("a", Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef),
+            ("b", Arc::new(StringArray::from(vec!["x", "y"])) as ArrayRef),
+            ("c", Arc::new(Int64Array::from(vec![3, 4])) as ArrayRef),
+        ])
+        .unwrap()
+    }
+
+    fn names(table: &Table) -> Vec<&str> {
+        table
+            .fields
+            .iter()
+            .map(|field| field.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_table_columns() {
+        let mut table = Table::from_arrow(&batch()).unwrap();
+        let selected = table.select(&["c", "a"]).unwrap();
+        assert_eq!(names(&selected), vec!["c", "a"]);
+        assert_eq!(
+            selected.to_arrow().unwrap(),
+            batch().select(&["c", "a"]).unwrap()
+        );
+        assert!(matches!(
+            table.select(&["a", "z"]),
+            Err(ArrowScalarError::UnknownColumn(name)) if name == "z"
+        ));
+        assert!(matches!(
+            table.select(&["a", "c", "a"]),
+            Err(ArrowScalarError::DuplicateColumn(name)) if name == "a"
+        ));
+
+        assert!(table.drop_columns(&["a", "z"]).is_err());
+        assert_eq!(names(&table), vec!["a", "b", "c"]);
+        table.drop_columns(&["b"]).unwrap();
+        assert_eq!(names(&table), vec!["a", "c"]);
+        assert_eq!(table.values[1], batch().column(2).clone_as_list().unwrap());
+
+        table.rename_column("a", "id").unwrap();
+        assert!(matches!(
+            table.rename_column("id", "c"),
+            Err(ArrowScalarError::DuplicateColumn(name)) if name == "c"
+        ));
+        assert_eq!(names(&table), vec!["id", "c"]);
+
+        let field = FieldProto::from_arrow(&Field::new("d", DataType::Int32, true));
+        let column = Int32Array::from(vec![5, 6]).clone_as_list().unwrap();
+        let short = Int32Array::from(vec![5]).clone_as_list().unwrap();
+        let error = table.add_column(field.clone(), short.clone()).unwrap_err();
+        assert!(matches!(
+            error.root(),
+            ArrowScalarError::LengthMismatch {
+                expected: 2,
+                actual: 1
+            }
+        ));
+        let error = table
+            .add_column(
+                field.clone(),
+                StringArray::from(vec!["p", "q"]).clone_as_list().unwrap(),
+            )
+            .unwrap_err();
+        assert!(matches!(error.root(), ArrowScalarError::InvalidList(_)));
+        let error = table
+            .add_column(field.clone(), TableList::default())
+            .unwrap_err();
+        assert!(matches!(error.root(), ArrowScalarError::InvalidList(_)));
+        assert!(table.replace_column("id", TableList::default()).is_err());
+        table.add_column(field.clone(), column.clone()).unwrap();
+        assert!(matches!(
+            table.add_column(field, column.clone()),
+            Err(ArrowScalarError::DuplicateColumn(_))
+        ));
+        assert_eq!(names(&table), vec!["id", "c", "d"]);
+
+        let old = table
+            .replace_column("id", Int32Array::from(vec![7, 8]).clone_as_list().unwrap())
+            .unwrap();
+        assert_eq!(old, batch().column(0).clone_as_list().unwrap());
+        assert!(table.replace_column("id", short).is_err());
+        assert!(table.replace_column("c", column).is_err());
+        table.validate().unwrap();
+
+        let mut empty = Table::default();
+        let field = FieldProto::from_arrow(&Field::new("a", DataType::Int32, false));
+        let column = Int32Array::from(vec![1, 2, 3]).clone_as_list().unwrap();
+        empty.add_column(field, column).unwrap();
+        assert_eq!(empty.len(), 3);
+    }
+
+    #[test]
+    fn test_batch_columns() {
+        let batch = batch();
+        let selected = batch.select(&["c", "a"]).unwrap();
+        assert_eq!(selected.schema().field(0).name(), "c");
+        assert_eq!(selected.num_columns(), 2);
+        assert!(batch.select(&["z"]).is_err());
+        assert!(matches!(
+            batch.select(&["a", "a"]),
+            Err(ArrowScalarError::DuplicateColumn(name)) if name == "a"
+        ));
+
+        let dropped = batch.drop_columns(&["a", "b", "c"]).unwrap();
+        assert_eq!(dropped.num_columns(), 0);
+        assert_eq!(dropped.num_rows(), 2);
+        assert!(batch.drop_columns(&["z"]).is_err());
+
+        let renamed = batch.rename_column("b", "name").unwrap();
+        assert_eq!(renamed.schema().field(1).name(), "name");
+        assert!(matches!(
+            batch.rename_column("b", "a"),
+            Err(ArrowScalarError::DuplicateColumn(_))
+        ));
+
+        let column = Arc::new(Int32Array::from(vec![5, 6])) as ArrayRef;
+        let added = batch
+            .add_column(Field::new("d", DataType::Int32, false), column.clone())
+            .unwrap();
+        assert_eq!(added.num_columns(), 4);
+        assert!(matches!(
+            batch
+                .add_column(Field::new("d", DataType::Int64, false), column.clone())
+                .unwrap_err()
+                .root(),
+            ArrowScalarError::TypeMismatch { .. }
+        ));
+        assert!(batch
+            .add_column(
+                Field::new("d", DataType::Int32, false),
+                Arc::new(Int32Array::from(vec![1])),
+            )
+            .is_err());
+
+        let replaced = batch.replace_column("a", column.clone()).unwrap();
+        assert_eq!(replaced.column(0).as_ref(), column.as_ref());
+        assert!(batch.replace_column("b", column).is_err());
+        assert_eq!(Table::from_arrow(&replaced).unwrap().values[0].len(), 2);
+    }
+}
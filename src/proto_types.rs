@@ -26,7 +26,7 @@ impl FieldProto {
         let data_type = if let Some(data_type) = self.data_type.as_ref() {
             data_type.to_arrow()?
         } else {
-            return Err(ArrowScalarError::InvalidProtobuf);
+            return Err(ArrowScalarError::InvalidProtobuf("FieldProto.data_type"));
         };
         Ok(Field::new(&self.name, data_type, self.nullable))
     }
@@ -136,7 +136,11 @@ impl DataTypeProto {
                     let data_type_proto::FixedSizeList { list_type, size } = fsl.as_ref();
                     match list_type {
                         Some(field) => DataType::FixedSizeList(Box::new(field.to_arrow()?), *size),
-                        None => return Err(ArrowScalarError::InvalidProtobuf),
+                        None => {
+                            return Err(ArrowScalarError::InvalidProtobuf(
+                                "FixedSizeList.list_type",
+                            ))
+                        }
                     }
                 }
                 data_type_proto::DataType::Struct(data_type_proto::Struct { fields }) => {
@@ -159,7 +163,7 @@ impl DataTypeProto {
                     let mode = match data_type_proto::union::Mode::from_i32(*mode) {
                         Some(data_type_proto::union::Mode::Dense) => UnionMode::Dense,
                         Some(data_type_proto::union::Mode::Sparse) => UnionMode::Sparse,
-                        _ => return Err(ArrowScalarError::InvalidProtobuf),
+                        _ => return Err(ArrowScalarError::InvalidProtobuf("Union.mode")),
                     };
                     DataType::Union(fields, type_ids, mode)
                 }
@@ -173,22 +177,30 @@ impl DataTypeProto {
                             Box::new(key_type.to_arrow()?),
                             Box::new(value_type.to_arrow()?),
                         ),
-                        _ => return Err(ArrowScalarError::InvalidProtobuf),
+                        _ => return Err(ArrowScalarError::InvalidProtobuf("Dictionary.key_type")),
                     }
                 }
                 data_type_proto::DataType::Decimal128(data_type_proto::Decimal {
                     precision,
                     scale,
                 }) => DataType::Decimal128(
-                    (*precision).try_into().unwrap(),
-                    (*scale).try_into().unwrap(),
+                    (*precision)
+                        .try_into()
+                        .map_err(|_| ArrowScalarError::InvalidProtobuf("Decimal.precision"))?,
+                    (*scale)
+                        .try_into()
+                        .map_err(|_| ArrowScalarError::InvalidProtobuf("Decimal.scale"))?,
                 ),
                 data_type_proto::DataType::Decimal256(data_type_proto::Decimal {
                     precision,
                     scale,
                 }) => DataType::Decimal256(
-                    (*precision).try_into().unwrap(),
-                    (*scale).try_into().unwrap(),
+                    (*precision)
+                        .try_into()
+                        .map_err(|_| ArrowScalarError::InvalidProtobuf("Decimal.precision"))?,
+                    (*scale)
+                        .try_into()
+                        .map_err(|_| ArrowScalarError::InvalidProtobuf("Decimal.scale"))?,
                 ),
                 data_type_proto::DataType::Map(map) => {
                     let data_type_proto::Map {
@@ -198,14 +210,14 @@ impl DataTypeProto {
                     if let Some(field) = struct_field {
                         DataType::Map(Box::new(field.to_arrow()?), *keys_sorted)
                     } else {
-                        return Err(ArrowScalarError::InvalidProtobuf);
+                        return Err(ArrowScalarError::InvalidProtobuf("Map.struct_field"));
                     }
                 }
                 data_type_proto::DataType::Null(_) => DataType::Null,
             };
             Ok(dt)
         } else {
-            Err(ArrowScalarError::InvalidProtobuf)
+            Err(ArrowScalarError::InvalidProtobuf("DataTypeProto.data_type"))
         }
     }
     pub fn from_arrow(value: &DataType) -> Self {
@@ -1,17 +1,54 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{borrow::Cow, collections::HashMap, ops::Range, sync::Arc};
 
+use crate::path::path_value;
 use crate::{
-    ArrowScalarError, FieldProto, ListValuable, ScalarValuable, Table, TableList, TableRow,
-    TableScalar,
+    ArrowScalarError, FieldProto, ListValuable, RowRef, Rows, ScalarValuable, Table, TableList,
+    TableRow, TableScalar,
 };
 use arrow::{
     datatypes::{Field, Schema},
     record_batch::RecordBatch,
 };
 
-pub trait RowValuable {
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for arrow::record_batch::RecordBatch {}
+    impl Sealed for crate::Table {}
+}
+
+/// Row access for [`RecordBatch`] and [`Table`]. The trait is sealed, so that methods can be
+/// added to it without breaking other crates.
+pub trait RowValuable: private::Sealed {
     fn row(&self, index: usize) -> Result<TableRow, ArrowScalarError>;
+    /// The value of `column` in row `index`. A name that is not a column is read as a path into
+    /// nested values, such as `a.b`, `tags[3]` or `attrs["key"]`.
     fn column_value(&self, column: &str, index: usize) -> Result<TableScalar, ArrowScalarError>;
+    /// Borrows row `index` without copying its values, see [`RowRef`].
+    fn row_ref(&self, index: usize) -> Result<RowRef<'_>, ArrowScalarError>;
+    /// Iterates over every row, as `row` would return them.
+    fn rows(&self) -> Rows<'_>;
+
+    /// Iterates over the rows in `range`, which must be within the rows.
+    fn rows_range(&self, range: Range<usize>) -> Result<Rows<'_>, ArrowScalarError> {
+        self.rows().with_range(range)
+    }
+
+    /// Builds every row in parallel, in order.
+    #[cfg(feature = "rayon")]
+    fn par_rows(
+        &self,
+    ) -> impl rayon::iter::IndexedParallelIterator<Item = Result<TableRow, ArrowScalarError>> + '_
+    where
+        Self: Sized,
+    {
+        use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+        let rows = self.rows();
+        rows.range()
+            .into_par_iter()
+            .map(move |index| rows.row(index))
+    }
 }
 
 impl RowValuable for RecordBatch {
@@ -20,19 +57,45 @@ impl RowValuable for RecordBatch {
 
         let schema = self.schema();
         for i in 0..self.num_columns() {
-            let column = self.column(i).scalar(index)?;
             let name = schema.field(i).name();
+            let column = self
+                .column(i)
+                .scalar(index)
+                .map_err(|error| error.at_row(index).in_column(name))?;
             row.values.insert(name.to_string(), column.clone());
         }
         Ok(row)
     }
     fn column_value(&self, column: &str, index: usize) -> Result<TableScalar, ArrowScalarError> {
-        if let Some((column_index, _field)) = self.schema().column_with_name(column) {
-            self.column(column_index).scalar(index)
-        } else {
-            Err(ArrowScalarError::AccessError)
+        let value = |column: &str| {
+            let (column_index, _field) = self.schema().column_with_name(column)?;
+            Some(
+                self.column(column_index)
+                    .scalar(index)
+                    .map_err(|error| error.at_row(index).in_column(column)),
+            )
+        };
+        match value(column) {
+            Some(value) => value,
+            None => path_value(column, index, value),
         }
     }
+    fn row_ref(&self, index: usize) -> Result<RowRef<'_>, ArrowScalarError> {
+        RowRef::batch(self, index)
+    }
+    fn rows(&self) -> Rows<'_> {
+        let columns = self
+            .schema()
+            .fields()
+            .iter()
+            .zip(self.columns())
+            .map(|(field, column)| {
+                let column: &(dyn ScalarValuable + Sync) = column;
+                (Cow::Owned(field.name().clone()), column)
+            })
+            .collect();
+        Rows::new(columns, self.num_rows())
+    }
 }
 
 impl RowValuable for Table {
@@ -41,23 +104,54 @@ impl RowValuable for Table {
             .fields
             .iter()
             .zip(self.values.iter())
-            .map(|(name, column)| Ok((name.name.to_owned(), column.scalar(index)?)))
+            .map(|(field, column)| {
+                let scalar = column
+                    .scalar(index)
+                    .map_err(|error| error.at_row(index).in_column(&field.name))?;
+                Ok((field.name.to_owned(), scalar))
+            })
             .collect::<Result<HashMap<String, TableScalar>, ArrowScalarError>>()?;
         Ok(TableRow { values })
     }
 
     fn column_value(&self, column: &str, index: usize) -> Result<TableScalar, ArrowScalarError> {
-        self.fields
+        let value = |column: &str| {
+            self.fields
+                .iter()
+                .zip(self.values.iter())
+                .find_map(|(field, value)| {
+                    if field.name == column {
+                        Some(
+                            value
+                                .scalar(index)
+                                .map_err(|error| error.at_row(index).in_column(column)),
+                        )
+                    } else {
+                        None
+                    }
+                })
+        };
+        match value(column) {
+            Some(value) => value,
+            None => path_value(column, index, value),
+        }
+    }
+
+    fn row_ref(&self, index: usize) -> Result<RowRef<'_>, ArrowScalarError> {
+        RowRef::table(self, index)
+    }
+
+    fn rows(&self) -> Rows<'_> {
+        let columns = self
+            .fields
             .iter()
             .zip(self.values.iter())
-            .find_map(|(field, value)| {
-                if field.name == column {
-                    Some(value.scalar(index))
-                } else {
-                    None
-                }
+            .map(|(field, column)| {
+                let column: &(dyn ScalarValuable + Sync) = column;
+                (Cow::Borrowed(field.name.as_str()), column)
             })
-            .unwrap_or(Err(ArrowScalarError::AccessError))
+            .collect();
+        Rows::new(columns, self.len())
     }
 }
 
@@ -102,11 +196,18 @@ impl Table {
         self.len() == 0
     }
 
+    /// Converts this table to a record batch, after checking it with [`Table::validate`].
     pub fn to_arrow(&self) -> Result<RecordBatch, ArrowScalarError> {
+        self.validate()?;
         let columns = self
-            .values
+            .fields
             .iter()
-            .map(|column| column.to_array())
+            .zip(self.values.iter())
+            .map(|(field, column)| {
+                column
+                    .build_array()
+                    .map_err(|error| error.in_column(&field.name))
+            })
             .collect::<Result<Vec<_>, ArrowScalarError>>()?;
         let schema = Arc::new(self.schema()?);
         RecordBatch::try_new(schema, columns).map_err(ArrowScalarError::ArrowError)
@@ -118,7 +219,12 @@ impl Table {
         let values = records
             .columns()
             .iter()
-            .map(|column| column.clone_as_list())
+            .zip(schema.fields())
+            .map(|(column, field)| {
+                column
+                    .clone_as_list()
+                    .map_err(|error| error.in_column(field.name()))
+            })
             .collect::<Result<Vec<_>, ArrowScalarError>>()?;
         Ok(Self { values, fields })
     }
@@ -129,10 +235,9 @@ impl Table {
 
     fn roll_back(&mut self, index: usize, mut row: TableRow) -> TableRow {
         for i in 0..index {
-            row.values.insert(
-                self.fields[i].name.to_owned(),
-                self.values[i].pop().unwrap(),
-            );
+            if let Some(value) = self.values[i].pop() {
+                row.values.insert(self.fields[i].name.to_owned(), value);
+            }
         }
         row
     }
@@ -141,7 +246,7 @@ impl Table {
         for (i, (field, values)) in self.fields.iter().zip(self.values.iter_mut()).enumerate() {
             if let Some(value) = row.values.remove(&field.name) {
                 if let Err(ArrowScalarError::InvalidScalar(scalar)) = values.push(value) {
-                    row.values.insert(field.name.to_owned(), scalar);
+                    row.values.insert(field.name.to_owned(), *scalar);
                     return Err(self.roll_back(i, row));
                 }
             } else {
@@ -160,17 +265,100 @@ impl Table {
             values.clear();
         }
     }
+
+    /// Converts every column to the packed validity form, see [`TableList::pack_validity`].
+    pub fn pack_validity(&mut self) {
+        for column in self.values.iter_mut() {
+            column.pack_validity();
+        }
+    }
+
+    /// Builds a table with the same fields by applying `f` to every column.
+    fn map_columns(
+        &self,
+        f: impl Fn(&TableList) -> Result<TableList, ArrowScalarError>,
+    ) -> Result<Table, ArrowScalarError> {
+        let values = self
+            .fields
+            .iter()
+            .zip(self.values.iter())
+            .map(|(field, column)| f(column).map_err(|error| error.in_column(&field.name)))
+            .collect::<Result<Vec<_>, ArrowScalarError>>()?;
+        Ok(Table {
+            fields: self.fields.clone(),
+            values,
+        })
+    }
+
+    /// Returns `len` rows starting at `offset`, see [`TableList::slice`].
+    pub fn slice(&self, offset: usize, len: usize) -> Result<Table, ArrowScalarError> {
+        self.map_columns(|column| column.slice(offset, len))
+    }
+
+    /// Returns the rows at `indices`, in that order, see [`TableList::take`].
+    pub fn take(&self, indices: &[usize]) -> Result<Table, ArrowScalarError> {
+        self.map_columns(|column| column.take(indices))
+    }
+
+    /// Returns the rows where `mask` is true, see [`TableList::filter`]. The mask must be as
+    /// long as the table.
+    pub fn filter_mask(&self, mask: &[bool]) -> Result<Table, ArrowScalarError> {
+        if mask.len() != self.len() {
+            return Err(ArrowScalarError::LengthMismatch {
+                expected: self.len(),
+                actual: mask.len(),
+            });
+        }
+        self.map_columns(|column| column.filter(mask))
+    }
+
+    /// Returns the rows for which `predicate` returns true, given a view of each row.
+    pub fn filter_rows(
+        &self,
+        mut predicate: impl FnMut(RowRef<'_>) -> bool,
+    ) -> Result<Table, ArrowScalarError> {
+        let mask = (0..self.len())
+            .map(|index| Ok(predicate(self.row_ref(index)?)))
+            .collect::<Result<Vec<_>, ArrowScalarError>>()?;
+        self.filter_mask(&mask)
+    }
+
+    /// Appends the rows of `other`, which must have the same schema. Nothing is appended
+    /// unless every column matches.
+    pub fn extend(&mut self, other: &Table) -> Result<(), ArrowScalarError> {
+        if self.fields != other.fields {
+            return Err(ArrowScalarError::type_mismatch(
+                self.schema()?,
+                other.schema()?,
+            ));
+        }
+        for ((field, values), other_values) in self
+            .fields
+            .iter()
+            .zip(self.values.iter())
+            .zip(other.values.iter())
+        {
+            values
+                .check_extend(other_values)
+                .map_err(|error| error.in_column(&field.name))?;
+        }
+        for (values, other_values) in self.values.iter_mut().zip(other.values.iter()) {
+            values.extend_from(other_values)?;
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::table_list;
     use arrow::{
         array::{
-            ArrayRef, BooleanArray, Float32Array, Int32Array, Int64Array, StructArray,
-            Time32SecondArray,
+            ArrayRef, BooleanArray, DictionaryArray, Float32Array, Int32Array, Int64Array,
+            ListArray, StructArray, Time32SecondArray,
         },
-        datatypes::{DataType, Field, Schema},
+        datatypes::{DataType, Field, Int32Type, Int8Type, Schema, UnionMode},
     };
 
     #[test]
@@ -247,4 +435,161 @@ mod tests {
         let new_batch = table.to_arrow().unwrap();
         assert_eq!(batch, new_batch);
     }
+
+    #[test]
+    fn test_nested_column_value() {
+        let batch = test_record_batch();
+        let table = Table::from_arrow(&batch).unwrap();
+        assert_eq!(
+            batch.column_value("model_results.pred", 1).unwrap(),
+            TableScalar::float32(0.2)
+        );
+        assert_eq!(
+            table.column_value("model_results.y", 3).unwrap(),
+            TableScalar::boolean(true)
+        );
+    }
+
+    #[test]
+    fn test_extend() {
+        let batch = test_record_batch();
+        let mut table = Table::from_arrow(&batch).unwrap();
+        let other = Table::from_arrow(&batch.slice(1, 2)).unwrap();
+        table.extend(&other).unwrap();
+        assert_eq!(table.len(), 7);
+        assert_eq!(table.row(5).unwrap(), other.row(0).unwrap());
+        assert_eq!(table.row(6).unwrap(), other.row(1).unwrap());
+
+        let schema = Schema::new(vec![Field::new("a", DataType::Int32, true)]);
+        assert!(table.extend(&Table::new(&schema).unwrap()).is_err());
+        assert_eq!(table.len(), 7);
+    }
+
+    #[test]
+    fn test_filter_rows() {
+        let batch = test_record_batch();
+        let table = Table::from_arrow(&batch).unwrap();
+        let filtered = table
+            .filter_rows(|row| row.get_i64("id").ok().flatten().unwrap_or(0) > 1)
+            .unwrap();
+        let mask = BooleanArray::from(vec![false, true, false, true, true]);
+        let expected = arrow::compute::filter_record_batch(&batch, &mask).unwrap();
+        assert_eq!(filtered.to_arrow().unwrap(), expected);
+        assert_eq!(
+            table
+                .filter_mask(&[false, true, false, true, true])
+                .unwrap(),
+            filtered
+        );
+        assert!(matches!(
+            table.filter_mask(&[true]),
+            Err(ArrowScalarError::LengthMismatch {
+                expected: 5,
+                actual: 1
+            })
+        ));
+        assert_eq!(table.filter_rows(|_| false).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn test_filter_rows_nested() {
+        let tags = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), None]),
+            None,
+            Some(vec![]),
+            Some(vec![Some(4)]),
+        ]);
+        let color = vec![Some("red"), Some("blue"), None, Some("red")]
+            .into_iter()
+            .collect::<DictionaryArray<Int8Type>>();
+        let batch = RecordBatch::try_from_iter(vec![
+            ("tags", Arc::new(tags) as ArrayRef),
+            ("color", Arc::new(color) as ArrayRef),
+        ])
+        .unwrap();
+        let mut table = Table::from_arrow(&batch).unwrap();
+        // Unions cannot be converted to arrow, so they only exist in tables.
+        let union_type = DataType::Union(
+            vec![
+                Field::new("i", DataType::Int32, true),
+                Field::new("s", DataType::Utf8, true),
+            ],
+            vec![0, 1],
+            UnionMode::Dense,
+        );
+        let values = vec![
+            TableScalar::int32(1),
+            TableScalar::utf8("x".to_string()),
+            TableScalar::int32(3),
+            TableScalar::utf8("y".to_string()),
+        ];
+        table
+            .fields
+            .push(FieldProto::from_arrow(&Field::new("u", union_type, true)));
+        table.values.push(TableList {
+            values: Some(table_list::Values::Union(table_list::UnionList {
+                values: values.clone(),
+                ..Default::default()
+            })),
+        });
+
+        let red = TableScalar::utf8("red".to_string());
+        let filtered = table
+            .filter_rows(|row| row.get("color").ok() == Some(red.clone()))
+            .unwrap();
+        assert_eq!(filtered.len(), 2);
+        for (row, index) in [(0, 0), (1, 3)] {
+            for name in ["tags", "color", "u"] {
+                assert_eq!(
+                    filtered.column_value(name, row).unwrap(),
+                    table.column_value(name, index).unwrap(),
+                    "{}",
+                    name
+                );
+            }
+        }
+        let mask = BooleanArray::from(vec![true, false, false, true]);
+        let expected = arrow::compute::filter_record_batch(&batch, &mask).unwrap();
+        assert_eq!(
+            filtered
+                .select(&["tags", "color"])
+                .unwrap()
+                .to_arrow()
+                .unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_error_context() {
+        let batch = RecordBatch::try_from_iter(vec![(
+            "a",
+            Arc::new(StructArray::from(vec![(
+                Field::new("b", DataType::Int32, true),
+                Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef,
+            )])) as ArrayRef,
+        )])
+        .unwrap();
+        let mut table = Table::from_arrow(&batch).unwrap();
+        if let Some(table_list::Values::Struct(list)) = table.values[0].values.as_mut() {
+            if let Some(table_list::Values::Int32(list)) = list.values[0].values.as_mut() {
+                list.validity = None;
+                list.set = vec![true];
+            }
+        }
+        let error = table.to_arrow().unwrap_err();
+        let context = error.context().unwrap();
+        assert_eq!(context.column.as_deref(), Some("a"));
+        assert_eq!(context.path, ".b");
+        assert!(matches!(error.root(), ArrowScalarError::InvalidList(_)));
+
+        let error = table.column_value("missing", 0).unwrap_err();
+        assert!(matches!(error, ArrowScalarError::UnknownColumn(_)));
+        let error = table.row(5).unwrap_err();
+        assert_eq!(error.context().unwrap().row, Some(5));
+        assert!(matches!(
+            error.root(),
+            ArrowScalarError::AccessError { index: 5, len: 2 }
+        ));
+    }
 }
@@ -0,0 +1,441 @@
+use std::ops::Deref;
+
+use arrow::array::{
+    Array, ArrayRef, BinaryArray, BooleanArray, DictionaryArray, FixedSizeBinaryArray,
+    Float64Array, LargeBinaryArray, LargeStringArray, PrimitiveArray, StringArray,
+};
+use arrow::datatypes::*;
+use arrow::record_batch::RecordBatch;
+
+use crate::list::{dictionary_key, downcast};
+use crate::{
+    ArrowScalarError, RowValuable, ScalarValuable, Table, TableList, TableRow, TableScalar,
+};
+
+/// A row of a record batch or table, borrowed rather than copied into a [`TableRow`]. The
+/// typed getters return `None` for a null value and borrow strings and bytes from the
+/// underlying storage.
+#[derive(Debug, Clone, Copy)]
+pub struct RowRef<'a> {
+    source: Source<'a>,
+    index: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Source<'a> {
+    Batch(&'a RecordBatch),
+    Table(&'a Table),
+}
+
+/// A column of the row, with the type name reported when a typed getter does not apply.
+enum Column<'a> {
+    Array(&'a ArrayRef),
+    List(&'a TableList),
+}
+
+impl<'a> Column<'a> {
+    fn type_name(&self) -> String {
+        match self {
+            Column::Array(array) => format!("{:?}", array.data_type()),
+            Column::List(list) => list.type_name(),
+        }
+    }
+}
+
+fn dictionary_value<K: ArrowDictionaryKeyType>(
+    array: &dyn Array,
+    index: usize,
+) -> Result<(&ArrayRef, usize), ArrowScalarError> {
+    let array = downcast::<DictionaryArray<K>>(array)?;
+    Ok((array.values(), dictionary_key(array.keys().value(index))?))
+}
+
+/// Looks through dictionaries to the array and index holding the value of row `index`, or
+/// `None` if the value is null.
+fn resolve(
+    array: &dyn Array,
+    index: usize,
+) -> Result<Option<(&dyn Array, usize)>, ArrowScalarError> {
+    if array.is_null(index) {
+        return Ok(None);
+    }
+    let DataType::Dictionary(key_type, _) = array.data_type() else {
+        return Ok(Some((array, index)));
+    };
+    let (values, index) = match key_type.deref() {
+        DataType::Int8 => dictionary_value::<Int8Type>(array, index)?,
+        DataType::Int16 => dictionary_value::<Int16Type>(array, index)?,
+        DataType::Int32 => dictionary_value::<Int32Type>(array, index)?,
+        DataType::Int64 => dictionary_value::<Int64Type>(array, index)?,
+        DataType::UInt8 => dictionary_value::<UInt8Type>(array, index)?,
+        DataType::UInt16 => dictionary_value::<UInt16Type>(array, index)?,
+        DataType::UInt32 => dictionary_value::<UInt32Type>(array, index)?,
+        DataType::UInt64 => dictionary_value::<UInt64Type>(array, index)?,
+        _ => return Err(crate::list::downcast_error(array)),
+    };
+    resolve(values.as_ref(), index)
+}
+
+fn primitive_value<T: ArrowPrimitiveType>(
+    array: &dyn Array,
+    index: usize,
+) -> Result<T::Native, ArrowScalarError> {
+    Ok(downcast::<PrimitiveArray<T>>(array)?.value(index))
+}
+
+impl<'a> RowRef<'a> {
+    pub(crate) fn batch(batch: &'a RecordBatch, index: usize) -> Result<Self, ArrowScalarError> {
+        Self::new(Source::Batch(batch), index, batch.num_rows())
+    }
+
+    pub(crate) fn table(table: &'a Table, index: usize) -> Result<Self, ArrowScalarError> {
+        Self::new(Source::Table(table), index, table.len())
+    }
+
+    fn new(source: Source<'a>, index: usize, len: usize) -> Result<Self, ArrowScalarError> {
+        if index >= len {
+            return Err(ArrowScalarError::AccessError { index, len });
+        }
+        Ok(RowRef { source, index })
+    }
+
+    /// The index of this row in its batch or table.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The number of columns in the row.
+    pub fn num_columns(&self) -> usize {
+        match self.source {
+            Source::Batch(batch) => batch.num_columns(),
+            Source::Table(table) => table.values.len(),
+        }
+    }
+
+    fn column_index(&self, name: &str) -> Result<usize, ArrowScalarError> {
+        let index = match self.source {
+            Source::Batch(batch) => batch
+                .schema()
+                .fields()
+                .iter()
+                .position(|field| field.name() == name),
+            Source::Table(table) => table.fields.iter().position(|field| field.name == name),
+        };
+        index.ok_or_else(|| ArrowScalarError::UnknownColumn(name.to_string()))
+    }
+
+    fn column(&self, name: &str) -> Result<Column<'a>, ArrowScalarError> {
+        let index = self.column_index(name)?;
+        let column = match self.source {
+            Source::Batch(batch) => batch.columns().get(index).map(Column::Array),
+            Source::Table(table) => table.values.get(index).map(Column::List),
+        };
+        column.ok_or(ArrowScalarError::InvalidProtobuf("Table.values"))
+    }
+
+    /// The value in the column called `name`.
+    pub fn get(&self, name: &str) -> Result<TableScalar, ArrowScalarError> {
+        self.get_by_index(self.column_index(name)?)
+            .map_err(|error| error.in_column(name))
+    }
+
+    /// The value in the column at `column`.
+    pub fn get_by_index(&self, column: usize) -> Result<TableScalar, ArrowScalarError> {
+        let access_error = || ArrowScalarError::AccessError {
+            index: column,
+            len: self.num_columns(),
+        };
+        let scalar = match self.source {
+            Source::Batch(batch) => batch
+                .columns()
+                .get(column)
+                .ok_or_else(access_error)?
+                .scalar(self.index),
+            Source::Table(table) => table
+                .values
+                .get(column)
+                .ok_or_else(access_error)?
+                .scalar(self.index),
+        };
+        scalar.map_err(|error| error.at_row(self.index))
+    }
+
+    /// Reads the value in the column called `name` with `from_array` or `from_list`, which
+    /// return `None` for a column of another type.
+    fn get_typed<T>(
+        &self,
+        name: &str,
+        expected: &str,
+        from_array: impl FnOnce(&'a dyn Array, usize) -> Result<Option<T>, ArrowScalarError>,
+        from_list: impl FnOnce(&'a TableList) -> Option<Option<T>>,
+    ) -> Result<Option<T>, ArrowScalarError> {
+        let column = self.column(name)?;
+        let value = match column {
+            Column::Array(array) => match resolve(array.as_ref(), self.index)? {
+                Some((array, index)) => from_array(array, index)?.map(Some),
+                None => Some(None),
+            },
+            Column::List(list) => from_list(list),
+        };
+        value
+            .ok_or_else(|| ArrowScalarError::TypeMismatch {
+                expected: expected.to_string(),
+                actual: column.type_name(),
+            })
+            .map_err(|error| error.at_row(self.index).in_column(name))
+    }
+
+    /// The value in the `Utf8` or `LargeUtf8` column called `name`.
+    pub fn get_str(&self, name: &str) -> Result<Option<&'a str>, ArrowScalarError> {
+        let index = self.index;
+        self.get_typed(
+            name,
+            "Utf8",
+            |array, i| match array.data_type() {
+                DataType::Utf8 => Ok(Some(downcast::<StringArray>(array)?.value(i))),
+                DataType::LargeUtf8 => Ok(Some(downcast::<LargeStringArray>(array)?.value(i))),
+                _ => Ok(None),
+            },
+            |list| {
+                let (values, validity) = list.as_str_values()?;
+                Some(
+                    values
+                        .get(index)
+                        .filter(|_| validity.is_valid(index))
+                        .map(String::as_str),
+                )
+            },
+        )
+    }
+
+    /// The value in the `Binary`, `LargeBinary` or `FixedSizeBinary` column called `name`.
+    pub fn get_bytes(&self, name: &str) -> Result<Option<&'a [u8]>, ArrowScalarError> {
+        let index = self.index;
+        self.get_typed(
+            name,
+            "Binary",
+            |array, i| match array.data_type() {
+                DataType::Binary => Ok(Some(downcast::<BinaryArray>(array)?.value(i))),
+                DataType::LargeBinary => Ok(Some(downcast::<LargeBinaryArray>(array)?.value(i))),
+                DataType::FixedSizeBinary(_) => {
+                    Ok(Some(downcast::<FixedSizeBinaryArray>(array)?.value(i)))
+                }
+                _ => Ok(None),
+            },
+            |list| {
+                let (values, validity) = list.as_bytes_values()?;
+                Some(
+                    values
+                        .get(index)
+                        .filter(|_| validity.is_valid(index))
+                        .map(Vec::as_slice),
+                )
+            },
+        )
+    }
+
+    /// The value in the `Boolean` column called `name`.
+    pub fn get_bool(&self, name: &str) -> Result<Option<bool>, ArrowScalarError> {
+        let index = self.index;
+        self.get_typed(
+            name,
+            "Boolean",
+            |array, i| match array.data_type() {
+                DataType::Boolean => Ok(Some(downcast::<BooleanArray>(array)?.value(i))),
+                _ => Ok(None),
+            },
+            |list| {
+                let (values, validity) = list.as_bool_values()?;
+                Some(
+                    values
+                        .get(index)
+                        .filter(|_| validity.is_valid(index))
+                        .copied(),
+                )
+            },
+        )
+    }
+
+    /// The value in the column called `name`, which must be stored as `i64`: `Int64` and
+    /// the 64-bit temporal types, see [`TableList::as_i64_values`].
+    pub fn get_i64(&self, name: &str) -> Result<Option<i64>, ArrowScalarError> {
+        let index = self.index;
+        self.get_typed(
+            name,
+            "Int64",
+            |array, i| {
+                let value = match array.data_type() {
+                    DataType::Int64 => primitive_value::<Int64Type>(array, i)?,
+                    DataType::Date64 => primitive_value::<Date64Type>(array, i)?,
+                    DataType::Time64(TimeUnit::Microsecond) => {
+                        primitive_value::<Time64MicrosecondType>(array, i)?
+                    }
+                    DataType::Time64(TimeUnit::Nanosecond) => {
+                        primitive_value::<Time64NanosecondType>(array, i)?
+                    }
+                    DataType::Timestamp(TimeUnit::Second, _) => {
+                        primitive_value::<TimestampSecondType>(array, i)?
+                    }
+                    DataType::Timestamp(TimeUnit::Millisecond, _) => {
+                        primitive_value::<TimestampMillisecondType>(array, i)?
+                    }
+                    DataType::Timestamp(TimeUnit::Microsecond, _) => {
+                        primitive_value::<TimestampMicrosecondType>(array, i)?
+                    }
+                    DataType::Timestamp(TimeUnit::Nanosecond, _) => {
+                        primitive_value::<TimestampNanosecondType>(array, i)?
+                    }
+                    DataType::Duration(TimeUnit::Second) => {
+                        primitive_value::<DurationSecondType>(array, i)?
+                    }
+                    DataType::Duration(TimeUnit::Millisecond) => {
+                        primitive_value::<DurationMillisecondType>(array, i)?
+                    }
+                    DataType::Duration(TimeUnit::Microsecond) => {
+                        primitive_value::<DurationMicrosecondType>(array, i)?
+                    }
+                    DataType::Duration(TimeUnit::Nanosecond) => {
+                        primitive_value::<DurationNanosecondType>(array, i)?
+                    }
+                    DataType::Interval(IntervalUnit::DayTime) => {
+                        primitive_value::<IntervalDayTimeType>(array, i)?
+                    }
+                    _ => return Ok(None),
+                };
+                Ok(Some(value))
+            },
+            |list| {
+                let (values, validity) = list.as_i64_values()?;
+                Some(
+                    values
+                        .get(index)
+                        .filter(|_| validity.is_valid(index))
+                        .copied(),
+                )
+            },
+        )
+    }
+
+    /// The value in the `Float64` column called `name`.
+    pub fn get_f64(&self, name: &str) -> Result<Option<f64>, ArrowScalarError> {
+        let index = self.index;
+        self.get_typed(
+            name,
+            "Float64",
+            |array, i| match array.data_type() {
+                DataType::Float64 => Ok(Some(downcast::<Float64Array>(array)?.value(i))),
+                _ => Ok(None),
+            },
+            |list| {
+                let (values, validity) = list.as_f64_values()?;
+                Some(
+                    values
+                        .get(index)
+                        .filter(|_| validity.is_valid(index))
+                        .copied(),
+                )
+            },
+        )
+    }
+
+    /// Copies the row into an owned [`TableRow`], as [`RowValuable::row`] would.
+    pub fn to_row(&self) -> Result<TableRow, ArrowScalarError> {
+        match self.source {
+            Source::Batch(batch) => batch.row(self.index),
+            Source::Table(table) => table.row(self.index),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::array::{Int64Array, TimestampMillisecondArray};
+
+    use super::*;
+
+    #[test]
+    fn test_row_ref() {
+        let dict: DictionaryArray<Int8Type> =
+            vec![Some("x"), None, Some("x")].into_iter().collect();
+        let batch = RecordBatch::try_from_iter(vec![
+            (
+                "s",
+                Arc::new(StringArray::from(vec![Some("a"), None, Some("c")])) as ArrayRef,
+            ),
+            ("d", Arc::new(dict) as ArrayRef),
+            (
+                "b",
+                Arc::new(BinaryArray::from(vec![&b"ab"[..], b"", b"c"])) as ArrayRef,
+            ),
+            (
+                "i",
+                Arc::new(Int64Array::from(vec![Some(1), Some(2), None])) as ArrayRef,
+            ),
+            (
+                "t",
+                Arc::new(TimestampMillisecondArray::from(vec![5, 6, 7])) as ArrayRef,
+            ),
+            (
+                "f",
+                Arc::new(Float64Array::from(vec![0.5, 1.5, 2.5])) as ArrayRef,
+            ),
+            (
+                "bo",
+                Arc::new(BooleanArray::from(vec![true, false, true])) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+        let table = Table::from_arrow(&batch).unwrap();
+
+        for index in 0..3 {
+            let from_batch = batch.row_ref(index).unwrap();
+            let from_table = table.row_ref(index).unwrap();
+            assert_eq!(from_batch.to_row().unwrap(), batch.row(index).unwrap());
+            assert_eq!(from_table.to_row().unwrap(), table.row(index).unwrap());
+            for row in [from_batch, from_table] {
+                assert_eq!(row.index(), index);
+                assert_eq!(row.num_columns(), 7);
+                assert_eq!(
+                    row.get_str("s").unwrap(),
+                    [Some("a"), None, Some("c")][index]
+                );
+                assert_eq!(
+                    row.get_str("d").unwrap(),
+                    [Some("x"), None, Some("x")][index]
+                );
+                assert_eq!(
+                    row.get_bytes("b").unwrap(),
+                    Some([&b"ab"[..], b"", b"c"][index])
+                );
+                assert_eq!(row.get_i64("i").unwrap(), [Some(1), Some(2), None][index]);
+                assert_eq!(row.get_i64("t").unwrap(), Some(5 + index as i64));
+                assert_eq!(row.get_f64("f").unwrap(), Some(0.5 + index as f64));
+                assert_eq!(row.get_bool("bo").unwrap(), Some(index != 1));
+            }
+        }
+
+        let row = table.row_ref(0).unwrap();
+        assert_eq!(row.get("s").unwrap(), TableScalar::utf8("a".to_string()));
+        assert_eq!(row.get_by_index(3).unwrap(), TableScalar::int64(1));
+        assert!(matches!(
+            row.get_by_index(7),
+            Err(ArrowScalarError::AccessError { index: 7, len: 7 })
+        ));
+        assert!(matches!(
+            row.get("missing"),
+            Err(ArrowScalarError::UnknownColumn(_))
+        ));
+        assert!(matches!(
+            row.get_str("i").unwrap_err().root(),
+            ArrowScalarError::TypeMismatch { .. }
+        ));
+        assert!(matches!(
+            batch.row_ref(0).unwrap().get_f64("s").unwrap_err().root(),
+            ArrowScalarError::TypeMismatch { .. }
+        ));
+        assert!(table.row_ref(3).is_err());
+        assert!(batch.row_ref(3).is_err());
+    }
+}
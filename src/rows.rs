@@ -0,0 +1,161 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use crate::{ArrowScalarError, ScalarValuable, TableRow};
+
+/// An iterator over the rows of a table or record batch, see [`crate::RowValuable::rows`].
+/// The column names are looked up once, not for every row.
+#[derive(Clone)]
+pub struct Rows<'a> {
+    columns: Vec<(Cow<'a, str>, &'a (dyn ScalarValuable + Sync))>,
+    range: Range<usize>,
+}
+
+impl<'a> Rows<'a> {
+    pub(crate) fn new(
+        columns: Vec<(Cow<'a, str>, &'a (dyn ScalarValuable + Sync))>,
+        len: usize,
+    ) -> Self {
+        Rows {
+            columns,
+            range: 0..len,
+        }
+    }
+
+    /// Limits the iterator to the rows in `range`, which must be within the rows and not
+    /// inverted.
+    pub(crate) fn with_range(mut self, range: Range<usize>) -> Result<Self, ArrowScalarError> {
+        let len = self.range.end;
+        if range.start > range.end {
+            return Err(ArrowScalarError::AccessError {
+                index: range.start,
+                len,
+            });
+        }
+        if range.end > len {
+            return Err(ArrowScalarError::AccessError {
+                index: range.end,
+                len,
+            });
+        }
+        self.range = range;
+        Ok(self)
+    }
+
+    /// The row at `index`, which the iterator need not cover.
+    pub(crate) fn row(&self, index: usize) -> Result<TableRow, ArrowScalarError> {
+        let mut values = HashMap::with_capacity(self.columns.len());
+        for (name, column) in self.columns.iter() {
+            let scalar = column
+                .scalar(index)
+                .map_err(|error| error.at_row(index).in_column(name))?;
+            values.insert(name.to_string(), scalar);
+        }
+        Ok(TableRow { values })
+    }
+
+    #[cfg(feature = "rayon")]
+    pub(crate) fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+}
+
+impl Iterator for Rows<'_> {
+    type Item = Result<TableRow, ArrowScalarError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.range.next()?;
+        Some(self.row(index))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let index = self.range.nth(n)?;
+        Some(self.row(index))
+    }
+}
+
+impl DoubleEndedIterator for Rows<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self.range.next_back()?;
+        Some(self.row(index))
+    }
+}
+
+impl ExactSizeIterator for Rows<'_> {}
+
+impl FusedIterator for Rows<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Range;
+    use std::sync::Arc;
+
+    use arrow::array::{ArrayRef, Int32Array, StringArray};
+    use arrow::record_batch::RecordBatch;
+
+    use crate::{ArrowScalarError, RowValuable, Table, TableScalar};
+
+    #[test]
+    fn test_rows() {
+        let batch = RecordBatch::try_from_iter(vec![
+            (
+                "a",
+                Arc::new(Int32Array::from(vec![Some(1), None, Some(3)])) as ArrayRef,
+            ),
+            (
+                "b",
+                Arc::new(StringArray::from(vec!["x", "y", "z"])) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+        let table = Table::from_arrow(&batch).unwrap();
+
+        let expected = (0..3).map(|i| table.row(i).unwrap()).collect::<Vec<_>>();
+        let rows = table.rows().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(rows, expected);
+        let rows = batch.rows().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(rows, expected);
+
+        let mut rows = table.rows_range(1..3).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows.next_back().unwrap().unwrap(), expected[2]);
+        let row = rows.next().unwrap().unwrap();
+        assert_eq!(row.values["a"], TableScalar { value: None });
+        assert!(rows.next().is_none());
+
+        assert_eq!(batch.rows_range(3..3).unwrap().count(), 0);
+        assert!(table.rows_range(2..4).is_err());
+        assert!(batch.rows_range(4..5).is_err());
+        let inverted = Range { start: 2, end: 1 };
+        assert!(matches!(
+            table.rows_range(inverted),
+            Err(ArrowScalarError::AccessError { index: 2, len: 3 })
+        ));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_rows() {
+        use rayon::iter::ParallelIterator;
+
+        let batch = RecordBatch::try_from_iter(vec![(
+            "a",
+            Arc::new(Int32Array::from((0..1000).collect::<Vec<_>>())) as ArrayRef,
+        )])
+        .unwrap();
+        let table = Table::from_arrow(&batch).unwrap();
+        let rows = table.par_rows().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(rows.len(), 1000);
+        assert_eq!(rows[999].values["a"], TableScalar::int32(999));
+        assert_eq!(
+            batch.par_rows().collect::<Result<Vec<_>, _>>().unwrap(),
+            rows
+        );
+    }
+}
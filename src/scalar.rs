@@ -1,4 +1,4 @@
-use crate::list::ListValuable;
+use crate::list::{dictionary_key, downcast_error, ListValuable};
 use crate::{table_scalar, ArrowScalarError, TableList, TableScalar};
 use arrow::array::*;
 use arrow::datatypes::*;
@@ -12,11 +12,17 @@ pub trait ScalarValuable {
 
 impl<T: Array> ScalarValuable for T {
     fn scalar(&self, i: usize) -> Result<TableScalar, ArrowScalarError> {
+        if i >= self.len() {
+            return Err(ArrowScalarError::AccessError {
+                index: i,
+                len: self.len(),
+            });
+        }
         if self.is_null(i) {
             return Ok(TableScalar { value: None });
         }
         let value = match self.data_type() {
-            DataType::Null => unreachable!(),
+            DataType::Null => None,
             DataType::Int8 => {
                 let array = as_primitive_array::<Int8Type>(self);
                 Some(table_scalar::Value::Int8(array.value(i).into()))
@@ -86,7 +92,7 @@ impl<T: Array> ScalarValuable for T {
                 let array = self
                     .as_any()
                     .downcast_ref::<FixedSizeBinaryArray>()
-                    .expect("Just checked it has this type.");
+                    .ok_or_else(|| downcast_error(self))?;
                 Some(table_scalar::Value::Binary(array.value(i).into()))
             }
             DataType::Utf8 => {
@@ -106,7 +112,7 @@ impl<T: Array> ScalarValuable for T {
                     let array = as_primitive_array::<Time32MillisecondType>(self);
                     Some(table_scalar::Value::Time32Millisecond(array.value(i)))
                 }
-                _ => unreachable!(),
+                _ => return Err(downcast_error(self)),
             },
             DataType::Time64(unit) => match unit {
                 TimeUnit::Microsecond => {
@@ -117,7 +123,7 @@ impl<T: Array> ScalarValuable for T {
                     let array = as_primitive_array::<Time64NanosecondType>(self);
                     Some(table_scalar::Value::Time64Nanosecond(array.value(i)))
                 }
-                _ => unreachable!(),
+                _ => return Err(downcast_error(self)),
             },
             DataType::Timestamp(unit, _tz) => match unit {
                 TimeUnit::Second => {
@@ -189,45 +195,45 @@ impl<T: Array> ScalarValuable for T {
                 let value = match key_type.deref() {
                     DataType::Int8 => {
                         let array = as_dictionary_array::<Int8Type>(self);
-                        let index = array.keys().value(i).to_usize().unwrap();
+                        let index = dictionary_key(array.keys().value(i))?;
                         array.values().scalar(index)
                     }
                     DataType::Int16 => {
                         let array = as_dictionary_array::<Int16Type>(self);
-                        let index = array.keys().value(i).to_usize().unwrap();
+                        let index = dictionary_key(array.keys().value(i))?;
                         array.values().scalar(index)
                     }
                     DataType::Int32 => {
                         let array = as_dictionary_array::<Int32Type>(self);
-                        let index = array.keys().value(i).to_usize().unwrap();
+                        let index = dictionary_key(array.keys().value(i))?;
                         array.values().scalar(index)
                     }
                     DataType::Int64 => {
                         let array = as_dictionary_array::<Int64Type>(self);
-                        let index = array.keys().value(i).to_usize().unwrap();
+                        let index = dictionary_key(array.keys().value(i))?;
                         array.values().scalar(index)
                     }
                     DataType::UInt8 => {
                         let array = as_dictionary_array::<UInt8Type>(self);
-                        let index = array.keys().value(i).to_usize().unwrap();
+                        let index = dictionary_key(array.keys().value(i))?;
                         array.values().scalar(index)
                     }
                     DataType::UInt16 => {
                         let array = as_dictionary_array::<UInt16Type>(self);
-                        let index = array.keys().value(i).to_usize().unwrap();
+                        let index = dictionary_key(array.keys().value(i))?;
                         array.values().scalar(index)
                     }
                     DataType::UInt32 => {
                         let array = as_dictionary_array::<UInt32Type>(self);
-                        let index = array.keys().value(i).to_usize().unwrap();
+                        let index = dictionary_key(array.keys().value(i))?;
                         array.values().scalar(index)
                     }
                     DataType::UInt64 => {
                         let array = as_dictionary_array::<UInt64Type>(self);
-                        let index = array.keys().value(i).to_usize().unwrap();
+                        let index = dictionary_key(array.keys().value(i))?;
                         array.values().scalar(index)
                     }
-                    _ => unreachable!(),
+                    _ => return Err(downcast_error(self)),
                 };
 
                 Some(table_scalar::Value::Dictionary(Box::new(value?)))
@@ -236,7 +242,7 @@ impl<T: Array> ScalarValuable for T {
                 let array = self
                     .as_any()
                     .downcast_ref::<ListArray>()
-                    .expect("Just checked it has this type.");
+                    .ok_or_else(|| downcast_error(self))?;
                 let value = array.value(i).clone_as_list()?;
                 Some(table_scalar::Value::List(value))
             }
@@ -244,7 +250,7 @@ impl<T: Array> ScalarValuable for T {
                 let array = self
                     .as_any()
                     .downcast_ref::<LargeListArray>()
-                    .expect("Just checked it has this type.");
+                    .ok_or_else(|| downcast_error(self))?;
                 let value = array.value(i).clone_as_list()?;
                 Some(table_scalar::Value::LargeList(value))
             }
@@ -252,7 +258,7 @@ impl<T: Array> ScalarValuable for T {
                 let array = self
                     .as_any()
                     .downcast_ref::<FixedSizeListArray>()
-                    .expect("Just checked it has this type.");
+                    .ok_or_else(|| downcast_error(self))?;
                 let value = array.value(i).clone_as_list()?;
                 Some(table_scalar::Value::FixedSizeList(value))
             }
@@ -379,7 +385,7 @@ impl TableScalar {
                 ))
             }
             Some(table_scalar::Value::Null(_)) => DataType::Null,
-            None => return Err(ArrowScalarError::InvalidProtobuf),
+            None => return Err(ArrowScalarError::InvalidProtobuf("TableScalar.value")),
         };
         Ok(val)
     }
@@ -589,10 +595,10 @@ impl TryFrom<Vec<TableScalar>> for TableList {
 }
 
 #[cfg(test)]
-pub mod tests {
+mod tests {
     use std::{collections::HashMap, sync::Arc};
 
-    use crate::{table_list, TableList};
+    use crate::{table_list, TableList, Validity};
 
     use super::*;
 
@@ -791,7 +797,11 @@ pub mod tests {
         };
         let list_entries = table_list::Int32List {
             values: vec![3, 0, 5],
-            set: vec![true, false, true],
+            set: vec![],
+            validity: Some(Validity {
+                bitmap: vec![0b101],
+                all_valid: false,
+            }),
         };
         let list_value = TableList {
             values: Some(table_list::Values::Int32(list_entries)),
@@ -800,7 +810,7 @@ pub mod tests {
             elements: HashMap::from([
                 ("bo".to_owned(), TableScalar { value: None }),
                 (
-                    "f1".to_owned(),
+                    "fl".to_owned(),
                     TableScalar {
                         value: Some(table_scalar::Value::Float64(0.0)),
                     },
@@ -883,7 +893,11 @@ pub mod tests {
         let list_array = ListArray::from_iter_primitive::<Int32Type, _, _>(data);
         let list_entries = table_list::Int32List {
             values: vec![3, 0, 5],
-            set: vec![true, false, true],
+            set: vec![],
+            validity: Some(Validity {
+                bitmap: vec![0b101],
+                all_valid: false,
+            }),
         };
         let list_value = TableList {
             values: Some(table_list::Values::Int32(list_entries)),
@@ -912,7 +926,8 @@ pub mod tests {
             Arc::new(DictionaryArray::try_new(&keys, &list).unwrap());
         let list_entries = table_list::Int32List {
             values: vec![0, 1, 2],
-            set: vec![true, true, true],
+            set: vec![],
+            validity: Some(Validity::all_valid()),
         };
         let list_value = TableList {
             values: Some(table_list::Values::Int32(list_entries)),
@@ -928,7 +943,11 @@ pub mod tests {
 
         let list_entries = table_list::Int32List {
             values: vec![3, 0, 5],
-            set: vec![true, false, true],
+            set: vec![],
+            validity: Some(Validity {
+                bitmap: vec![0b101],
+                all_valid: false,
+            }),
         };
         let list_value = TableList {
             values: Some(table_list::Values::Int32(list_entries)),
@@ -0,0 +1,295 @@
+use std::mem::size_of;
+
+use prost::Message;
+
+use crate::table_list::{
+    BinaryList, BooleanList, DictionaryList, Float16List, Float32List, Float64List, Int16List,
+    Int32List, Int64List, Int8List, ListList, StructList, TimeList, UInt16List, UInt32List,
+    UInt64List, UInt8List, UnionList, Utf8List,
+};
+use crate::{table_list, FieldProto, Table, TableList, Validity};
+
+fn vec_heap_size<T>(values: &Vec<T>) -> usize {
+    values.capacity() * size_of::<T>()
+}
+
+/// Memory size of a list message, excluding the message itself.
+trait Size {
+    /// Bytes the message owns on the heap.
+    fn heap_size(&self) -> usize;
+}
+
+impl Size for Option<Validity> {
+    fn heap_size(&self) -> usize {
+        self.as_ref()
+            .map(|validity| validity.bitmap.capacity())
+            .unwrap_or(0)
+    }
+}
+
+macro_rules! primitive_size {
+    ($list:ty) => {
+        impl Size for $list {
+            fn heap_size(&self) -> usize {
+                vec_heap_size(&self.values) + self.set.capacity() + self.validity.heap_size()
+            }
+        }
+    };
+}
+
+primitive_size!(BooleanList);
+primitive_size!(Int8List);
+primitive_size!(Int16List);
+primitive_size!(Int32List);
+primitive_size!(Int64List);
+primitive_size!(UInt8List);
+primitive_size!(UInt16List);
+primitive_size!(UInt32List);
+primitive_size!(UInt64List);
+primitive_size!(Float16List);
+primitive_size!(Float32List);
+primitive_size!(Float64List);
+
+impl Size for TimeList {
+    fn heap_size(&self) -> usize {
+        vec_heap_size(&self.times)
+            + self.tz.as_ref().map(String::capacity).unwrap_or(0)
+            + self.set.capacity()
+            + self.validity.heap_size()
+    }
+}
+
+impl Size for BinaryList {
+    fn heap_size(&self) -> usize {
+        vec_heap_size(&self.values)
+            + self.values.iter().map(Vec::capacity).sum::<usize>()
+            + self.set.capacity()
+            + self.validity.heap_size()
+    }
+}
+
+impl Size for Utf8List {
+    fn heap_size(&self) -> usize {
+        vec_heap_size(&self.values)
+            + self.values.iter().map(String::capacity).sum::<usize>()
+            + self.set.capacity()
+            + self.validity.heap_size()
+    }
+}
+
+/// Type metadata is small and deeply boxed, so its heap size is approximated by its
+/// encoded length.
+fn field_heap_size(field: &FieldProto) -> usize {
+    field.name.capacity()
+        + field
+            .data_type
+            .as_ref()
+            .map(Message::encoded_len)
+            .unwrap_or(0)
+}
+
+fn fields_heap_size(fields: &Vec<FieldProto>) -> usize {
+    vec_heap_size(fields) + fields.iter().map(field_heap_size).sum::<usize>()
+}
+
+fn lists_heap_size(lists: &Vec<TableList>) -> usize {
+    vec_heap_size(lists) + lists.iter().map(TableList::heap_size).sum::<usize>()
+}
+
+impl Size for DictionaryList {
+    fn heap_size(&self) -> usize {
+        self.values
+            .as_ref()
+            .map(|values| size_of::<TableList>() + values.heap_size())
+            .unwrap_or(0)
+            + self
+                .index_type
+                .as_ref()
+                .map(Message::encoded_len)
+                .unwrap_or(0)
+    }
+}
+
+impl Size for StructList {
+    fn heap_size(&self) -> usize {
+        fields_heap_size(&self.fields)
+            + lists_heap_size(&self.values)
+            + self.set.capacity()
+            + self.validity.heap_size()
+    }
+}
+
+/// Union values are rare enough that their heap size is approximated by their encoded
+/// length.
+impl Size for UnionList {
+    fn heap_size(&self) -> usize {
+        vec_heap_size(&self.values)
+            + self.values.iter().map(Message::encoded_len).sum::<usize>()
+            + self.set.capacity()
+            + self.validity.heap_size()
+    }
+}
+
+impl Size for ListList {
+    fn heap_size(&self) -> usize {
+        lists_heap_size(&self.values)
+            + self.set.capacity()
+            + self.list_type.as_ref().map(field_heap_size).unwrap_or(0)
+            + self.validity.heap_size()
+    }
+}
+
+impl<T: Size> Size for Box<T> {
+    fn heap_size(&self) -> usize {
+        size_of::<T>() + self.as_ref().heap_size()
+    }
+}
+
+impl TableList {
+    fn size(&self) -> Option<&dyn Size> {
+        let list: &dyn Size = match self.values.as_ref()? {
+            table_list::Values::Boolean(list) => list,
+            table_list::Values::Int8(list) => list,
+            table_list::Values::Int16(list) => list,
+            table_list::Values::Int32(list)
+            | table_list::Values::Date32(list)
+            | table_list::Values::Time32Second(list)
+            | table_list::Values::Time32Millisecond(list)
+            | table_list::Values::IntervalYearMonth(list) => list,
+            table_list::Values::Int64(list)
+            | table_list::Values::Date64(list)
+            | table_list::Values::Time64Microsecond(list)
+            | table_list::Values::Time64Nanosecond(list)
+            | table_list::Values::DurationSecond(list)
+            | table_list::Values::DurationMillisecond(list)
+            | table_list::Values::DurationMicrosecond(list)
+            | table_list::Values::DurationNanosecond(list)
+            | table_list::Values::IntervalDayTime(list) => list,
+            table_list::Values::Uint8(list) => list,
+            table_list::Values::Uint16(list) => list,
+            table_list::Values::Uint32(list) => list,
+            table_list::Values::Uint64(list) => list,
+            table_list::Values::Float16(list) => list,
+            table_list::Values::Float32(list) => list,
+            table_list::Values::Float64(list) => list,
+            table_list::Values::TimestampSecond(list)
+            | table_list::Values::TimestampMillisecond(list)
+            | table_list::Values::TimestampMicrosecond(list)
+            | table_list::Values::TimestampNanosecond(list) => list,
+            table_list::Values::Binary(list)
+            | table_list::Values::LargeBinary(list)
+            | table_list::Values::FixedSizeBinary(list) => list,
+            table_list::Values::Utf8(list) | table_list::Values::LargeUtf8(list) => list,
+            table_list::Values::List(list)
+            | table_list::Values::LargeList(list)
+            | table_list::Values::FixedSizeList(list) => list,
+            table_list::Values::Union(list) => list,
+            table_list::Values::Dictionary(dict) => dict,
+            table_list::Values::Struct(list) => list,
+        };
+        Some(list)
+    }
+
+    fn heap_size(&self) -> usize {
+        self.size().map(Size::heap_size).unwrap_or(0)
+    }
+
+    /// An estimate of the bytes this list takes in memory, including the capacity of its
+    /// buffers, string and binary values and nested children. For the size on the wire, use
+    /// the exact [`Message::encoded_len`].
+    pub fn memory_size(&self) -> usize {
+        size_of::<TableList>() + self.heap_size()
+    }
+}
+
+impl Table {
+    /// An estimate of the bytes this table takes in memory, see [`TableList::memory_size`].
+    pub fn memory_size(&self) -> usize {
+        size_of::<Table>() + fields_heap_size(&self.fields) + lists_heap_size(&self.values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::array::{
+        ArrayRef, BinaryArray, Float32Array, Int32Array, ListArray, StringArray, StructArray,
+    };
+    use arrow::datatypes::{DataType, Field, Int32Type};
+    use arrow::record_batch::RecordBatch;
+
+    use super::*;
+    use crate::ListValuable;
+
+    /// The sizes of the lists nested in `list`.
+    fn children_size(list: &TableList) -> usize {
+        match list.values.as_ref() {
+            Some(table_list::Values::Struct(list)) => {
+                list.values.iter().map(TableList::memory_size).sum()
+            }
+            Some(table_list::Values::List(list)) => {
+                list.values.iter().map(TableList::memory_size).sum()
+            }
+            _ => 0,
+        }
+    }
+
+    #[test]
+    fn test_memory_size() {
+        let strct = StructArray::from(vec![
+            (
+                Field::new("x", DataType::Float32, true),
+                Arc::new(Float32Array::from(vec![Some(1.5), None, Some(-0.0)])) as ArrayRef,
+            ),
+            (
+                Field::new("y", DataType::Utf8, true),
+                Arc::new(StringArray::from(vec![Some("a"), Some(""), None])) as ArrayRef,
+            ),
+        ]);
+        let strct = strct.clone_as_list().unwrap();
+        assert!(strct.memory_size() > children_size(&strct) + "xy".len());
+
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(-1), None]),
+            None,
+            Some(vec![Some(i32::MIN)]),
+        ]);
+        let list = list.clone_as_list().unwrap();
+        assert!(list.memory_size() > children_size(&list));
+
+        let short = Int32Array::from(vec![1, 2, 3]).clone_as_list().unwrap();
+        let long = Int32Array::from((0..1000).collect::<Vec<_>>())
+            .clone_as_list()
+            .unwrap();
+        assert!(long.memory_size() >= short.memory_size() + 997 * size_of::<i32>());
+
+        let small = StringArray::from(vec!["a"]).clone_as_list().unwrap();
+        let large = StringArray::from(vec!["a".repeat(1000)])
+            .clone_as_list()
+            .unwrap();
+        assert!(large.memory_size() >= small.memory_size() + 999);
+        let small = BinaryArray::from(vec![&b"a"[..]]).clone_as_list().unwrap();
+        let large = BinaryArray::from(vec![&[0u8; 1000][..]])
+            .clone_as_list()
+            .unwrap();
+        assert!(large.memory_size() >= small.memory_size() + 999);
+
+        let table = |array: Int32Array| {
+            let batch =
+                RecordBatch::try_from_iter(vec![("a", Arc::new(array) as ArrayRef)]).unwrap();
+            Table::from_arrow(&batch).unwrap()
+        };
+        let short = table(Int32Array::from(vec![1, 2, 3]));
+        let long = table(Int32Array::from((0..1000).collect::<Vec<_>>()));
+        assert!(long.memory_size() >= short.memory_size() + 997 * size_of::<i32>());
+
+        // Clearing keeps the capacity, which is still counted.
+        let mut cleared = Int32Array::from((0..1000).collect::<Vec<_>>())
+            .clone_as_list()
+            .unwrap();
+        cleared.clear();
+        assert_eq!(cleared.len(), 0);
+        assert!(cleared.memory_size() >= 1000 * size_of::<i32>());
+    }
+}
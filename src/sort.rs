@@ -0,0 +1,243 @@
+use std::cmp::Ordering;
+
+use arrow::compute::SortOptions;
+
+use crate::validity::ValidityRef;
+use crate::{ArrowScalarError, Table, TableList};
+
+/// Compares two rows of a list, with nulls and direction already taken into account.
+type RowComparator<'a> = Box<dyn Fn(usize, usize) -> Ordering + 'a>;
+
+fn comparator<'a, T>(
+    values: &'a [T],
+    validity: ValidityRef<'a>,
+    options: SortOptions,
+    compare: fn(&T, &T) -> Ordering,
+) -> RowComparator<'a> {
+    Box::new(
+        move |a, b| match (validity.is_valid(a), validity.is_valid(b)) {
+            (false, false) => Ordering::Equal,
+            (false, true) if options.nulls_first => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (true, false) if options.nulls_first => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (true, true) => {
+                let ordering = match (values.get(a), values.get(b)) {
+                    (Some(a), Some(b)) => compare(a, b),
+                    (a, b) => a.is_some().cmp(&b.is_some()),
+                };
+                if options.descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            }
+        },
+    )
+}
+
+impl TableList {
+    pub(crate) fn row_comparator(
+        &self,
+        options: SortOptions,
+    ) -> Result<RowComparator<'_>, ArrowScalarError> {
+        if let Some((values, validity)) = self.as_bool_values() {
+            Ok(comparator(values, validity, options, Ord::cmp))
+        } else if let Some((values, validity)) = self.as_i32_values() {
+            Ok(comparator(values, validity, options, Ord::cmp))
+        } else if let Some((values, validity)) = self.as_i64_values() {
+            Ok(comparator(values, validity, options, Ord::cmp))
+        } else if let Some((values, validity)) = self.as_u32_values() {
+            Ok(comparator(values, validity, options, Ord::cmp))
+        } else if let Some((values, validity)) = self.as_u64_values() {
+            Ok(comparator(values, validity, options, Ord::cmp))
+        } else if let Some((values, validity)) = self.as_f32_values() {
+            Ok(comparator(values, validity, options, f32::total_cmp))
+        } else if let Some((values, validity)) = self.as_f64_values() {
+            Ok(comparator(values, validity, options, f64::total_cmp))
+        } else if let Some((values, validity)) = self.as_str_values() {
+            Ok(comparator(values, validity, options, Ord::cmp))
+        } else if let Some((values, validity)) = self.as_bytes_values() {
+            Ok(comparator(values, validity, options, Ord::cmp))
+        } else {
+            Err(ArrowScalarError::Unimplemented(
+                "sort_indices",
+                "Struct, List, Union or Null",
+            ))
+        }
+    }
+
+    /// Returns the row indices in sorted order. The sort is stable, so equal rows keep
+    /// their relative order. Floats use a total order, with NaN above infinity, and
+    /// dictionaries are ordered by value rather than by key.
+    pub fn sort_indices(&self, options: SortOptions) -> Result<Vec<usize>, ArrowScalarError> {
+        let compare = self.row_comparator(options)?;
+        let mut indices = (0..self.len()).collect::<Vec<_>>();
+        indices.sort_by(|a, b| compare(*a, *b));
+        Ok(indices)
+    }
+}
+
+impl Table {
+    /// Returns the row indices in lexicographic order of `columns`, each sorted with its
+    /// own options. Rows that compare equal on every column keep their relative order.
+    pub fn sort_indices(
+        &self,
+        columns: &[(&str, SortOptions)],
+    ) -> Result<Vec<usize>, ArrowScalarError> {
+        let comparators = columns
+            .iter()
+            .map(|(name, options)| {
+                self.column_by_name(name)
+                    .ok_or_else(|| ArrowScalarError::UnknownColumn(name.to_string()))?
+                    .row_comparator(*options)
+                    .map_err(|error| error.in_column(name))
+            })
+            .collect::<Result<Vec<_>, ArrowScalarError>>()?;
+        let mut indices = (0..self.len()).collect::<Vec<_>>();
+        indices.sort_by(|a, b| {
+            comparators
+                .iter()
+                .map(|compare| compare(*a, *b))
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or(Ordering::Equal)
+        });
+        Ok(indices)
+    }
+
+    /// Returns a copy of the table with its rows sorted by `columns`, see
+    /// [`Table::sort_indices`].
+    pub fn sort_by(&self, columns: &[(&str, SortOptions)]) -> Result<Table, ArrowScalarError> {
+        let indices = self.sort_indices(columns)?;
+        self.take(&indices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::array::{
+        ArrayRef, DictionaryArray, Float64Array, Int32Array, StringArray, StructArray,
+        TimestampMillisecondArray,
+    };
+    use arrow::datatypes::Int8Type;
+    use arrow::record_batch::RecordBatch;
+
+    use super::*;
+    use crate::ListValuable;
+
+    const ASC: SortOptions = SortOptions {
+        descending: false,
+        nulls_first: true,
+    };
+    const DESC_NULLS_LAST: SortOptions = SortOptions {
+        descending: true,
+        nulls_first: false,
+    };
+
+    #[test]
+    fn test_sort_indices() {
+        let list = Int32Array::from(vec![Some(3), None, Some(1), Some(3), Some(2)])
+            .clone_as_list()
+            .unwrap();
+        assert_eq!(list.sort_indices(ASC).unwrap(), vec![1, 2, 4, 0, 3]);
+        assert_eq!(
+            list.sort_indices(DESC_NULLS_LAST).unwrap(),
+            vec![0, 3, 4, 2, 1]
+        );
+
+        let list = Float64Array::from(vec![Some(f64::NAN), Some(-1.5), None, Some(f64::INFINITY)])
+            .clone_as_list()
+            .unwrap();
+        assert_eq!(list.sort_indices(ASC).unwrap(), vec![2, 1, 3, 0]);
+
+        let list = StringArray::from(vec![Some("b"), Some("a"), None, Some("ab")])
+            .clone_as_list()
+            .unwrap();
+        assert_eq!(list.sort_indices(ASC).unwrap(), vec![2, 1, 3, 0]);
+
+        let list = TimestampMillisecondArray::from(vec![Some(10), None, Some(-5)])
+            .clone_as_list()
+            .unwrap();
+        assert_eq!(list.sort_indices(DESC_NULLS_LAST).unwrap(), vec![0, 2, 1]);
+
+        // Keys are in the reverse order of the values they point to.
+        let dict = vec![Some("z"), Some("a"), None, Some("m")]
+            .into_iter()
+            .collect::<DictionaryArray<Int8Type>>();
+        let list = dict.clone_as_list().unwrap();
+        assert_eq!(list.sort_indices(ASC).unwrap(), vec![2, 1, 3, 0]);
+
+        let list = StructArray::from(vec![(
+            arrow::datatypes::Field::new("a", arrow::datatypes::DataType::Int32, true),
+            Arc::new(Int32Array::from(vec![1])) as ArrayRef,
+        )])
+        .clone_as_list()
+        .unwrap();
+        assert!(matches!(
+            list.sort_indices(ASC),
+            Err(ArrowScalarError::Unimplemented(..))
+        ));
+    }
+
+    #[test]
+    fn test_sort_by() {
+        let batch = RecordBatch::try_from_iter(vec![
+            (
+                "a",
+                Arc::new(Int32Array::from(vec![
+                    Some(2),
+                    Some(1),
+                    None,
+                    Some(2),
+                    Some(1),
+                ])) as ArrayRef,
+            ),
+            (
+                "b",
+                Arc::new(StringArray::from(vec!["x", "y", "z", "y", "x"])) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+        let table = Table::from_arrow(&batch).unwrap();
+
+        assert_eq!(
+            table
+                .sort_indices(&[("a", DESC_NULLS_LAST), ("b", ASC)])
+                .unwrap(),
+            vec![0, 3, 4, 1, 2]
+        );
+        // Ties on every column keep their input order.
+        assert_eq!(
+            table.sort_indices(&[("a", ASC)]).unwrap(),
+            vec![2, 1, 4, 0, 3]
+        );
+
+        let sorted = table.sort_by(&[("b", ASC), ("a", ASC)]).unwrap();
+        let expected = Table::from_arrow(
+            &RecordBatch::try_from_iter(vec![
+                (
+                    "a",
+                    Arc::new(Int32Array::from(vec![
+                        Some(1),
+                        Some(2),
+                        Some(1),
+                        Some(2),
+                        None,
+                    ])) as ArrayRef,
+                ),
+                (
+                    "b",
+                    Arc::new(StringArray::from(vec!["x", "x", "y", "y", "z"])) as ArrayRef,
+                ),
+            ])
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(sorted, expected);
+
+        let error = table.sort_by(&[("c", ASC)]).unwrap_err();
+        assert!(matches!(error, ArrowScalarError::UnknownColumn(name) if name == "c"));
+    }
+}
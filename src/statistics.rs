@@ -0,0 +1,409 @@
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, Float64Array, StringArray, UInt64Array};
+use arrow::compute::SortOptions;
+use arrow::record_batch::RecordBatch;
+use arrow::util::display::array_value_to_string;
+
+use crate::{table_list, ArrowScalarError, ScalarValuable, Table, TableList, TableScalar};
+
+/// Summary statistics of a list. Everything but `null_count` is computed over the valid
+/// rows only.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Statistics {
+    /// The number of valid rows.
+    pub count: usize,
+    pub null_count: usize,
+    /// The exact number of distinct valid values, for types that can be sorted.
+    pub distinct_count: Option<usize>,
+    /// The smallest value, in the order of [`TableList::sort_indices`].
+    pub min: Option<TableScalar>,
+    /// The largest value, in the order of [`TableList::sort_indices`].
+    pub max: Option<TableScalar>,
+    /// The sum of a numeric list, as an `Int64`, `UInt64` or `Float64`. Integer sums that
+    /// overflow 64 bits are given as a `Float64`.
+    pub sum: Option<TableScalar>,
+    /// The mean of a numeric list, as a `Float64`.
+    pub mean: Option<TableScalar>,
+    /// The shortest value of a string or binary list, in bytes.
+    pub min_length: Option<usize>,
+    /// The longest value of a string or binary list, in bytes.
+    pub max_length: Option<usize>,
+}
+
+fn integer_sum(sum: i128) -> TableScalar {
+    i64::try_from(sum)
+        .map(TableScalar::int64)
+        .unwrap_or_else(|_| TableScalar::float64(sum as f64))
+}
+
+fn unsigned_sum(sum: u128) -> TableScalar {
+    u64::try_from(sum)
+        .map(TableScalar::uint64)
+        .unwrap_or_else(|_| TableScalar::float64(sum as f64))
+}
+
+fn sum_and_mean(
+    sum: TableScalar,
+    total: f64,
+    count: usize,
+) -> (Option<TableScalar>, Option<TableScalar>) {
+    let mean = (count > 0).then(|| TableScalar::float64(total / count as f64));
+    (Some(sum), mean)
+}
+
+impl TableList {
+    fn is_numeric(&self) -> bool {
+        match self.values.as_ref() {
+            Some(
+                table_list::Values::Int8(_)
+                | table_list::Values::Int16(_)
+                | table_list::Values::Int32(_)
+                | table_list::Values::Int64(_)
+                | table_list::Values::Uint8(_)
+                | table_list::Values::Uint16(_)
+                | table_list::Values::Uint32(_)
+                | table_list::Values::Uint64(_)
+                | table_list::Values::Float16(_)
+                | table_list::Values::Float32(_)
+                | table_list::Values::Float64(_),
+            ) => true,
+            Some(table_list::Values::Dictionary(dict)) => dict
+                .values
+                .as_ref()
+                .map(|values| values.is_numeric())
+                .unwrap_or(false),
+            _ => false,
+        }
+    }
+
+    fn sum_and_mean(&self, valid: &[usize]) -> (Option<TableScalar>, Option<TableScalar>) {
+        let count = valid.len();
+        if !self.is_numeric() {
+            (None, None)
+        } else if let Some((values, _)) = self.as_i32_values() {
+            let sum = valid
+                .iter()
+                .filter_map(|i| values.get(*i))
+                .map(|v| *v as i128)
+                .sum::<i128>();
+            sum_and_mean(integer_sum(sum), sum as f64, count)
+        } else if let Some((values, _)) = self.as_i64_values() {
+            let sum = valid
+                .iter()
+                .filter_map(|i| values.get(*i))
+                .map(|v| *v as i128)
+                .sum::<i128>();
+            sum_and_mean(integer_sum(sum), sum as f64, count)
+        } else if let Some((values, _)) = self.as_u32_values() {
+            let sum = valid
+                .iter()
+                .filter_map(|i| values.get(*i))
+                .map(|v| *v as u128)
+                .sum::<u128>();
+            sum_and_mean(unsigned_sum(sum), sum as f64, count)
+        } else if let Some((values, _)) = self.as_u64_values() {
+            let sum = valid
+                .iter()
+                .filter_map(|i| values.get(*i))
+                .map(|v| *v as u128)
+                .sum::<u128>();
+            sum_and_mean(unsigned_sum(sum), sum as f64, count)
+        } else if let Some((values, _)) = self.as_f32_values() {
+            let sum = valid
+                .iter()
+                .filter_map(|i| values.get(*i))
+                .map(|v| *v as f64)
+                .sum::<f64>();
+            sum_and_mean(TableScalar::float64(sum), sum, count)
+        } else if let Some((values, _)) = self.as_f64_values() {
+            let sum = valid.iter().filter_map(|i| values.get(*i)).sum::<f64>();
+            sum_and_mean(TableScalar::float64(sum), sum, count)
+        } else {
+            (None, None)
+        }
+    }
+
+    fn lengths(&self, valid: &[usize]) -> Option<(usize, usize)> {
+        let lengths = if let Some((values, _)) = self.as_str_values() {
+            valid
+                .iter()
+                .filter_map(|i| values.get(*i))
+                .map(String::len)
+                .collect::<Vec<_>>()
+        } else if let Some((values, _)) = self.as_bytes_values() {
+            valid
+                .iter()
+                .filter_map(|i| values.get(*i))
+                .map(Vec::len)
+                .collect::<Vec<_>>()
+        } else {
+            return None;
+        };
+        Some((*lengths.iter().min()?, *lengths.iter().max()?))
+    }
+
+    /// Computes [`Statistics`] for this list. Struct, list and union lists only get counts.
+    pub fn statistics(&self) -> Result<Statistics, ArrowScalarError> {
+        let list = self
+            .list_validity()
+            .ok_or(ArrowScalarError::InvalidProtobuf("TableList.values"))?;
+        list.check().map_err(ArrowScalarError::InvalidList)?;
+        let validity = list.validity_ref();
+        let valid = (0..self.len())
+            .filter(|i| validity.is_valid(*i))
+            .collect::<Vec<_>>();
+
+        let (mut min, mut max, mut distinct_count) = (None, None, None);
+        if let Ok(compare) = self.row_comparator(SortOptions::default()) {
+            let mut sorted = valid.clone();
+            sorted.sort_by(|a, b| compare(*a, *b));
+            distinct_count = Some(
+                sorted
+                    .windows(2)
+                    .filter(|pair| compare(pair[0], pair[1]).is_ne())
+                    .count()
+                    + usize::from(!sorted.is_empty()),
+            );
+            if let (Some(first), Some(last)) = (sorted.first(), sorted.last()) {
+                min = Some(self.scalar(*first)?);
+                max = Some(self.scalar(*last)?);
+            }
+        }
+        let (sum, mean) = self.sum_and_mean(&valid);
+        let lengths = self.lengths(&valid);
+        Ok(Statistics {
+            count: valid.len(),
+            null_count: self.len() - valid.len(),
+            distinct_count,
+            min,
+            max,
+            sum,
+            mean,
+            min_length: lengths.map(|(min, _)| min),
+            max_length: lengths.map(|(_, max)| max),
+        })
+    }
+}
+
+/// Formats `scalar` the way Arrow prints it, falling back to its `Debug` rendering for the
+/// types Arrow cannot print. Null scalars have no text.
+pub(crate) fn display(scalar: &Option<TableScalar>) -> Option<String> {
+    let scalar = scalar.as_ref()?;
+    let value = scalar.value.as_ref()?;
+    let text = TableList::try_from(vec![scalar.clone()])
+        .and_then(|list| list.to_array())
+        .and_then(|array| Ok(array_value_to_string(&array, 0)?));
+    Some(text.unwrap_or_else(|_| format!("{:?}", value)))
+}
+
+impl Table {
+    /// Returns a table with one row of [`Statistics`] per column, in the style of
+    /// `pandas.DataFrame.describe`. The `min`, `max` and `sum` columns are formatted as
+    /// strings, since their types differ between columns.
+    pub fn describe(&self) -> Result<Table, ArrowScalarError> {
+        let mut names = vec![];
+        let mut count = vec![];
+        let mut null_count = vec![];
+        let mut distinct_count = vec![];
+        let mut min = vec![];
+        let mut max = vec![];
+        let mut sum = vec![];
+        let mut mean = vec![];
+        let mut min_length = vec![];
+        let mut max_length = vec![];
+        for (field, column) in self.fields.iter().zip(self.values.iter()) {
+            let in_column = |error: ArrowScalarError| error.in_column(&field.name);
+            // A column without values has the Null type, so every row of the table is null.
+            let statistics = if column.values.is_none() {
+                Statistics {
+                    count: 0,
+                    null_count: self.len(),
+                    distinct_count: None,
+                    min: None,
+                    max: None,
+                    sum: None,
+                    mean: None,
+                    min_length: None,
+                    max_length: None,
+                }
+            } else {
+                column.statistics().map_err(in_column)?
+            };
+            names.push(field.name.clone());
+            count.push(statistics.count as u64);
+            null_count.push(statistics.null_count as u64);
+            distinct_count.push(statistics.distinct_count.map(|n| n as u64));
+            min.push(display(&statistics.min));
+            max.push(display(&statistics.max));
+            sum.push(display(&statistics.sum));
+            mean.push(match statistics.mean.and_then(|mean| mean.value) {
+                Some(crate::table_scalar::Value::Float64(mean)) => Some(mean),
+                _ => None,
+            });
+            min_length.push(statistics.min_length.map(|n| n as u64));
+            max_length.push(statistics.max_length.map(|n| n as u64));
+        }
+        let batch = RecordBatch::try_from_iter(vec![
+            ("column", Arc::new(StringArray::from(names)) as ArrayRef),
+            ("count", Arc::new(UInt64Array::from(count)) as ArrayRef),
+            (
+                "null_count",
+                Arc::new(UInt64Array::from(null_count)) as ArrayRef,
+            ),
+            (
+                "distinct_count",
+                Arc::new(UInt64Array::from(distinct_count)) as ArrayRef,
+            ),
+            (
+                "min",
+                Arc::new(min.into_iter().collect::<StringArray>()) as ArrayRef,
+            ),
+            (
+                "max",
+                Arc::new(max.into_iter().collect::<StringArray>()) as ArrayRef,
+            ),
+            (
+                "sum",
+                Arc::new(sum.into_iter().collect::<StringArray>()) as ArrayRef,
+            ),
+            ("mean", Arc::new(Float64Array::from(mean)) as ArrayRef),
+            (
+                "min_length",
+                Arc::new(UInt64Array::from(min_length)) as ArrayRef,
+            ),
+            (
+                "max_length",
+                Arc::new(UInt64Array::from(max_length)) as ArrayRef,
+            ),
+        ])?;
+        Table::from_arrow(&batch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{DictionaryArray, DurationSecondArray, FixedSizeBinaryArray, Int32Array};
+    use arrow::datatypes::{DataType, Field, Int8Type};
+
+    use super::*;
+    use crate::{FieldProto, ListValuable, RowValuable};
+
+    #[test]
+    fn test_statistics() {
+        let list = Int32Array::from(vec![Some(3), None, Some(-1), Some(3), None])
+            .clone_as_list()
+            .unwrap();
+        assert_eq!(
+            list.statistics().unwrap(),
+            Statistics {
+                count: 3,
+                null_count: 2,
+                distinct_count: Some(2),
+                min: Some(TableScalar::int32(-1)),
+                max: Some(TableScalar::int32(3)),
+                sum: Some(TableScalar::int64(5)),
+                mean: Some(TableScalar::float64(5.0 / 3.0)),
+                min_length: None,
+                max_length: None,
+            }
+        );
+
+        let list = vec![Some("bb"), None, Some("a"), Some("bb"), Some("ccc")]
+            .into_iter()
+            .collect::<DictionaryArray<Int8Type>>()
+            .clone_as_list()
+            .unwrap();
+        let statistics = list.statistics().unwrap();
+        assert_eq!(statistics.null_count, 1);
+        assert_eq!(statistics.distinct_count, Some(3));
+        assert_eq!(statistics.min, Some(TableScalar::utf8("a".to_string())));
+        assert_eq!(statistics.max, Some(TableScalar::utf8("ccc".to_string())));
+        assert_eq!(
+            (statistics.min_length, statistics.max_length),
+            (Some(1), Some(3))
+        );
+        assert_eq!((statistics.sum, statistics.mean), (None, None));
+
+        let list = UInt64Array::from(vec![u64::MAX, 1])
+            .clone_as_list()
+            .unwrap();
+        assert_eq!(
+            list.statistics().unwrap().sum,
+            Some(TableScalar::float64(u64::MAX as f64 + 1.0))
+        );
+
+        let list = Int32Array::from(vec![None, None]).clone_as_list().unwrap();
+        let statistics = list.statistics().unwrap();
+        assert_eq!(statistics.distinct_count, Some(0));
+        assert_eq!((statistics.min, statistics.mean), (None, None));
+        assert_eq!(statistics.sum, Some(TableScalar::int64(0)));
+    }
+
+    #[test]
+    fn test_describe() {
+        let batch = RecordBatch::try_from_iter(vec![
+            (
+                "a",
+                Arc::new(Int32Array::from(vec![Some(1), None, Some(2)])) as ArrayRef,
+            ),
+            (
+                "b",
+                Arc::new(StringArray::from(vec!["x", "yy", "x"])) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+        let description = Table::from_arrow(&batch).unwrap().describe().unwrap();
+        assert_eq!(description.len(), 2);
+
+        let a = description.row(0).unwrap();
+        assert_eq!(a.values["column"], TableScalar::utf8("a".to_string()));
+        assert_eq!(a.values["null_count"], TableScalar::uint64(1));
+        assert_eq!(a.values["min"], TableScalar::utf8("1".to_string()));
+        assert_eq!(a.values["sum"], TableScalar::utf8("3".to_string()));
+        assert_eq!(a.values["mean"], TableScalar::float64(1.5));
+        assert_eq!(a.values["max_length"], TableScalar { value: None });
+
+        let b = description.row(1).unwrap();
+        assert_eq!(b.values["distinct_count"], TableScalar::uint64(2));
+        assert_eq!(b.values["max"], TableScalar::utf8("yy".to_string()));
+        assert_eq!(b.values["sum"], TableScalar { value: None });
+        assert_eq!(b.values["max_length"], TableScalar::uint64(2));
+
+        let mut table = Table::from_arrow(&batch).unwrap();
+        table.fields.push(FieldProto::from_arrow(&Field::new(
+            "c",
+            DataType::Null,
+            true,
+        )));
+        table.values.push(TableList::default());
+        let description = table.describe().unwrap();
+        let c = description.row(2).unwrap();
+        assert_eq!(c.values["column"], TableScalar::utf8("c".to_string()));
+        assert_eq!(c.values["count"], TableScalar::uint64(0));
+        assert_eq!(c.values["null_count"], TableScalar::uint64(3));
+        assert_eq!(c.values["min"], TableScalar { value: None });
+
+        // Arrow cannot print these types, which must not fail the whole description.
+        let batch = RecordBatch::try_from_iter(vec![
+            (
+                "d",
+                Arc::new(DurationSecondArray::from(vec![3, 1])) as ArrayRef,
+            ),
+            (
+                "e",
+                Arc::new(
+                    FixedSizeBinaryArray::try_from_iter(vec![vec![2u8], vec![1u8]].into_iter())
+                        .unwrap(),
+                ) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+        let description = Table::from_arrow(&batch).unwrap().describe().unwrap();
+        let d = description.row(0).unwrap();
+        assert_eq!(d.values["count"], TableScalar::uint64(2));
+        assert!(d.values["min"].value.is_some());
+        let e = description.row(1).unwrap();
+        assert_eq!(e.values["count"], TableScalar::uint64(2));
+    }
+}
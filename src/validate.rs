@@ -0,0 +1,416 @@
+use arrow::datatypes::DataType;
+
+use crate::validity::ListValidity;
+use crate::{table_list, ArrowScalarError, FieldProto, Table, TableList};
+
+/// A violated invariant, with the path to the offending list built up on the way out.
+struct Invalid {
+    path: String,
+    reason: String,
+}
+
+impl Invalid {
+    fn new(reason: String) -> Self {
+        Invalid {
+            path: String::new(),
+            reason,
+        }
+    }
+
+    fn at(mut self, segment: &str) -> Self {
+        self.path.insert_str(0, segment);
+        self
+    }
+}
+
+impl From<Invalid> for ArrowScalarError {
+    fn from(invalid: Invalid) -> Self {
+        let error = ArrowScalarError::InvalidList(invalid.reason);
+        if invalid.path.is_empty() {
+            error
+        } else {
+            error.at_path(&invalid.path)
+        }
+    }
+}
+
+fn field_type(field: &FieldProto) -> Result<DataType, Invalid> {
+    field
+        .to_arrow()
+        .map(|field| field.data_type().clone())
+        .map_err(|_| Invalid::new(format!("field `{}` has no valid data type", field.name)))
+}
+
+/// Checks that a list holds values of `expected`. Lists without values are null rows, and
+/// dictionaries are checked against their own index type only.
+fn check_type(list: &TableList, expected: &DataType) -> Result<(), Invalid> {
+    if list.values.is_none() || matches!(expected, DataType::Dictionary(_, _)) {
+        return Ok(());
+    }
+    match list.data_type() {
+        Ok(data_type) if &data_type != expected => Err(Invalid::new(format!(
+            "expected values of type {:?}, found {:?}",
+            expected, data_type
+        ))),
+        _ => Ok(()),
+    }
+}
+
+fn check_size(size: i32) -> Result<usize, Invalid> {
+    usize::try_from(size).map_err(|_| Invalid::new(format!("negative `size` {}", size)))
+}
+
+/// Small integer types are stored widened, so their values must fit the declared type.
+fn check_range<T: TryFrom<V>, V: Copy + std::fmt::Display>(
+    values: &[V],
+    type_name: &str,
+) -> Result<(), Invalid> {
+    match values.iter().position(|value| T::try_from(*value).is_err()) {
+        Some(i) => Err(
+            Invalid::new(format!("{} does not fit in {}", values[i], type_name))
+                .at(&format!("[{}]", i)),
+        ),
+        None => Ok(()),
+    }
+}
+
+fn validate_list_list(list: &table_list::ListList, fixed_size: bool) -> Result<(), Invalid> {
+    let list_type = list
+        .list_type
+        .as_ref()
+        .ok_or_else(|| Invalid::new("missing `list_type`".to_string()))?;
+    let item_type = field_type(list_type)?;
+    let size = match (fixed_size, list.size) {
+        (true, None) => return Err(Invalid::new("missing `size`".to_string())),
+        (true, Some(size)) => Some(check_size(size)?),
+        (false, _) => None,
+    };
+    for (i, child) in list.values.iter().enumerate() {
+        let segment = format!("[{}]", i);
+        validate(child).map_err(|invalid| invalid.at(&segment))?;
+        check_type(child, &item_type).map_err(|invalid| invalid.at(&segment))?;
+        if let Some(size) = size {
+            if list.is_valid(i) && child.len() != size {
+                return Err(
+                    Invalid::new(format!("has {} items, expected {}", child.len(), size))
+                        .at(&segment),
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+fn validate_struct(list: &table_list::StructList) -> Result<(), Invalid> {
+    if list.fields.len() != list.values.len() {
+        return Err(Invalid::new(format!(
+            "{} fields for {} columns",
+            list.fields.len(),
+            list.values.len()
+        )));
+    }
+    let rows = list.row_count();
+    for (field, child) in list.fields.iter().zip(list.values.iter()) {
+        let segment = format!(".{}", field.name);
+        validate(child).map_err(|invalid| invalid.at(&segment))?;
+        check_type(child, &field_type(field)?).map_err(|invalid| invalid.at(&segment))?;
+        if child.len() != rows {
+            return Err(
+                Invalid::new(format!("has {} rows, expected {}", child.len(), rows)).at(&segment),
+            );
+        }
+    }
+    Ok(())
+}
+
+fn validate_dictionary(list: &table_list::DictionaryList) -> Result<(), Invalid> {
+    let index_type = list
+        .index_type
+        .as_ref()
+        .ok_or_else(|| Invalid::new("missing `index_type`".to_string()))?
+        .to_arrow()
+        .map_err(|_| Invalid::new("invalid `index_type`".to_string()))?;
+    if !DataType::is_dictionary_key_type(&index_type) {
+        return Err(Invalid::new(format!(
+            "{:?} is not a dictionary index type",
+            index_type
+        )));
+    }
+    let values = list
+        .values
+        .as_ref()
+        .ok_or_else(|| Invalid::new("missing `values`".to_string()))?;
+    validate(values)
+}
+
+fn validate(list: &TableList) -> Result<(), Invalid> {
+    if let Some(validity) = list.list_validity() {
+        validity.check().map_err(Invalid::new)?;
+    }
+    match list.values.as_ref() {
+        Some(table_list::Values::List(list)) | Some(table_list::Values::LargeList(list)) => {
+            validate_list_list(list, false)
+        }
+        Some(table_list::Values::FixedSizeList(list)) => validate_list_list(list, true),
+        Some(table_list::Values::FixedSizeBinary(list)) => {
+            let size = check_size(
+                list.size
+                    .ok_or_else(|| Invalid::new("missing `size`".to_string()))?,
+            )?;
+            match list.values.iter().position(|value| value.len() != size) {
+                Some(i) => Err(Invalid::new(format!(
+                    "value has {} bytes, expected {}",
+                    list.values[i].len(),
+                    size
+                ))
+                .at(&format!("[{}]", i))),
+                None => Ok(()),
+            }
+        }
+        Some(table_list::Values::Int8(list)) => check_range::<i8, _>(&list.values, "Int8"),
+        Some(table_list::Values::Int16(list)) => check_range::<i16, _>(&list.values, "Int16"),
+        Some(table_list::Values::Uint8(list)) => check_range::<u8, _>(&list.values, "UInt8"),
+        Some(table_list::Values::Uint16(list)) => check_range::<u16, _>(&list.values, "UInt16"),
+        Some(table_list::Values::Struct(list)) => validate_struct(list),
+        Some(table_list::Values::Dictionary(list)) => validate_dictionary(list),
+        _ => Ok(()),
+    }
+}
+
+impl TableList {
+    /// Checks the invariants that decoding does not enforce, on this list and every list
+    /// nested in it: the validity covers every row, struct fields have as many rows as the
+    /// struct, nested lists hold values of their declared type and fixed sizes are respected.
+    /// [`TableList::to_array`] calls this, so a malformed message from an untrusted client is
+    /// an error instead of a panic.
+    pub fn validate(&self) -> Result<(), ArrowScalarError> {
+        Ok(validate(self)?)
+    }
+}
+
+/// Checks that a table column has values of the type of its field, and validates them.
+fn check_values(field: &FieldProto, column: &TableList) -> Result<(), Invalid> {
+    if column.values.is_none() {
+        return Err(Invalid::new("missing values".to_string()));
+    }
+    validate(column)?;
+    check_type(column, &field_type(field)?)
+}
+
+/// Checks that `column` is valid and holds values of the type of `field`, as
+/// [`Table::validate`] does.
+pub(crate) fn check_column(field: &FieldProto, column: &TableList) -> Result<(), ArrowScalarError> {
+    check_values(field, column)
+        .map_err(|invalid| ArrowScalarError::from(invalid).in_column(&field.name))
+}
+
+impl Table {
+    /// Checks that every column matches its field and has as many rows as the others, then
+    /// validates each column, see [`TableList::validate`].
+    pub fn validate(&self) -> Result<(), ArrowScalarError> {
+        if self.fields.len() != self.values.len() {
+            return Err(Invalid::new(format!(
+                "{} fields for {} columns",
+                self.fields.len(),
+                self.values.len()
+            ))
+            .into());
+        }
+        let rows = self.len();
+        for (field, column) in self.fields.iter().zip(self.values.iter()) {
+            let check = || {
+                check_values(field, column)?;
+                if column.len() != rows {
+                    return Err(Invalid::new(format!(
+                        "has {} rows, expected {}",
+                        column.len(),
+                        rows
+                    )));
+                }
+                Ok(())
+            };
+            check().map_err(|invalid| ArrowScalarError::from(invalid).in_column(&field.name))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::array::*;
+    use arrow::datatypes::*;
+    use arrow::record_batch::RecordBatch;
+
+    use super::*;
+    use crate::{ListValuable, Validity};
+
+    fn int32_list(values: Vec<i32>, set: Vec<bool>) -> TableList {
+        TableList {
+            values: Some(table_list::Values::Int32(table_list::Int32List {
+                values,
+                set,
+                validity: None,
+            })),
+        }
+    }
+
+    fn invalid(result: Result<(), ArrowScalarError>) -> (String, String) {
+        match result {
+            Err(error) => match error.root() {
+                ArrowScalarError::InvalidList(reason) => (
+                    error
+                        .context()
+                        .map(|context| context.path.clone())
+                        .unwrap_or_default(),
+                    reason.clone(),
+                ),
+                other => panic!("expected an invalid list, found {:?}", other),
+            },
+            Ok(()) => panic!("expected an invalid list"),
+        }
+    }
+
+    #[test]
+    fn test_validate_validity() {
+        assert!(int32_list(vec![1, 2], vec![true, false]).validate().is_ok());
+        assert!(int32_list(vec![1, 2], vec![]).validate().is_ok());
+        let list = int32_list(vec![1, 2], vec![true]);
+        assert_eq!(
+            invalid(list.validate()),
+            ("".to_string(), "`set` has 1 entries for 2 rows".to_string())
+        );
+        assert!(matches!(
+            list.to_array(),
+            Err(ArrowScalarError::InvalidList(_))
+        ));
+
+        let mut list = int32_list(vec![0; 9], vec![]);
+        if let Some(table_list::Values::Int32(list)) = list.values.as_mut() {
+            list.validity = Some(Validity {
+                bitmap: vec![0xff],
+                all_valid: false,
+            });
+        }
+        assert_eq!(
+            invalid(list.validate()).1,
+            "`validity` has 1 bytes for 9 rows"
+        );
+    }
+
+    #[test]
+    fn test_validate_nested() {
+        let array = StructArray::from(vec![
+            (
+                Field::new("a", DataType::Int32, true),
+                Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef,
+            ),
+            (
+                Field::new(
+                    "b",
+                    DataType::List(Box::new(Field::new("item", DataType::Int32, true))),
+                    true,
+                ),
+                Arc::new(ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+                    Some(vec![Some(1)]),
+                    None,
+                ])) as ArrayRef,
+            ),
+        ]);
+        let list = array.clone_as_list().unwrap();
+        assert!(list.validate().is_ok());
+
+        let mut short = list.clone();
+        if let Some(table_list::Values::Struct(list)) = short.values.as_mut() {
+            if let Some(table_list::Values::List(list)) = list.values[1].values.as_mut() {
+                list.values.pop();
+            }
+        }
+        assert_eq!(
+            invalid(short.validate()),
+            (".b".to_string(), "has 1 rows, expected 2".to_string())
+        );
+
+        let mut wrong_type = list.clone();
+        if let Some(table_list::Values::Struct(list)) = wrong_type.values.as_mut() {
+            if let Some(table_list::Values::List(list)) = list.values[1].values.as_mut() {
+                list.values[0] = TableList::from_strings(vec!["a"]);
+            }
+        }
+        assert_eq!(
+            invalid(wrong_type.validate()),
+            (
+                ".b[0]".to_string(),
+                "expected values of type Int32, found Utf8".to_string()
+            )
+        );
+
+        let mut untyped = list;
+        if let Some(table_list::Values::Struct(list)) = untyped.values.as_mut() {
+            if let Some(table_list::Values::List(list)) = list.values[1].values.as_mut() {
+                list.list_type = None;
+            }
+        }
+        assert_eq!(
+            invalid(untyped.validate()),
+            (".b".to_string(), "missing `list_type`".to_string())
+        );
+    }
+
+    #[test]
+    fn test_validate_fixed_size_binary() {
+        let array =
+            FixedSizeBinaryArray::try_from_sparse_iter_with_size(vec![Some(b"ab")].into_iter(), 2)
+                .unwrap();
+        let mut list = array.clone_as_list().unwrap();
+        assert!(list.validate().is_ok());
+        if let Some(table_list::Values::FixedSizeBinary(list)) = list.values.as_mut() {
+            list.values.push(b"abc".to_vec());
+            list.validity = None;
+        }
+        assert_eq!(
+            invalid(list.validate()),
+            (
+                "[1]".to_string(),
+                "value has 3 bytes, expected 2".to_string()
+            )
+        );
+        assert!(list.to_array().is_err());
+
+        if let Some(table_list::Values::FixedSizeBinary(list)) = list.values.as_mut() {
+            list.size = Some(-1);
+        }
+        assert_eq!(invalid(list.validate()).1, "negative `size` -1");
+    }
+
+    #[test]
+    fn test_validate_range() {
+        let mut list = TableList::from_values::<Int8Type>(vec![1, -1]);
+        assert!(list.validate().is_ok());
+        if let Some(table_list::Values::Int8(list)) = list.values.as_mut() {
+            list.values.push(300);
+        }
+        assert_eq!(
+            invalid(list.validate()),
+            ("[2]".to_string(), "300 does not fit in Int8".to_string())
+        );
+    }
+
+    #[test]
+    fn test_validate_table() {
+        let batch = RecordBatch::try_from_iter(vec![
+            ("a", Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef),
+            ("b", Arc::new(StringArray::from(vec!["a", "b"])) as ArrayRef),
+        ])
+        .unwrap();
+        let mut table = Table::from_arrow(&batch).unwrap();
+        assert!(table.validate().is_ok());
+        table.values[1] = TableList::from_strings(vec!["a"]);
+        assert_eq!(
+            table.validate().unwrap_err().to_string(),
+            "column `b`: Invalid list: has 1 rows, expected 2"
+        );
+        assert!(table.to_arrow().is_err());
+    }
+}
@@ -0,0 +1,835 @@
+use crate::{table_list, TableList, Validity};
+
+/// Borrowed validity of a list, in whichever form it is stored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidityRef<'a> {
+    /// The `repeated bool set` form, one entry per row.
+    Set(&'a [bool]),
+    /// A packed little-endian bitmap, one bit per row.
+    Bitmap(&'a [u8]),
+    /// Every row is valid.
+    AllValid,
+}
+
+impl<'a> ValidityRef<'a> {
+    /// Whether row `i` is valid. Rows the validity does not cover are null.
+    pub fn is_valid(&self, i: usize) -> bool {
+        match self {
+            ValidityRef::Set(set) => set.get(i).copied().unwrap_or(false),
+            ValidityRef::Bitmap(bitmap) => bitmap
+                .get(i / 8)
+                .map(|byte| byte & (1 << (i % 8)) != 0)
+                .unwrap_or(false),
+            ValidityRef::AllValid => true,
+        }
+    }
+
+    /// Iterates over the validity of `len` rows. The `set` form stops at its own length.
+    pub fn iter(&self, len: usize) -> ValidityIter<'a> {
+        let len = match self {
+            ValidityRef::Set(set) => set.len(),
+            _ => len,
+        };
+        ValidityIter {
+            validity: *self,
+            index: 0,
+            len,
+        }
+    }
+}
+
+/// Iterator over the validity of each row, see [`ValidityRef::iter`].
+#[derive(Debug, Clone)]
+pub struct ValidityIter<'a> {
+    validity: ValidityRef<'a>,
+    index: usize,
+    len: usize,
+}
+
+impl<'a> From<&'a [bool]> for ValidityIter<'a> {
+    fn from(set: &'a [bool]) -> Self {
+        ValidityRef::Set(set).iter(set.len())
+    }
+}
+
+impl<'a> Iterator for ValidityIter<'a> {
+    type Item = bool;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            return None;
+        }
+        let valid = self.validity.is_valid(self.index);
+        self.index += 1;
+        Some(valid)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a> ExactSizeIterator for ValidityIter<'a> {}
+
+impl Validity {
+    /// The packed validity of a list without null rows.
+    pub(crate) fn all_valid() -> Self {
+        Validity {
+            bitmap: vec![],
+            all_valid: true,
+        }
+    }
+}
+
+/// Packs one validity entry per row into an Arrow-style bitmap.
+pub(crate) fn pack_bitmap(set: impl Iterator<Item = bool>) -> Vec<u8> {
+    let mut bitmap = Vec::with_capacity(set.size_hint().0 / 8 + 1);
+    for (i, valid) in set.enumerate() {
+        if i % 8 == 0 {
+            bitmap.push(0);
+        }
+        if valid {
+            bitmap[i / 8] |= 1 << (i % 8);
+        }
+    }
+    bitmap
+}
+
+/// Access to the two validity representations of a list message.
+///
+/// Every list message resolves to one validity per row, in this order:
+/// - A non-empty `set` holds one entry per row and `false` marks a null row, so an all-`false`
+///   set is an all-null list. A `set` of any other length is invalid.
+/// - Otherwise a packed `validity` applies: `all_valid`, or a bitmap covering every row.
+/// - A list with neither has every row valid. This is also what older writers produce for a
+///   list without rows.
+pub(crate) trait ListValidity {
+    fn set(&self) -> &Vec<bool>;
+    fn set_mut(&mut self) -> &mut Vec<bool>;
+    fn packed(&self) -> &Option<Validity>;
+    fn packed_mut(&mut self) -> &mut Option<Validity>;
+    fn row_count(&self) -> usize;
+
+    fn validity_ref(&self) -> ValidityRef<'_> {
+        if !self.set().is_empty() {
+            return ValidityRef::Set(self.set());
+        }
+        match self.packed() {
+            Some(validity) if !validity.all_valid => ValidityRef::Bitmap(&validity.bitmap),
+            _ => ValidityRef::AllValid,
+        }
+    }
+
+    fn is_valid(&self, i: usize) -> bool {
+        self.validity_ref().is_valid(i)
+    }
+
+    fn is_packed(&self) -> bool {
+        self.set().is_empty() && self.packed().is_some()
+    }
+
+    /// Checks that the validity covers every row, describing the problem if it does not.
+    fn check(&self) -> Result<(), String> {
+        let rows = self.row_count();
+        match self.validity_ref() {
+            ValidityRef::Set(set) if set.len() != rows => {
+                Err(format!("`set` has {} entries for {} rows", set.len(), rows))
+            }
+            ValidityRef::Bitmap(bitmap) if bitmap.len() < rows.div_ceil(8) => Err(format!(
+                "`validity` has {} bytes for {} rows",
+                bitmap.len(),
+                rows
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Switches to a `set` with one entry per row.
+    fn unpack(&mut self) {
+        if self.set().is_empty() && self.row_count() > 0 {
+            let set = self
+                .validity_ref()
+                .iter(self.row_count())
+                .collect::<Vec<_>>();
+            *self.set_mut() = set;
+        }
+        *self.packed_mut() = None;
+    }
+
+    /// Switches to the packed form. A `set` that does not cover every row is left as is.
+    fn pack(&mut self) {
+        if self.is_packed() {
+            return;
+        }
+        let validity = match self.validity_ref() {
+            ValidityRef::Set(set) if set.len() != self.row_count() => return,
+            ValidityRef::Set(set) if !set.iter().all(|valid| *valid) => Validity {
+                bitmap: pack_bitmap(set.iter().copied()),
+                all_valid: false,
+            },
+            _ => Validity::all_valid(),
+        };
+        self.set_mut().clear();
+        *self.packed_mut() = Some(validity);
+    }
+
+    /// Adds the validity of rows about to be appended, keeping the form of this list. Call
+    /// it before appending their values.
+    fn extend_validity(&mut self, valid: ValidityIter<'_>) {
+        if !self.is_packed() {
+            self.unpack();
+            self.set_mut().extend(valid);
+            return;
+        }
+        let len = self.row_count();
+        if let Some(validity) = self.packed_mut() {
+            push_bits(validity, len, valid);
+        }
+    }
+
+    /// Prepares to add or remove rows at the end of this list, writing their validity to
+    /// `set` as a `set` form list would. A packed list stays packed: the validity of its last
+    /// `popped` rows moves to the empty `set`, which then stages only the rows that change
+    /// until [`ListValidity::commit`]. Other lists are unpacked.
+    ///
+    /// Returns the first staged row of a packed list.
+    fn stage(&mut self, popped: usize) -> Option<usize> {
+        if !self.is_packed() {
+            self.unpack();
+            return None;
+        }
+        let len = self.row_count();
+        let start = len.saturating_sub(popped);
+        let validity = self.validity_ref();
+        let staged = (start..len)
+            .map(|i| validity.is_valid(i))
+            .collect::<Vec<_>>();
+        if let Some(validity) = self.packed_mut() {
+            truncate_bits(validity, start);
+            // Without null rows left the list is all valid, as `pack` would write it.
+            if staged.contains(&false)
+                && ValidityRef::Bitmap(&validity.bitmap)
+                    .iter(start)
+                    .all(|valid| valid)
+            {
+                *validity = Validity::all_valid();
+            }
+        }
+        *self.set_mut() = staged;
+        Some(start)
+    }
+
+    /// Writes the rows staged by [`ListValidity::stage`] back to the bitmap, after the rows
+    /// before `start`.
+    fn commit(&mut self, start: usize) {
+        let staged = std::mem::take(self.set_mut());
+        if let Some(validity) = self.packed_mut() {
+            push_bits(validity, start, staged.into_iter());
+        }
+    }
+}
+
+/// Writes the bits of rows `len..` of a packed validity, keeping `all_valid` while it holds.
+fn push_bits(validity: &mut Validity, len: usize, valid: impl Iterator<Item = bool>) {
+    for (i, valid) in (len..).zip(valid) {
+        if validity.all_valid {
+            if valid {
+                continue;
+            }
+            validity.all_valid = false;
+            validity.bitmap = pack_bitmap(std::iter::repeat_n(true, i));
+        }
+        let byte = i / 8;
+        if validity.bitmap.len() <= byte {
+            validity.bitmap.resize(byte + 1, 0);
+        }
+        if valid {
+            validity.bitmap[byte] |= 1 << (i % 8);
+        } else {
+            validity.bitmap[byte] &= !(1 << (i % 8));
+        }
+    }
+}
+
+/// Keeps the bits of the first `len` rows of a packed validity, clearing the rest.
+fn truncate_bits(validity: &mut Validity, len: usize) {
+    if validity.all_valid {
+        return;
+    }
+    validity.bitmap.truncate(len.div_ceil(8));
+    let bits = len % 8;
+    if let Some(last) = validity.bitmap.last_mut().filter(|_| bits > 0) {
+        *last &= (1 << bits) - 1;
+    }
+}
+
+macro_rules! impl_list_validity {
+    ($list_type:ident, $values:ident) => {
+        impl ListValidity for table_list::$list_type {
+            fn set(&self) -> &Vec<bool> {
+                &self.set
+            }
+            fn set_mut(&mut self) -> &mut Vec<bool> {
+                &mut self.set
+            }
+            fn packed(&self) -> &Option<Validity> {
+                &self.validity
+            }
+            fn packed_mut(&mut self) -> &mut Option<Validity> {
+                &mut self.validity
+            }
+            fn row_count(&self) -> usize {
+                self.$values.len()
+            }
+        }
+    };
+}
+
+impl_list_validity!(BooleanList, values);
+impl_list_validity!(Int8List, values);
+impl_list_validity!(Int16List, values);
+impl_list_validity!(Int32List, values);
+impl_list_validity!(Int64List, values);
+impl_list_validity!(UInt8List, values);
+impl_list_validity!(UInt16List, values);
+impl_list_validity!(UInt32List, values);
+impl_list_validity!(UInt64List, values);
+impl_list_validity!(Float16List, values);
+impl_list_validity!(Float32List, values);
+impl_list_validity!(Float64List, values);
+impl_list_validity!(TimeList, times);
+impl_list_validity!(BinaryList, values);
+impl_list_validity!(Utf8List, values);
+impl_list_validity!(UnionList, values);
+impl_list_validity!(ListList, values);
+
+impl ListValidity for table_list::StructList {
+    fn set(&self) -> &Vec<bool> {
+        &self.set
+    }
+    fn set_mut(&mut self) -> &mut Vec<bool> {
+        &mut self.set
+    }
+    fn packed(&self) -> &Option<Validity> {
+        &self.validity
+    }
+    fn packed_mut(&mut self) -> &mut Option<Validity> {
+        &mut self.validity
+    }
+    fn row_count(&self) -> usize {
+        self.values.first().map(|column| column.len()).unwrap_or(0)
+    }
+}
+
+impl TableList {
+    pub(crate) fn list_validity(&self) -> Option<&dyn ListValidity> {
+        let list: &dyn ListValidity = match self.values.as_ref()? {
+            table_list::Values::Boolean(list) => list,
+            table_list::Values::Int8(list) => list,
+            table_list::Values::Int16(list) => list,
+            table_list::Values::Int32(list)
+            | table_list::Values::Date32(list)
+            | table_list::Values::Time32Second(list)
+            | table_list::Values::Time32Millisecond(list)
+            | table_list::Values::IntervalYearMonth(list) => list,
+            table_list::Values::Int64(list)
+            | table_list::Values::Date64(list)
+            | table_list::Values::Time64Microsecond(list)
+            | table_list::Values::Time64Nanosecond(list)
+            | table_list::Values::DurationSecond(list)
+            | table_list::Values::DurationMillisecond(list)
+            | table_list::Values::DurationMicrosecond(list)
+            | table_list::Values::DurationNanosecond(list)
+            | table_list::Values::IntervalDayTime(list) => list,
+            table_list::Values::Uint8(list) => list,
+            table_list::Values::Uint16(list) => list,
+            table_list::Values::Uint32(list) => list,
+            table_list::Values::Uint64(list) => list,
+            table_list::Values::Float16(list) => list,
+            table_list::Values::Float32(list) => list,
+            table_list::Values::Float64(list) => list,
+            table_list::Values::TimestampSecond(list)
+            | table_list::Values::TimestampMillisecond(list)
+            | table_list::Values::TimestampMicrosecond(list)
+            | table_list::Values::TimestampNanosecond(list) => list,
+            table_list::Values::Binary(list)
+            | table_list::Values::LargeBinary(list)
+            | table_list::Values::FixedSizeBinary(list) => list,
+            table_list::Values::Utf8(list) | table_list::Values::LargeUtf8(list) => list,
+            table_list::Values::List(list)
+            | table_list::Values::LargeList(list)
+            | table_list::Values::FixedSizeList(list) => list,
+            table_list::Values::Union(list) => list,
+            table_list::Values::Struct(list) => list,
+            table_list::Values::Dictionary(dict) => return dict.values.as_ref()?.list_validity(),
+        };
+        Some(list)
+    }
+
+    fn list_validity_mut(&mut self) -> Option<&mut dyn ListValidity> {
+        let list: &mut dyn ListValidity = match self.values.as_mut()? {
+            table_list::Values::Boolean(list) => list,
+            table_list::Values::Int8(list) => list,
+            table_list::Values::Int16(list) => list,
+            table_list::Values::Int32(list)
+            | table_list::Values::Date32(list)
+            | table_list::Values::Time32Second(list)
+            | table_list::Values::Time32Millisecond(list)
+            | table_list::Values::IntervalYearMonth(list) => list,
+            table_list::Values::Int64(list)
+            | table_list::Values::Date64(list)
+            | table_list::Values::Time64Microsecond(list)
+            | table_list::Values::Time64Nanosecond(list)
+            | table_list::Values::DurationSecond(list)
+            | table_list::Values::DurationMillisecond(list)
+            | table_list::Values::DurationMicrosecond(list)
+            | table_list::Values::DurationNanosecond(list)
+            | table_list::Values::IntervalDayTime(list) => list,
+            table_list::Values::Uint8(list) => list,
+            table_list::Values::Uint16(list) => list,
+            table_list::Values::Uint32(list) => list,
+            table_list::Values::Uint64(list) => list,
+            table_list::Values::Float16(list) => list,
+            table_list::Values::Float32(list) => list,
+            table_list::Values::Float64(list) => list,
+            table_list::Values::TimestampSecond(list)
+            | table_list::Values::TimestampMillisecond(list)
+            | table_list::Values::TimestampMicrosecond(list)
+            | table_list::Values::TimestampNanosecond(list) => list,
+            table_list::Values::Binary(list)
+            | table_list::Values::LargeBinary(list)
+            | table_list::Values::FixedSizeBinary(list) => list,
+            table_list::Values::Utf8(list) | table_list::Values::LargeUtf8(list) => list,
+            table_list::Values::List(list)
+            | table_list::Values::LargeList(list)
+            | table_list::Values::FixedSizeList(list) => list,
+            table_list::Values::Union(list) => list,
+            table_list::Values::Struct(list) => list,
+            table_list::Values::Dictionary(dict) => {
+                return dict.values.as_mut()?.list_validity_mut()
+            }
+        };
+        Some(list)
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut TableList> {
+        match self.values.as_mut() {
+            Some(table_list::Values::List(list))
+            | Some(table_list::Values::LargeList(list))
+            | Some(table_list::Values::FixedSizeList(list)) => list.values.iter_mut().collect(),
+            Some(table_list::Values::Struct(list)) => list.values.iter_mut().collect(),
+            Some(table_list::Values::Dictionary(dict)) => match dict.values.as_mut() {
+                Some(values) => values.children_mut(),
+                None => vec![],
+            },
+            _ => vec![],
+        }
+    }
+
+    fn children(&self) -> Vec<&TableList> {
+        match self.values.as_ref() {
+            Some(table_list::Values::List(list))
+            | Some(table_list::Values::LargeList(list))
+            | Some(table_list::Values::FixedSizeList(list)) => list.values.iter().collect(),
+            Some(table_list::Values::Struct(list)) => list.values.iter().collect(),
+            Some(table_list::Values::Dictionary(dict)) => match dict.values.as_ref() {
+                Some(values) => values.children(),
+                None => vec![],
+            },
+            _ => vec![],
+        }
+    }
+
+    /// The validity of the rows of this list, or `None` for a list without values.
+    pub fn validity(&self) -> Option<ValidityRef<'_>> {
+        Some(self.list_validity()?.validity_ref())
+    }
+
+    /// Converts this list and its children to the packed validity form, which encodes to one
+    /// bit per row, or nothing at all when every row is valid.
+    pub fn pack_validity(&mut self) {
+        if let Some(list) = self.list_validity_mut() {
+            list.pack();
+        }
+        for child in self.children_mut() {
+            child.pack_validity();
+        }
+    }
+
+    /// Converts this list and its children to the `repeated bool set` form, with one entry per
+    /// row.
+    pub fn unpack_validity(&mut self) {
+        self.unpack_top_validity();
+        for child in self.children_mut() {
+            child.unpack_validity();
+        }
+    }
+
+    /// Whether this list or one of its children uses the packed validity form.
+    pub fn has_packed_validity(&self) -> bool {
+        self.list_validity()
+            .map(|list| list.is_packed())
+            .unwrap_or(false)
+            || self
+                .children()
+                .iter()
+                .any(|child| child.has_packed_validity())
+    }
+
+    /// Converts only this list, not its children, to the `set` form before mutating it.
+    pub(crate) fn unpack_top_validity(&mut self) {
+        if let Some(list) = self.list_validity_mut() {
+            list.unpack();
+        }
+    }
+
+    /// Runs `mutate`, which adds or removes rows at the end of this list and records their
+    /// validity in `set`, between [`ListValidity::stage`] and [`ListValidity::commit`] of
+    /// this list, not its children. A dictionary leaves this to its values, which `mutate`
+    /// mutates in turn.
+    pub(crate) fn stage_validity<R>(
+        &mut self,
+        popped: usize,
+        mutate: impl FnOnce(&mut TableList) -> R,
+    ) -> R {
+        if let Some(table_list::Values::Dictionary(_)) = self.values {
+            return mutate(self);
+        }
+        let start = self.list_validity_mut().and_then(|list| list.stage(popped));
+        let result = mutate(self);
+        if let (Some(start), Some(list)) = (start, self.list_validity_mut()) {
+            list.commit(start);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::array::*;
+    use arrow::buffer::Buffer;
+    use arrow::datatypes::*;
+    use prost::Message;
+
+    use super::*;
+    use crate::{ArrowScalarError, FieldProto, ListValuable, ScalarValuable, TableScalar};
+
+    #[test]
+    fn test_pack_bitmap() {
+        let set = [true, false, true, true, false, false, false, false, true];
+        let bitmap = pack_bitmap(set.iter().copied());
+        assert_eq!(bitmap, vec![0b0000_1101, 0b0000_0001]);
+        let validity = ValidityRef::Bitmap(&bitmap);
+        assert_eq!(validity.iter(set.len()).collect::<Vec<_>>(), set);
+        assert!(!validity.is_valid(100));
+    }
+
+    #[test]
+    fn test_pack_round_trip() {
+        let array = Int32Array::from(vec![Some(1), None, Some(3)]);
+        let mut list = array.clone_as_list().unwrap();
+        assert!(list.has_packed_validity());
+        assert_eq!(list.validity(), Some(ValidityRef::Bitmap(&[0b101])));
+        assert_eq!(list.scalar(1).unwrap(), TableScalar { value: None });
+        assert_eq!(list.scalar(2).unwrap(), TableScalar::int32(3));
+
+        let decoded = TableList::decode(list.encode_to_vec().as_slice()).unwrap();
+        assert_eq!(decoded, list);
+        assert_eq!(decoded.to_array().unwrap().as_ref(), &array as &dyn Array);
+
+        list.unpack_validity();
+        assert!(!list.has_packed_validity());
+        assert_eq!(
+            list.validity(),
+            Some(ValidityRef::Set(&[true, false, true]))
+        );
+        list.pack_validity();
+        assert_eq!(decoded, list);
+    }
+
+    #[test]
+    fn test_all_valid_shortcut() {
+        let array = StringArray::from(vec!["a"; 100]);
+        let list = array.clone_as_list().unwrap();
+        assert_eq!(list.validity(), Some(ValidityRef::AllValid));
+        let mut legacy = list.clone();
+        legacy.unpack_validity();
+        assert!(list.encoded_len() + 90 < legacy.encoded_len());
+    }
+
+    #[test]
+    fn test_legacy_set_decodes() {
+        let legacy = TableList {
+            values: Some(table_list::Values::Int64(table_list::Int64List {
+                values: vec![1, 0],
+                set: vec![true, false],
+                validity: None,
+            })),
+        };
+        let decoded = TableList::decode(legacy.encode_to_vec().as_slice()).unwrap();
+        assert_eq!(
+            decoded.to_array().unwrap().as_ref(),
+            &Int64Array::from(vec![Some(1), None]) as &dyn Array
+        );
+    }
+
+    #[test]
+    fn test_mutate_packed() {
+        let array = Int64Array::from(vec![Some(1), None]);
+        let mut list = array.clone_as_list().unwrap();
+        list.push(TableScalar::int64(3)).unwrap();
+        list.push(TableScalar { value: None }).unwrap();
+        let expected = Int64Array::from(vec![Some(1), None, Some(3), None]);
+        assert_eq!(list, expected.clone_as_list().unwrap());
+        assert_eq!(list.pop(), Some(TableScalar { value: None }));
+        assert_eq!(list.pop(), Some(TableScalar::int64(3)));
+        assert_eq!(list.pop(), Some(TableScalar { value: None }));
+        assert_eq!(list.len(), 1);
+        // Popping the last null row goes back to the all valid form.
+        let expected = Int64Array::from(vec![1]);
+        assert_eq!(list, expected.clone_as_list().unwrap());
+
+        let values = (0..10)
+            .map(|i| if i == 8 { None } else { Some(i) })
+            .collect::<Vec<_>>();
+        let mut list = TableList::new(&DataType::Int64).unwrap();
+        for value in values.iter() {
+            match value {
+                Some(value) => list.push_native::<Int64Type>(*value).unwrap(),
+                None => list.push_null(),
+            }
+        }
+        assert_eq!(list, Int64Array::from(values).clone_as_list().unwrap());
+        list.clear();
+        assert_eq!(list, TableList::new(&DataType::Int64).unwrap());
+
+        let nested = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), None]),
+            None,
+        ]);
+        let list = nested.clone_as_list().unwrap();
+        assert_eq!(list.to_array().unwrap().as_ref(), &nested as &dyn Array);
+        let mut pushed = TableList::new(nested.data_type()).unwrap();
+        for scalar in list.iter() {
+            pushed.push(scalar).unwrap();
+        }
+        assert_eq!(pushed, list);
+    }
+
+    fn null_round_trip(array: ArrayRef) {
+        let mut list = array.clone_as_list().unwrap();
+        for packed in [true, false] {
+            if packed {
+                list.pack_validity();
+            } else {
+                list.unpack_validity();
+            }
+            assert_eq!(list.len(), array.len(), "{:?}", array.data_type());
+            assert_eq!(list.to_array().unwrap().as_ref(), array.as_ref());
+            for i in 0..array.len() {
+                let scalar = list.scalar(i).unwrap();
+                assert_eq!(
+                    scalar.value.is_none(),
+                    array.is_null(i),
+                    "row {i} of {list:?}"
+                );
+            }
+            let decoded = TableList::decode(list.encode_to_vec().as_slice()).unwrap();
+            assert_eq!(decoded.to_array().unwrap().as_ref(), array.as_ref());
+
+            let mut pushed = list.clone();
+            pushed.push_null();
+            let pushed = pushed.to_array().unwrap();
+            assert_eq!(pushed.len(), array.len() + 1, "{:?}", array.data_type());
+            assert!(pushed.is_null(array.len()), "{:?}", array.data_type());
+            assert_eq!(pushed.slice(0, array.len()).as_ref(), array.as_ref());
+        }
+    }
+
+    macro_rules! null_round_trips {
+        ($($array:expr),* $(,)?) => {
+            $(
+                null_round_trip(Arc::new($array.clone()));
+                null_round_trip($array.slice(0, 0));
+            )*
+        };
+    }
+
+    fn mixed<T: Clone>(value: T) -> Vec<Option<T>> {
+        vec![Some(value.clone()), None, Some(value), None]
+    }
+
+    fn all_null<T>() -> Vec<Option<T>> {
+        vec![None, None, None]
+    }
+
+    #[test]
+    fn test_null_round_trips() {
+        null_round_trips!(
+            BooleanArray::from(mixed(true)),
+            BooleanArray::from(all_null::<bool>()),
+            Int8Array::from(mixed(1)),
+            Int8Array::from(all_null()),
+            Int16Array::from(mixed(1)),
+            Int16Array::from(all_null()),
+            Int32Array::from(mixed(1)),
+            Int32Array::from(all_null()),
+            Int64Array::from(mixed(1)),
+            Int64Array::from(all_null()),
+            UInt8Array::from(mixed(1)),
+            UInt8Array::from(all_null()),
+            UInt16Array::from(mixed(1)),
+            UInt16Array::from(all_null()),
+            UInt32Array::from(mixed(1)),
+            UInt32Array::from(all_null()),
+            UInt64Array::from(mixed(1)),
+            UInt64Array::from(all_null()),
+            mixed(half::f16::ONE).into_iter().collect::<Float16Array>(),
+            all_null::<half::f16>()
+                .into_iter()
+                .collect::<Float16Array>(),
+            Float32Array::from(mixed(1.0)),
+            Float32Array::from(all_null()),
+            Float64Array::from(mixed(1.0)),
+            Float64Array::from(all_null()),
+            StringArray::from(mixed("a")),
+            StringArray::from(all_null::<&str>()),
+            LargeStringArray::from(mixed("a")),
+            LargeStringArray::from(all_null::<&str>()),
+            BinaryArray::from(mixed(b"a".as_ref())),
+            BinaryArray::from(all_null::<&[u8]>()),
+            LargeBinaryArray::from(mixed(b"a".as_ref())),
+            LargeBinaryArray::from(all_null::<&[u8]>()),
+            FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+                mixed(b"ab".to_vec()).into_iter(),
+                2
+            )
+            .unwrap(),
+            Date32Array::from(mixed(1)),
+            Date32Array::from(all_null()),
+            Date64Array::from(mixed(1)),
+            Date64Array::from(all_null()),
+            Time32SecondArray::from(mixed(1)),
+            Time32MillisecondArray::from(all_null()),
+            Time64MicrosecondArray::from(mixed(1)),
+            Time64NanosecondArray::from(all_null()),
+            TimestampSecondArray::from(mixed(1)),
+            TimestampMillisecondArray::from(all_null()),
+            TimestampMicrosecondArray::from(mixed(1)),
+            TimestampNanosecondArray::from(all_null()),
+            DurationSecondArray::from(mixed(1)),
+            DurationMillisecondArray::from(all_null()),
+            DurationMicrosecondArray::from(mixed(1)),
+            DurationNanosecondArray::from(all_null()),
+            IntervalYearMonthArray::from(mixed(1)),
+            IntervalDayTimeArray::from(all_null()),
+            ListArray::from_iter_primitive::<Int32Type, _, _>(mixed(vec![Some(1), None])),
+            ListArray::from_iter_primitive::<Int32Type, _, _>(all_null::<Vec<Option<i32>>>()),
+            mixed("a")
+                .into_iter()
+                .collect::<DictionaryArray<Int32Type>>(),
+            all_null::<&str>()
+                .into_iter()
+                .collect::<DictionaryArray<Int32Type>>(),
+        );
+    }
+
+    #[test]
+    fn test_struct_nulls() {
+        let array = StructArray::from((
+            vec![(
+                Field::new("a", DataType::Int32, true),
+                Arc::new(Int32Array::from(vec![Some(1), None, Some(3)])) as ArrayRef,
+            )],
+            Buffer::from_slice_ref([0b011u8]),
+        ));
+        null_round_trip(Arc::new(array));
+    }
+
+    #[test]
+    fn test_union_push_null() {
+        let mut list = TableList {
+            values: Some(table_list::Values::Union(table_list::UnionList {
+                values: vec![TableScalar::int32(1)],
+                ..Default::default()
+            })),
+        };
+        list.push_null();
+        assert_eq!(list.len(), 2);
+        assert!(list.scalar(0).unwrap().value.is_some());
+        assert_eq!(list.scalar(1).unwrap(), TableScalar::default());
+    }
+
+    fn int32_list(values: Vec<i32>, set: Vec<bool>) -> TableList {
+        TableList {
+            values: Some(table_list::Values::Int32(table_list::Int32List {
+                values,
+                set,
+                validity: None,
+            })),
+        }
+    }
+
+    #[test]
+    fn test_validity_semantics() {
+        let all_false = int32_list(vec![1, 2], vec![false, false]);
+        assert_eq!(
+            all_false.validity(),
+            Some(ValidityRef::Set(&[false, false]))
+        );
+        assert_eq!(all_false.scalar(0).unwrap(), TableScalar { value: None });
+        assert_eq!(all_false.to_array().unwrap().null_count(), 2);
+
+        let implicit = int32_list(vec![1, 2], vec![]);
+        assert_eq!(implicit.validity(), Some(ValidityRef::AllValid));
+        assert_eq!(implicit.scalar(1).unwrap(), TableScalar::int32(2));
+        assert_eq!(
+            implicit.to_array().unwrap().as_ref(),
+            &Int32Array::from(vec![1, 2]) as &dyn Array
+        );
+
+        let short = int32_list(vec![1, 2], vec![true]);
+        assert!(matches!(
+            short.to_array(),
+            Err(ArrowScalarError::InvalidList(_))
+        ));
+        assert!(matches!(
+            short.scalar(0),
+            Err(ArrowScalarError::InvalidList(_))
+        ));
+
+        let mut short_bitmap = int32_list(vec![0; 9], vec![]);
+        if let Some(table_list::Values::Int32(list)) = short_bitmap.values.as_mut() {
+            list.validity = Some(Validity {
+                bitmap: vec![0xff],
+                all_valid: false,
+            });
+        }
+        assert!(matches!(
+            short_bitmap.to_array(),
+            Err(ArrowScalarError::InvalidList(_))
+        ));
+
+        let nested = TableList {
+            values: Some(table_list::Values::List(table_list::ListList {
+                values: vec![implicit.clone(), implicit],
+                set: vec![],
+                list_type: Some(FieldProto::from_arrow(&Field::new(
+                    "item",
+                    DataType::Int32,
+                    true,
+                ))),
+                size: None,
+                validity: None,
+            })),
+        };
+        assert_eq!(
+            nested.to_array().unwrap().as_ref(),
+            &ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+                Some(vec![Some(1), Some(2)]),
+                Some(vec![Some(1), Some(2)]),
+            ]) as &dyn Array
+        );
+    }
+}